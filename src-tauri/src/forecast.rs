@@ -0,0 +1,382 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\forecast.rs
+//! Erlang C / Erlang A 기반 인력 산정
+use serde::{Deserialize, Serialize};
+
+use crate::state::agent_state::AgentState;
+use crate::state::queue_state::QueueState;
+
+/// 탐색할 최대 상담원 수
+const MAX_AGENTS: u32 = 2000;
+/// 실측 AHT 가 없을 때 기본값 (초)
+const DEFAULT_AHT_SECS: f64 = 300.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StaffingModel {
+    /// 포기 없음 가정
+    ErlangC,
+    /// 평균 인내 시간으로 포기 반영
+    ErlangA,
+}
+
+/// `calculate_staffing` 입력. 생략한 값은 QueueState 실측치로 채운다
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StaffingRequest {
+    /// 시간당 인입 콜 수 (생략 시 최근 1시간 인입)
+    #[serde(default)]
+    pub arrival_rate_per_hour: Option<f64>,
+    /// 평균 처리 시간 (생략 시 최근 1시간 AHT)
+    #[serde(default)]
+    pub average_handle_time_secs: Option<f64>,
+    /// 목표 서비스 레벨 (0.0 ~ 1.0, 기본 0.8)
+    #[serde(default)]
+    pub target_service_level: Option<f64>,
+    /// 목표 응답 시간 (생략 시 KPI 서비스 레벨 기준)
+    #[serde(default)]
+    pub target_answer_secs: Option<f64>,
+    /// 평균 인내 시간. 지정하면 Erlang A 로 계산
+    #[serde(default)]
+    pub average_patience_secs: Option<f64>,
+    /// 최대 점유율 (0.0 ~ 1.0)
+    #[serde(default)]
+    pub max_occupancy: Option<f64>,
+}
+
+/// 특정 상담원 수에서의 예상 지표
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StaffingMetrics {
+    pub agents: u32,
+    pub service_level: f64,
+    pub probability_of_waiting: f64,
+    /// 평균 응답 속도 (초). 대기열이 발산하면 None
+    pub average_speed_of_answer_secs: Option<f64>,
+    pub occupancy: f64,
+    /// Erlang A 에서만 계산
+    pub abandonment_probability: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StaffingResult {
+    pub model: StaffingModel,
+    pub arrival_rate_per_hour: f64,
+    pub average_handle_time_secs: f64,
+    /// 트래픽 강도 (Erlang)
+    pub traffic_erlangs: f64,
+    pub target_service_level: f64,
+    pub target_answer_secs: f64,
+    pub required_agents: u32,
+    pub projected: StaffingMetrics,
+    /// 현재 근무 중인 상담원 (offline / break 제외)
+    pub current_agents: u32,
+    pub current: StaffingMetrics,
+    /// 양수면 부족 인원, 음수면 여유 인원
+    pub shortfall: i64,
+}
+
+/// 입력 값이 정해진 계산 파라미터
+#[derive(Debug, Clone, Copy)]
+pub struct StaffingParams {
+    pub arrival_rate_per_hour: f64,
+    pub aht_secs: f64,
+    pub target_service_level: f64,
+    pub target_answer_secs: f64,
+    pub patience_secs: Option<f64>,
+    pub max_occupancy: Option<f64>,
+}
+
+impl StaffingParams {
+    fn validate(&self) -> Result<(), String> {
+        if !(self.arrival_rate_per_hour >= 0.0 && self.arrival_rate_per_hour.is_finite()) {
+            return Err("인입량은 0 이상이어야 합니다".to_string());
+        }
+        if !(self.aht_secs > 0.0 && self.aht_secs.is_finite()) {
+            return Err("평균 처리 시간은 0보다 커야 합니다".to_string());
+        }
+        if !(self.target_service_level > 0.0 && self.target_service_level < 1.0) {
+            return Err("목표 서비스 레벨은 0과 1 사이여야 합니다".to_string());
+        }
+        if !(self.target_answer_secs >= 0.0 && self.target_answer_secs.is_finite()) {
+            return Err("목표 응답 시간은 0 이상이어야 합니다".to_string());
+        }
+        if let Some(patience) = self.patience_secs {
+            if !(patience > 0.0 && patience.is_finite()) {
+                return Err("평균 인내 시간은 0보다 커야 합니다".to_string());
+            }
+        }
+        if let Some(max) = self.max_occupancy {
+            if !(max > 0.0 && max <= 1.0) {
+                return Err("최대 점유율은 0과 1 사이여야 합니다".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn traffic_erlangs(&self) -> f64 {
+        self.arrival_rate_per_hour / 3600.0 * self.aht_secs
+    }
+
+    pub fn model(&self) -> StaffingModel {
+        match self.patience_secs {
+            Some(_) => StaffingModel::ErlangA,
+            None => StaffingModel::ErlangC,
+        }
+    }
+
+    /// 상담원 `agents` 명일 때의 지표
+    pub fn metrics(&self, agents: u32) -> StaffingMetrics {
+        match self.patience_secs {
+            Some(patience) => erlang_a_metrics(self, agents, patience),
+            None => erlang_c_metrics(self, agents),
+        }
+    }
+
+    fn meets_target(&self, metrics: &StaffingMetrics) -> bool {
+        let occupancy_ok = self
+            .max_occupancy
+            .is_none_or(|max| metrics.occupancy <= max);
+        metrics.service_level >= self.target_service_level && occupancy_ok
+    }
+
+    /// 목표를 만족하는 최소 상담원 수
+    pub fn required_agents(&self) -> Result<u32, String> {
+        if self.arrival_rate_per_hour == 0.0 {
+            return Ok(0);
+        }
+        // Erlang C 는 트래픽 강도보다 많아야 안정. Erlang A 는 포기 콜 덕분에
+        // 트래픽 강도보다 적은 인원으로도 목표를 만족할 수 있으므로 1명부터 찾는다
+        let start = match self.model() {
+            StaffingModel::ErlangC => (self.traffic_erlangs().floor() as u32).max(1),
+            StaffingModel::ErlangA => 1,
+        };
+        (start..=MAX_AGENTS)
+            .find(|&n| self.meets_target(&self.metrics(n)))
+            .ok_or_else(|| format!("상담원 {}명으로도 목표를 달성할 수 없습니다", MAX_AGENTS))
+    }
+}
+
+/// Erlang B 차단 확률 (점화식)
+pub fn erlang_b(agents: u32, traffic: f64) -> f64 {
+    let mut b = 1.0;
+    for k in 1..=agents {
+        b = traffic * b / (k as f64 + traffic * b);
+    }
+    b
+}
+
+/// Erlang C 대기 확률
+pub fn erlang_c(agents: u32, traffic: f64) -> f64 {
+    let n = agents as f64;
+    if n <= traffic {
+        return 1.0;
+    }
+    let b = erlang_b(agents, traffic);
+    n * b / (n - traffic * (1.0 - b))
+}
+
+fn erlang_c_metrics(params: &StaffingParams, agents: u32) -> StaffingMetrics {
+    let traffic = params.traffic_erlangs();
+    let n = agents as f64;
+
+    if agents == 0 || n <= traffic {
+        return StaffingMetrics {
+            agents,
+            service_level: if traffic == 0.0 { 1.0 } else { 0.0 },
+            probability_of_waiting: if traffic == 0.0 { 0.0 } else { 1.0 },
+            average_speed_of_answer_secs: (traffic == 0.0).then_some(0.0),
+            occupancy: if agents == 0 { 0.0 } else { 1.0 },
+            abandonment_probability: None,
+        };
+    }
+
+    let pw = erlang_c(agents, traffic);
+    let service_level =
+        1.0 - pw * (-(n - traffic) * params.target_answer_secs / params.aht_secs).exp();
+    StaffingMetrics {
+        agents,
+        service_level: service_level.clamp(0.0, 1.0),
+        probability_of_waiting: pw,
+        average_speed_of_answer_secs: Some(pw * params.aht_secs / (n - traffic)),
+        occupancy: traffic / n,
+        abandonment_probability: None,
+    }
+}
+
+/// Erlang A (M/M/n+M) 정상 분포를 출생-사망 과정으로 계산
+fn erlang_a_metrics(params: &StaffingParams, agents: u32, patience_secs: f64) -> StaffingMetrics {
+    let lambda = params.arrival_rate_per_hour / 3600.0;
+    let mu = 1.0 / params.aht_secs;
+    let theta = 1.0 / patience_secs;
+    let n = agents as usize;
+
+    if lambda == 0.0 {
+        return StaffingMetrics {
+            agents,
+            service_level: 1.0,
+            probability_of_waiting: 0.0,
+            average_speed_of_answer_secs: Some(0.0),
+            occupancy: 0.0,
+            abandonment_probability: Some(0.0),
+        };
+    }
+
+    // 상태 k = 시스템 내 콜 수. 꼬리 확률이 충분히 작아질 때까지 전개
+    let mut weights = vec![1.0_f64];
+    let mut total = 1.0;
+    let mut k = 0usize;
+    loop {
+        k += 1;
+        let death = mu * k.min(n) as f64 + theta * k.saturating_sub(n) as f64;
+        let next = weights[k - 1] * lambda / death;
+        weights.push(next);
+        total += next;
+        if total > 1e200 {
+            weights.iter_mut().for_each(|w| *w /= total);
+            total = 1.0;
+        }
+        if (k > n && next / total < 1e-12) || k > n + 20_000 {
+            break;
+        }
+    }
+    let pi: Vec<f64> = weights.iter().map(|w| w / total).collect();
+
+    let probability_of_waiting: f64 = pi.iter().skip(n).sum();
+    let queue_length: f64 = pi
+        .iter()
+        .enumerate()
+        .skip(n + 1)
+        .map(|(k, p)| (k - n) as f64 * p)
+        .sum();
+    let abandonment_probability = (theta * queue_length / lambda).clamp(0.0, 1.0);
+    let busy_agents: f64 = pi
+        .iter()
+        .enumerate()
+        .map(|(k, p)| k.min(n) as f64 * p)
+        .sum();
+
+    // 대기 시작 시 앞에 j 명이 있는 콜이 t 초 안에 응답받을 확률을 수치 적분
+    // (앞 콜의 서비스 진입 / 포기 속도 n·μ + i·θ, 본인 포기 속도 θ)
+    let positions: Vec<f64> = pi.iter().skip(n).copied().collect();
+    let served_within = served_within(
+        &positions,
+        agents as f64 * mu,
+        theta,
+        params.target_answer_secs,
+    );
+
+    StaffingMetrics {
+        agents,
+        service_level: ((1.0 - probability_of_waiting) + served_within).clamp(0.0, 1.0),
+        probability_of_waiting,
+        average_speed_of_answer_secs: Some(queue_length / lambda),
+        occupancy: if agents == 0 {
+            0.0
+        } else {
+            busy_agents / agents as f64
+        },
+        abandonment_probability: Some(abandonment_probability),
+    }
+}
+
+/// 대기열 위치별 초기 확률에서 t 초 안에 서비스에 진입하는 확률 (Euler 적분)
+fn served_within(positions: &[f64], service_rate: f64, theta: f64, t: f64) -> f64 {
+    if positions.is_empty() || t <= 0.0 || service_rate == 0.0 {
+        return 0.0;
+    }
+    let max_rate = service_rate + theta * positions.len() as f64 + theta;
+    let steps = ((t * max_rate / 0.05).ceil() as usize).clamp(50, 200_000);
+    let dt = t / steps as f64;
+
+    let mut mass = positions.to_vec();
+    let mut served = 0.0;
+    for _ in 0..steps {
+        let mut next = vec![0.0; mass.len()];
+        for (i, &m) in mass.iter().enumerate() {
+            if m == 0.0 {
+                continue;
+            }
+            let advance = (service_rate + theta * i as f64) * dt;
+            let abandon = theta * dt;
+            if i == 0 {
+                served += m * advance;
+            } else {
+                next[i - 1] += m * advance;
+            }
+            next[i] += m * (1.0 - advance - abandon).max(0.0);
+        }
+        mass = next;
+    }
+    served
+}
+
+/// 현재 근무 중(응대 가능/호출/통화/후처리) 상담원 수
+fn staffed_agents(state: &QueueState, queue_id: Option<&str>) -> Result<u32, String> {
+    let agents = state.agents_for(queue_id)?;
+    if agents.is_empty() {
+        return Ok(state.status_for(queue_id)?.inbound_agents_total);
+    }
+    Ok(agents
+        .iter()
+        .filter(|a| !matches!(a.status(), AgentState::Break | AgentState::Offline))
+        .count() as u32)
+}
+
+// === 인력 산정 Tauri Command ===
+
+/// Erlang C (인내 시간 지정 시 Erlang A) 로 필요 인원과 현재 인원 대비 부족분 계산.
+/// `queue_id` 지정 시 해당 대기열의 KPI / 상담원 기준.
+/// Erlang A 적분은 오래 걸릴 수 있어서 IPC 스레드를 막지 않도록 blocking 스레드에서 계산한다
+#[tauri::command]
+pub async fn calculate_staffing(
+    state: tauri::State<'_, QueueState>,
+    request: Option<StaffingRequest>,
+    queue_id: Option<String>,
+) -> Result<StaffingResult, String> {
+    let request = request.unwrap_or_default();
+    let kpis = state.kpi_report_for(queue_id.as_deref())?;
+
+    let params = StaffingParams {
+        arrival_rate_per_hour: request
+            .arrival_rate_per_hour
+            .unwrap_or(kpis.one_hour.calls_offered as f64),
+        aht_secs: request
+            .average_handle_time_secs
+            .or(kpis.one_hour.average_handle_time_secs)
+            .unwrap_or(DEFAULT_AHT_SECS),
+        target_service_level: request.target_service_level.unwrap_or(0.8),
+        target_answer_secs: request
+            .target_answer_secs
+            .unwrap_or(kpis.service_level_threshold_secs as f64),
+        patience_secs: request.average_patience_secs,
+        max_occupancy: request.max_occupancy,
+    };
+    params.validate()?;
+    let current_agents = staffed_agents(&state, queue_id.as_deref())?;
+
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let required_agents = params.required_agents()?;
+        Ok::<_, String>(StaffingResult {
+            model: params.model(),
+            arrival_rate_per_hour: params.arrival_rate_per_hour,
+            average_handle_time_secs: params.aht_secs,
+            traffic_erlangs: params.traffic_erlangs(),
+            target_service_level: params.target_service_level,
+            target_answer_secs: params.target_answer_secs,
+            required_agents,
+            projected: params.metrics(required_agents),
+            current_agents,
+            current: params.metrics(current_agents),
+            shortfall: required_agents as i64 - current_agents as i64,
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    println!(
+        "📐 [FORECAST] {} 필요 {}명 / 현재 {}명 (트래픽 {:.2} Erlang)",
+        queue_id.as_deref().unwrap_or("전체"),
+        result.required_agents,
+        result.current_agents,
+        result.traffic_erlangs
+    );
+    Ok(result)
+}
@@ -2,10 +2,27 @@
 use tauri::Manager;
 
 mod devtools;
-mod state;
-mod window;
+pub mod forecast;
+pub mod state;
+pub mod window;
 
+use state::audit_log::AUDIT_LOG_FILE_NAME;
 use state::auth_state::AuthState;
+use state::backend::BACKEND_CONFIG_FILE_NAME;
+use state::idle_lock::IDLE_LOCK_FILE_NAME;
+use state::offline_login::OFFLINE_CREDENTIALS_FILE_NAME;
+use state::queue_alerts::ALERT_RULES_FILE_NAME;
+use state::queue_history::HISTORY_FILE_NAME;
+use state::queue_registry::QUEUE_REGISTRY_FILE_NAME;
+use state::queue_simulation::SimulationState;
+use state::queue_state::QueueState;
+use state::rbac::RBAC_POLICY_FILE_NAME;
+use state::session_store::SESSION_FILE_NAME;
+use state::totp::TOTP_FILE_NAME;
+use window::config::{WindowRegistry, WINDOW_OVERRIDES_FILE_NAME};
+use window::geometry::WINDOW_GEOMETRY_FILE_NAME;
+use window::layouts::WINDOW_LAYOUTS_FILE_NAME;
+use window::WindowState;
 
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -16,7 +33,64 @@ fn greet(name: &str) -> String {
 pub fn run() {
     tauri::Builder::default()
         .manage(AuthState::default())
+        .manage(QueueState::default())
+        .manage(SimulationState::default())
+        .manage(WindowState::default())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_notification::init())
+        .setup(|app| {
+            let data_dir = app.path().app_data_dir()?;
+
+            // 🪟 윈도우 정의 로드 + 검증 (포함된 정의가 잘못되어 있으면 시작하지 않는다)
+            app.manage(WindowRegistry::load(
+                data_dir.join(WINDOW_OVERRIDES_FILE_NAME),
+            )?);
+            // 📐 사용자별 윈도우 위치 / 크기 + 작업 레이아웃
+            app.state::<WindowState>()
+                .load_geometry(data_dir.join(WINDOW_GEOMETRY_FILE_NAME));
+            app.state::<WindowState>().load_layouts(
+                data_dir.join(WINDOW_LAYOUTS_FILE_NAME),
+                &app.state::<WindowRegistry>(),
+            );
+
+            // 🔓 백엔드 설정 / 권한 정책 / 감사 로그 / 잠금 / 2단계 인증 설정 로드 + 암호화된 로그인 세션 복원 (토큰 갱신 / 만료 타이머 시작)
+            app.state::<AuthState>()
+                .load_backend_config(data_dir.join(BACKEND_CONFIG_FILE_NAME));
+            app.state::<AuthState>()
+                .load_rbac_policy(data_dir.join(RBAC_POLICY_FILE_NAME));
+            app.state::<AuthState>()
+                .open_audit_log(data_dir.join(AUDIT_LOG_FILE_NAME));
+            app.state::<AuthState>()
+                .load_idle_lock(data_dir.join(IDLE_LOCK_FILE_NAME));
+            app.state::<AuthState>()
+                .load_offline_credentials(data_dir.join(OFFLINE_CREDENTIALS_FILE_NAME));
+            app.state::<AuthState>()
+                .load_totp(data_dir.join(TOTP_FILE_NAME));
+            app.state::<AuthState>()
+                .restore_session(data_dir.join(SESSION_FILE_NAME));
+            state::auth_state::schedule_session_timers(app.handle().clone());
+            state::idle_lock::start_idle_monitor(app.handle().clone());
+
+            // 🗂️ 대기열 목록 / 스킬 배정 복원
+            app.state::<QueueState>()
+                .load_queue_registry(data_dir.join(QUEUE_REGISTRY_FILE_NAME));
+
+            // 📈 대기열 히스토리 복원 + 샘플러 시작
+            app.state::<QueueState>()
+                .load_history(data_dir.join(HISTORY_FILE_NAME));
+            state::queue_state::start_history_sampler(app.handle().clone());
+
+            // 🚨 알림 규칙 로드 + 주기 평가 시작
+            app.state::<QueueState>()
+                .load_alert_rules(data_dir.join(ALERT_RULES_FILE_NAME));
+            state::queue_state::start_alert_monitor(app.handle().clone());
+
+            // 🔀 콜 분배 엔진 시작 (링 타임아웃 처리)
+            state::routing::start_routing_engine(app.handle().clone());
+            Ok(())
+        })
+        // 📐 윈도우 이동 / 리사이즈 / 닫기 때 위치 / 크기 기록
+        .on_window_event(window::geometry::handle_window_event)
         .invoke_handler(tauri::generate_handler![
             greet,
             // 🛠️ 개발자 도구 관리 (단순화된 방식)
@@ -41,23 +115,86 @@ pub fn run() {
             window::commands::window_exists,
             window::commands::hide_window,
             window::commands::show_window,
+            window::commands::list_windows,
             window::commands::minimize_window,
             window::commands::maximize_window,
             window::commands::unmaximize_window,
             window::commands::resize_window,
             window::commands::move_window,
             window::commands::set_always_on_top,
+            // 🧩 작업 레이아웃
+            window::layouts::list_layouts,
+            window::layouts::save_layout,
+            window::layouts::apply_layout,
             // 🔐 핵심 인증 함수들
-            state::auth_state::set_user_state,
+            state::auth_state::login,
             state::auth_state::get_user_state,
             state::auth_state::logout_user,
             state::auth_state::is_authenticated,
             state::auth_state::check_permission,
+            state::auth_state::get_effective_permissions,
+            state::offline_login::offline_login,
+            state::api_proxy::api_request,
+            state::idle_lock::report_activity,
+            state::idle_lock::lock_session,
+            state::idle_lock::unlock_session,
+            state::idle_lock::get_lock_status,
+            state::idle_lock::set_idle_timeout,
+            state::idle_lock::set_unlock_pin,
+            state::audit_log::verify_audit_log,
+            state::audit_log::export_audit_log,
+            state::impersonation::start_impersonation,
+            state::impersonation::end_impersonation,
+            state::totp::get_second_factor_status,
+            state::totp::begin_totp_enrollment,
+            state::totp::confirm_totp_enrollment,
+            state::totp::verify_second_factor,
             // 🔐 호환성 별칭들
-            state::auth_state::login_user,
             state::auth_state::get_user,
             state::auth_state::is_logged_in,
+            // 📊 대기열 모니터링
+            state::queue_state::update_queue_status,
+            state::queue_state::get_queue_status,
+            state::queue_state::get_queue_snapshot_since,
+            state::queue_state::update_agent_status,
+            state::queue_state::get_all_agents,
+            state::queue_state::get_available_agents,
+            state::queue_state::get_busy_agents,
+            state::queue_state::get_queue_statistics,
+            state::queue_state::record_call_event,
+            state::queue_state::get_queue_kpis,
+            state::queue_state::set_service_level_threshold,
+            state::queue_state::get_queue_history,
+            state::queue_state::get_queue_history_config,
+            state::queue_state::set_queue_history_config,
+            state::queue_state::get_alert_rules,
+            state::queue_state::save_alert_rule,
+            state::queue_state::delete_alert_rule,
+            state::queue_state::get_active_alerts,
+            state::queue_state::get_resolved_alerts,
+            state::queue_state::list_queues,
+            state::queue_state::save_queue,
+            state::queue_state::delete_queue,
+            state::queue_state::get_agent_skills,
+            state::queue_state::set_agent_skills,
+            // 🔀 콜 분배 (ACD)
+            state::routing::enqueue_interaction,
+            state::routing::accept_offer,
+            state::routing::reject_offer,
+            state::routing::abandon_interaction,
+            state::routing::get_routing_queue,
+            state::routing::get_routing_log,
+            state::routing::get_routing_config,
+            state::routing::set_routing_config,
+            // 🎲 시뮬레이션 (데모 / 부하 테스트)
+            state::queue_simulation::start_simulation,
+            state::queue_simulation::stop_simulation,
+            state::queue_simulation::set_simulation_speed,
+            state::queue_simulation::get_simulation_status,
+            // 📐 인력 산정
+            forecast::calculate_staffing,
             // 🔍 디버깅 (개발용)
+            #[cfg(feature = "debug-commands")]
             state::auth_state::get_auth_debug_info,
         ])
         .run(tauri::generate_context!())
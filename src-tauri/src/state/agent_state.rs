@@ -0,0 +1,213 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\agent_state.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 상담원 상태
+///
+/// 정상 흐름: available → ringing → busy → wrap_up → available
+/// 그 외: break(사유 코드 필수), offline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentState {
+    Available,
+    Ringing,
+    Busy,
+    WrapUp,
+    Break,
+    Offline,
+}
+
+impl AgentState {
+    pub const ALL: [AgentState; 6] = [
+        AgentState::Available,
+        AgentState::Ringing,
+        AgentState::Busy,
+        AgentState::WrapUp,
+        AgentState::Break,
+        AgentState::Offline,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AgentState::Available => "available",
+            AgentState::Ringing => "ringing",
+            AgentState::Busy => "busy",
+            AgentState::WrapUp => "wrap_up",
+            AgentState::Break => "break",
+            AgentState::Offline => "offline",
+        }
+    }
+
+    /// `self` 에서 `next` 로 바로 넘어갈 수 있는지 여부
+    pub fn can_transition_to(&self, next: AgentState) -> bool {
+        use AgentState::*;
+        matches!(
+            (*self, next),
+            (Offline, Available)
+                | (Available, Ringing)
+                | (Available, Break)
+                | (Available, Offline)
+                | (Ringing, Busy) // 응답
+                | (Ringing, Available) // 미응답 / 링 타임아웃
+                | (Ringing, Offline) // 로그아웃 / 연결 끊김
+                | (Busy, WrapUp)
+                | (Busy, Offline) // 통화 중 로그아웃 / 연결 끊김
+                | (WrapUp, Available)
+                | (WrapUp, Break)
+                | (WrapUp, Offline)
+                | (Break, Available)
+                | (Break, Offline)
+        )
+    }
+}
+
+impl fmt::Display for AgentState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 휴식 사유 코드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakReason {
+    Rest,
+    Lunch,
+    Meeting,
+    Training,
+    Coaching,
+    Personal,
+}
+
+/// 상태 전이 실패 사유 (프론트엔드에는 `kind` 태그가 붙은 객체로 전달)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AgentTransitionError {
+    IllegalTransition {
+        agent_id: String,
+        from: AgentState,
+        to: AgentState,
+    },
+    MissingBreakReason {
+        agent_id: String,
+    },
+    UnexpectedBreakReason {
+        agent_id: String,
+        state: AgentState,
+    },
+    /// 함께 보낸 스킬 배정이 잘못됨 (상태도 바꾸지 않는다)
+    InvalidSkills {
+        agent_id: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for AgentTransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IllegalTransition { agent_id, from, to } => {
+                write!(
+                    f,
+                    "상담원 {agent_id}: {from} → {to} 전이는 허용되지 않습니다"
+                )
+            }
+            Self::MissingBreakReason { agent_id } => {
+                write!(
+                    f,
+                    "상담원 {agent_id}: break 상태에는 사유 코드가 필요합니다"
+                )
+            }
+            Self::UnexpectedBreakReason { agent_id, state } => {
+                write!(
+                    f,
+                    "상담원 {agent_id}: {state} 상태에는 사유 코드를 지정할 수 없습니다"
+                )
+            }
+            Self::InvalidSkills { agent_id, reason } => {
+                write!(
+                    f,
+                    "상담원 {agent_id}: 스킬 배정이 올바르지 않습니다 ({reason})"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AgentTransitionError {}
+
+/// 현재 상태와 진입 시각
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentStateMachine {
+    pub status: AgentState,
+    pub break_reason: Option<BreakReason>,
+    pub status_since: DateTime<Utc>,
+    pub previous_status: Option<AgentState>,
+}
+
+impl AgentStateMachine {
+    /// 신규 상담원은 offline 에서 시작
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            status: AgentState::Offline,
+            break_reason: None,
+            status_since: now,
+            previous_status: None,
+        }
+    }
+
+    /// 상태 전이. 실제로 상태가 바뀌었으면 `true`.
+    /// 같은 상태로의 요청은 진입 시각을 유지한 채 통과시킨다
+    /// (단, break 사유 변경은 새로운 break 로 간주해 시각을 갱신).
+    pub fn transition(
+        &mut self,
+        agent_id: &str,
+        next: AgentState,
+        break_reason: Option<BreakReason>,
+        now: DateTime<Utc>,
+    ) -> Result<bool, AgentTransitionError> {
+        match (next, break_reason) {
+            (AgentState::Break, None) => {
+                return Err(AgentTransitionError::MissingBreakReason {
+                    agent_id: agent_id.to_string(),
+                })
+            }
+            (state, Some(_)) if state != AgentState::Break => {
+                return Err(AgentTransitionError::UnexpectedBreakReason {
+                    agent_id: agent_id.to_string(),
+                    state,
+                })
+            }
+            _ => {}
+        }
+
+        if next == self.status {
+            if self.break_reason == break_reason {
+                return Ok(false);
+            }
+            self.break_reason = break_reason;
+            self.status_since = now;
+            return Ok(true);
+        }
+
+        if !self.status.can_transition_to(next) {
+            return Err(AgentTransitionError::IllegalTransition {
+                agent_id: agent_id.to_string(),
+                from: self.status,
+                to: next,
+            });
+        }
+
+        self.previous_status = Some(self.status);
+        self.status = next;
+        self.break_reason = break_reason;
+        self.status_since = now;
+        Ok(true)
+    }
+
+    /// 현재 상태에 머문 시간(초)
+    pub fn seconds_in_state(&self, now: DateTime<Utc>) -> i64 {
+        (now - self.status_since).num_seconds().max(0)
+    }
+}
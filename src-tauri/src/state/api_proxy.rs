@@ -0,0 +1,165 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\api_proxy.rs
+//! 웹뷰 대신 Rust 에서 백엔드 API 를 호출한다. 토큰은 여기서만 붙이므로 웹뷰로 나가지 않는다.
+//! 허용된 base URL(`BackendConfig`) 밖으로는 요청하지 않는다.
+use reqwest::header::{HeaderName, HeaderValue, AUTHORIZATION};
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::auth_state::AuthState;
+use super::backend::BackendConfig;
+
+/// 호출자가 직접 넣을 수 없는 헤더
+const RESERVED_HEADERS: &[&str] = &["authorization", "cookie", "host", "proxy-authorization"];
+
+/// `api_request` 요청
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiRequest {
+    /// 기본값 GET
+    #[serde(default)]
+    pub method: Option<String>,
+    /// `/auth/me` 같은 경로 또는 허용된 base URL 로 시작하는 절대 URL (쿼리 포함 가능)
+    pub url: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// 직렬화된 본문 (JSON 이면 Content-Type 헤더도 함께 보낸다)
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+/// `api_request` 응답. HTTP 오류 상태도 그대로 돌려준다
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+impl BackendConfig {
+    /// `url` 이 base_url 또는 allowed_base_urls 중 하나의 하위 경로인지
+    pub fn is_allowed_url(&self, url: &Url) -> bool {
+        std::iter::once(&self.base_url)
+            .chain(self.allowed_base_urls.iter())
+            .filter_map(|base| Url::parse(base).ok())
+            .any(|base| {
+                let base_path = base.path().trim_end_matches('/');
+                base.scheme() == url.scheme()
+                    && base.host_str() == url.host_str()
+                    && base.port_or_known_default() == url.port_or_known_default()
+                    && url.username().is_empty()
+                    && url.password().is_none()
+                    && (url.path() == base_path
+                        || url.path().starts_with(&format!("{}/", base_path)))
+            })
+    }
+
+    /// 로그인 / 토큰 갱신 / 대리 세션 주소. 응답에 토큰이 들어 있으므로 Rust 명령으로만 호출한다
+    fn is_token_endpoint(&self, url: &Url) -> bool {
+        [&self.login_path, &self.refresh_path, &self.impersonate_path]
+            .into_iter()
+            .filter_map(|path| Url::parse(&self.url(path)).ok())
+            .any(|endpoint| {
+                endpoint.origin() == url.origin()
+                    && endpoint.path().trim_end_matches('/') == url.path().trim_end_matches('/')
+            })
+    }
+
+    /// 경로면 base_url 에 붙이고, 결과가 허용 목록 밖이면 거부
+    pub fn resolve_api_url(&self, url: &str) -> Result<Url, String> {
+        let resolved = Url::parse(url)
+            .or_else(|_| Url::parse(&self.url(url)))
+            .map_err(|e| format!("잘못된 URL: {}", e))?;
+
+        if !self.is_allowed_url(&resolved) {
+            return Err(format!(
+                "허용되지 않은 API 주소: {}",
+                resolved.origin().ascii_serialization()
+            ));
+        }
+        if self.is_token_endpoint(&resolved) {
+            return Err(format!(
+                "토큰을 발급하는 주소는 직접 호출할 수 없습니다: {}",
+                resolved.path()
+            ));
+        }
+        Ok(resolved)
+    }
+}
+
+/// 요청 1회 전송. `token` 이 있으면 `Authorization: Bearer` 로 붙인다
+pub async fn send(
+    client: &reqwest::Client,
+    config: &BackendConfig,
+    token: Option<&str>,
+    request: ApiRequest,
+) -> Result<ApiResponse, String> {
+    let url = config.resolve_api_url(&request.url)?;
+    let method = match request.method.as_deref() {
+        Some(method) => Method::from_bytes(method.to_ascii_uppercase().as_bytes())
+            .map_err(|_| format!("잘못된 HTTP 메서드: {}", method))?,
+        None => Method::GET,
+    };
+
+    let mut builder = client
+        .request(method, url)
+        .timeout(config.request_timeout());
+    for (name, value) in &request.headers {
+        if RESERVED_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+            return Err(format!("직접 지정할 수 없는 헤더: {}", name));
+        }
+        let name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| e.to_string())?;
+        let value = HeaderValue::from_str(value).map_err(|e| e.to_string())?;
+        builder = builder.header(name, value);
+    }
+    if let Some(token) = token {
+        builder = builder.header(AUTHORIZATION, format!("Bearer {}", token));
+    }
+    if let Some(body) = request.body {
+        builder = builder.body(body);
+    }
+
+    let response = builder
+        .send()
+        .await
+        .map_err(|e| format!("API 요청 실패: {}", e.without_url()))?;
+    let status = response.status().as_u16();
+    let headers = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.as_str().to_string(), value.to_string()))
+        })
+        .collect();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("API 응답 읽기 실패: {}", e.without_url()))?;
+
+    Ok(ApiResponse {
+        status,
+        headers,
+        body,
+    })
+}
+
+/// 백엔드 API 호출 프록시. 로그인 중이면 토큰을 붙이고, 아니면 토큰 없이 보낸다 (회원가입 등).
+/// 로그인은 `login` 명령으로 한다
+#[tauri::command]
+pub async fn api_request(
+    state: tauri::State<'_, AuthState>,
+    request: ApiRequest,
+) -> Result<ApiResponse, String> {
+    if state.is_locked() {
+        return Err("세션이 잠겨 있습니다".to_string());
+    }
+    if state.is_offline() {
+        return Err("오프라인 세션에서는 서버에 요청할 수 없습니다".to_string());
+    }
+    let config = state.backend.lock().unwrap().clone();
+    let client = state.http.client().clone();
+    let token = state.bearer_token(chrono::Utc::now());
+    send(&client, &config, token.as_deref(), request).await
+}
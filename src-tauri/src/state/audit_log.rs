@@ -0,0 +1,459 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\audit_log.rs
+//! 인증 감사 로그. JSON Lines 파일에 추가만 하고, 각 레코드는 직전 레코드의 해시를 포함한다.
+//! 해시는 설치별 마스터 키(OS 키 저장소)에서 유도한 키의 HMAC-SHA256 이라 키 없이는 다시 계산할 수 없다.
+//! 중간 레코드를 고치거나 지우면 이후 해시 체인이 끊기므로 `verify` 로 찾아낼 수 있다.
+//! 단, 파일 끝 레코드를 잘라 내면 남은 체인은 그대로 온전하므로 파일만으로는 알 수 없다.
+//! 잘림까지 확인하려면 검증 결과의 `records` / `head_hash` 를 파일 밖에 보관해 둔 값과 비교해야 한다.
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
+
+use super::auth_state::{AuthState, LogoutReason};
+use super::idle_lock::LockReason;
+use super::impersonation::ImpersonationEndReason;
+use super::keystore::{derive_key, install_master_key};
+use super::totp::SecondFactorMethod;
+
+pub const AUDIT_LOG_FILE_NAME: &str = "audit_log.jsonl";
+/// 내보낸 사본을 두는 디렉터리 (감사 로그와 같은 app data 디렉터리 아래)
+pub const AUDIT_EXPORT_DIR_NAME: &str = "audit_exports";
+
+/// 키 유도용 도메인 구분자 (형식이 바뀌면 버전을 올린다)
+const KEY_CONTEXT: &[u8] = b"nexus-call-hub/audit-log/v1";
+
+/// 감사 로그 검증 / 내보내기에 필요한 권한
+pub const AUDIT_VIEW_PERMISSION: &str = "audit:view";
+
+/// 첫 레코드의 prev_hash
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 감사 대상 이벤트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AuditEvent {
+    Login,
+    /// 저장된 자격 증명으로 오프라인 로그인
+    OfflineLogin,
+    OfflineLoginFailed {
+        remaining: u32,
+    },
+    /// 오프라인 세션이 서버 로그인에 성공해 정상 세션으로 전환
+    SessionUpgraded,
+    /// 앱 시작 시 저장된 세션 복원
+    SessionRestored,
+    Logout {
+        reason: LogoutReason,
+    },
+    TokenRefreshed,
+    TokenRefreshFailed {
+        error: String,
+    },
+    /// 윈도우 / 명령 권한 거부
+    PermissionDenied {
+        target: String,
+        reason: String,
+    },
+    SessionLocked {
+        reason: LockReason,
+    },
+    SessionUnlocked,
+    UnlockFailed {
+        remaining: u32,
+    },
+    /// 대리 세션 시작 (`user_id` 는 대상 사용자, `actor_id` 는 시작한 사람)
+    ImpersonationStarted {
+        reason: Option<String>,
+    },
+    ImpersonationEnded {
+        reason: ImpersonationEndReason,
+    },
+    /// 인증 앱 등록 확정 (관리자가 대신 등록하면 `target_user_id` 가 본인과 다르다)
+    TotpEnrolled {
+        target_user_id: String,
+    },
+    SecondFactorVerified {
+        method: SecondFactorMethod,
+    },
+    SecondFactorFailed {
+        remaining: u32,
+    },
+}
+
+/// 파일 한 줄
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub seq: u64,
+    pub at: DateTime<Utc>,
+    pub user_id: Option<String>,
+    /// 대리 세션 중이면 실제로 조작한 사용자 (아니면 생략)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    pub window: Option<String>,
+    #[serde(flatten)]
+    pub event: AuditEvent,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+/// 마스터 키에서 감사 로그 HMAC 키 유도
+pub fn audit_key(master_key: &[u8]) -> Zeroizing<[u8; 32]> {
+    derive_key(master_key, KEY_CONTEXT)
+}
+
+/// 해시 계산 대상 (hash 필드를 뺀 레코드)
+#[derive(Serialize)]
+struct HashedFields<'a> {
+    seq: u64,
+    at: &'a DateTime<Utc>,
+    user_id: &'a Option<String>,
+    actor_id: &'a Option<String>,
+    window: &'a Option<String>,
+    #[serde(flatten)]
+    event: &'a AuditEvent,
+    prev_hash: &'a str,
+}
+
+impl AuditRecord {
+    /// `key` (`audit_key`) 로 계산한 HMAC-SHA256
+    pub fn compute_hash(&self, key: &[u8]) -> String {
+        let fields = HashedFields {
+            seq: self.seq,
+            at: &self.at,
+            user_id: &self.user_id,
+            actor_id: &self.actor_id,
+            window: &self.window,
+            event: &self.event,
+            prev_hash: &self.prev_hash,
+        };
+        let bytes = serde_json::to_vec(&fields).unwrap_or_default();
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC 키 길이는 제한이 없다");
+        mac.update(&bytes);
+        to_hex(&mac.finalize().into_bytes())
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// `verify_audit_log` 결과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditVerification {
+    pub valid: bool,
+    /// 검증을 통과한 레코드 수
+    pub records: u64,
+    /// 마지막 정상 레코드의 해시
+    pub head_hash: String,
+    /// 문제가 처음 발견된 줄 (1부터)
+    pub broken_at_line: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// 파일 전체를 처음부터 검증. 수정 / 중간 삭제 / 순서 변경은 `broken_at_line` 으로 보고하지만
+/// 끝부분이 잘린 파일은 유효하다고 나온다 (모듈 문서 참고). `key` 는 `audit_key` 결과
+pub fn verify_file(path: &Path, key: &[u8]) -> Result<AuditVerification, String> {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(AuditVerification {
+                valid: true,
+                records: 0,
+                head_hash: GENESIS_HASH.to_string(),
+                broken_at_line: None,
+                error: None,
+            })
+        }
+        Err(e) => return Err(e.to_string()),
+    };
+
+    let mut prev_hash = GENESIS_HASH.to_string();
+    let mut records = 0u64;
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| e.to_string())?;
+        let line_no = index as u64 + 1;
+        let broken = |error: String| AuditVerification {
+            valid: false,
+            records,
+            head_hash: prev_hash.clone(),
+            broken_at_line: Some(line_no),
+            error: Some(error),
+        };
+
+        let record: AuditRecord = match serde_json::from_str(&line) {
+            Ok(record) => record,
+            Err(e) => return Ok(broken(format!("레코드 파싱 실패: {}", e))),
+        };
+        if record.seq != records + 1 {
+            return Ok(broken(format!(
+                "순번 불일치: {} (기대값 {})",
+                record.seq,
+                records + 1
+            )));
+        }
+        if record.prev_hash != prev_hash {
+            return Ok(broken("이전 레코드 해시와 연결되지 않습니다".to_string()));
+        }
+        if record.compute_hash(key) != record.hash {
+            return Ok(broken("레코드 내용이 해시와 일치하지 않습니다".to_string()));
+        }
+        prev_hash = record.hash;
+        records += 1;
+    }
+
+    Ok(AuditVerification {
+        valid: true,
+        records,
+        head_hash: prev_hash,
+        broken_at_line: None,
+        error: None,
+    })
+}
+
+/// 추가 전용 로그 파일
+pub struct AuditLog {
+    path: Option<PathBuf>,
+    key: Zeroizing<[u8; 32]>,
+    last_seq: u64,
+    last_hash: String,
+}
+
+impl Default for AuditLog {
+    fn default() -> Self {
+        Self {
+            path: None,
+            key: Zeroizing::new([0u8; 32]),
+            last_seq: 0,
+            last_hash: GENESIS_HASH.to_string(),
+        }
+    }
+}
+
+impl AuditLog {
+    /// 이 설치의 마스터 키(OS 키 저장소)로 HMAC 키를 만들어 연다
+    pub fn for_install(path: PathBuf) -> Result<Self, String> {
+        let master_key = install_master_key()?;
+        Ok(Self::open(path, &master_key))
+    }
+
+    /// 기존 파일 끝에 이어서 기록. 체인이 깨져 있어도 기록은 계속하고 경고만 남긴다.
+    /// 마지막 레코드가 이 키로 쓴 것이 아니면 (키 없는 이전 형식 / 다른 설치) 파일을 보관해 두고 새 체인을 시작한다
+    pub fn open(path: PathBuf, master_key: &[u8]) -> Self {
+        let key = audit_key(master_key);
+        let (last_seq, last_hash) = match last_record(&path) {
+            Ok(Some(record)) if record.compute_hash(key.as_slice()) != record.hash => {
+                match archive_foreign_log(&path, Utc::now()) {
+                    Ok(archived) => println!(
+                        "🚨 [AUDIT] 이 설치의 키로 쓰지 않은 감사 로그를 보관하고 새로 시작합니다: {}",
+                        archived.display()
+                    ),
+                    Err(e) => println!("⚠️ [AUDIT] 이전 감사 로그 보관 실패: {}", e),
+                }
+                (0, GENESIS_HASH.to_string())
+            }
+            Ok(Some(record)) => (record.seq, record.hash),
+            Ok(None) => (0, GENESIS_HASH.to_string()),
+            Err(e) => {
+                println!(
+                    "⚠️ [AUDIT] 감사 로그 마지막 레코드를 읽지 못했습니다: {}",
+                    e
+                );
+                (0, GENESIS_HASH.to_string())
+            }
+        };
+        println!("📜 [AUDIT] 감사 로그: 레코드 {}개", last_seq);
+        Self {
+            path: Some(path),
+            key,
+            last_seq,
+            last_hash,
+        }
+    }
+
+    /// 파일 전체의 해시 체인 검증 (`verify_file` 참고)
+    pub fn verify(&self) -> Result<AuditVerification, String> {
+        let path = self
+            .path
+            .as_deref()
+            .ok_or_else(|| "감사 로그가 열려 있지 않습니다".to_string())?;
+        verify_file(path, self.key.as_slice())
+    }
+
+    /// 검증 후 `audit_exports/audit_log-<시각>.jsonl` 새 파일로 복사. 같은 이름의 파일이 있으면 덮어쓰지 않고 실패한다
+    pub fn export(&self, now: DateTime<Utc>) -> Result<(PathBuf, AuditVerification), String> {
+        let verification = self.verify()?;
+        let path = self.path.as_deref().unwrap_or(Path::new(""));
+        let dir = path
+            .parent()
+            .ok_or_else(|| "감사 로그 위치를 알 수 없습니다".to_string())?
+            .join(AUDIT_EXPORT_DIR_NAME);
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        let destination = dir.join(format!("audit_log-{}.jsonl", now.format("%Y%m%d-%H%M%S")));
+
+        let mut exported = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&destination)
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::AlreadyExists => format!(
+                    "이미 같은 이름의 내보낸 파일이 있습니다: {}",
+                    destination.display()
+                ),
+                _ => e.to_string(),
+            })?;
+        match fs::File::open(path) {
+            Ok(mut source) => {
+                std::io::copy(&mut source, &mut exported).map_err(|e| e.to_string())?;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.to_string()),
+        }
+        exported.sync_all().map_err(|e| e.to_string())?;
+        Ok((destination, verification))
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// 레코드 추가 후 디스크에 반영
+    pub fn append(
+        &mut self,
+        event: AuditEvent,
+        user_id: Option<String>,
+        actor_id: Option<String>,
+        window: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Result<AuditRecord, String> {
+        let mut record = AuditRecord {
+            seq: self.last_seq + 1,
+            at: now,
+            user_id,
+            actor_id,
+            window,
+            event,
+            prev_hash: self.last_hash.clone(),
+            hash: String::new(),
+        };
+        record.hash = record.compute_hash(self.key.as_slice());
+
+        if let Some(path) = &self.path {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+            }
+            let mut line = serde_json::to_string(&record).map_err(|e| e.to_string())?;
+            line.push('\n');
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map_err(|e| e.to_string())?;
+            file.write_all(line.as_bytes())
+                .and_then(|_| file.sync_data())
+                .map_err(|e| e.to_string())?;
+        }
+
+        self.last_seq = record.seq;
+        self.last_hash = record.hash.clone();
+        Ok(record)
+    }
+}
+
+/// `audit_log.jsonl` → `audit_log.jsonl.<시각>.old`
+fn archive_foreign_log(path: &Path, now: DateTime<Utc>) -> Result<PathBuf, String> {
+    let mut archived = path.as_os_str().to_os_string();
+    archived.push(format!(".{}.old", now.format("%Y%m%d-%H%M%S")));
+    let archived = PathBuf::from(archived);
+    fs::rename(path, &archived).map_err(|e| e.to_string())?;
+    Ok(archived)
+}
+
+fn last_record(path: &Path) -> Result<Option<AuditRecord>, String> {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.to_string()),
+    };
+    let mut last = None;
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| e.to_string())?;
+        if !line.trim().is_empty() {
+            last = Some(line);
+        }
+    }
+    last.map(|line| serde_json::from_str(&line).map_err(|e| e.to_string()))
+        .transpose()
+}
+
+// === Tauri 연동 ===
+
+/// `export_audit_log` 응답
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditExport {
+    pub path: String,
+    pub verification: AuditVerification,
+}
+
+/// 감사 로그 조회 권한 확인. 거부도 감사 로그에 남긴다
+fn require_audit_permission(
+    state: &AuthState,
+    window: &tauri::Window,
+    target: &str,
+) -> Result<(), String> {
+    if state.has_permission(AUDIT_VIEW_PERMISSION) {
+        return Ok(());
+    }
+    state.audit(
+        Some(window.label()),
+        AuditEvent::PermissionDenied {
+            target: target.to_string(),
+            reason: format!("{} 권한 없음", AUDIT_VIEW_PERMISSION),
+        },
+    );
+    Err("감사 로그를 볼 권한이 없습니다".to_string())
+}
+
+/// 해시 체인 검증 (`audit:view` 권한 필요)
+#[tauri::command]
+pub fn verify_audit_log(
+    window: tauri::Window,
+    state: tauri::State<AuthState>,
+) -> Result<AuditVerification, String> {
+    require_audit_permission(&state, &window, "verify_audit_log")?;
+    let verification = state.audit.lock().unwrap().verify()?;
+    if verification.valid {
+        println!("📜 [AUDIT] 감사 로그 검증 통과: {}개", verification.records);
+    } else {
+        println!(
+            "🚨 [AUDIT] 감사 로그 변조 감지: {}번째 줄 ({})",
+            verification.broken_at_line.unwrap_or_default(),
+            verification.error.as_deref().unwrap_or_default()
+        );
+    }
+    Ok(verification)
+}
+
+/// 감사 로그를 app data 의 `audit_exports` 디렉터리에 새 파일로 복사 (JSON Lines 원본 + 검증 결과 반환).
+/// 저장 위치는 웹뷰가 정하지 않고, 기존 파일은 덮어쓰지 않는다
+#[tauri::command]
+pub fn export_audit_log(
+    window: tauri::Window,
+    state: tauri::State<AuthState>,
+) -> Result<AuditExport, String> {
+    require_audit_permission(&state, &window, "export_audit_log")?;
+    // 검증과 복사 사이에 기록이 끼어들지 않도록 잠근 채로 진행
+    let (destination, verification) = state.audit.lock().unwrap().export(Utc::now())?;
+    println!(
+        "📤 [AUDIT] 감사 로그 내보내기: {} ({}개)",
+        destination.display(),
+        verification.records
+    );
+    Ok(AuditExport {
+        path: destination.to_string_lossy().into_owned(),
+        verification,
+    })
+}
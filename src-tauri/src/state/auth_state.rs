@@ -1,7 +1,37 @@
 // C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\auth_state.rs
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::sync::Mutex;
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use super::audit_log::{AuditEvent, AuditLog};
+use super::backend::{BackendClient, BackendConfig};
+use super::idle_lock::{IdleLock, LockReason};
+use super::impersonation::{self, ImpersonationEndReason, SavedSession};
+use super::jwt::{decode_claims, TokenClaims};
+use super::offline_login::{
+    request_login, LoginError, OfflineCredentials, OfflineSession, OFFLINE_FALLBACK_SECS,
+};
+use super::rbac::{is_granted, RbacPolicy};
+use super::secret::Secret;
+use super::session_store::{PersistedSession, SessionStore};
+use super::token_refresh::{refresh_with_backoff, RefreshedTokens};
+use super::totp::TotpStore;
+
+// === 인증 이벤트 계약 ===
+
+/// payload: `SessionUser` (대리 세션 시작 / 종료 시에도 발송)
+pub const USER_LOGGED_IN_EVENT: &str = "user-logged-in";
+/// payload: `LogoutEvent`
+pub const USER_LOGGED_OUT_EVENT: &str = "user-logged-out";
+/// payload: `SessionExpiring` (만료 `SESSION_EXPIRY_WARNING_SECS` 초 전)
+pub const SESSION_EXPIRING_EVENT: &str = "session-expiring";
+/// payload: `SessionUser` (갱신된 사용자 정보, 토큰은 가려짐)
+pub const TOKEN_REFRESHED_EVENT: &str = "token-refreshed";
+
+/// 만료 몇 초 전에 `session-expiring` 을 보낼지
+pub const SESSION_EXPIRY_WARNING_SECS: i64 = 120;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
@@ -10,97 +40,844 @@ pub struct User {
     pub email: Option<String>,
     pub department: String,
     pub role: String,
-    pub token: String,
+    /// 직렬화 / Debug 출력에서 가려진다 (이벤트 / 로그로 나가지 않음)
+    pub token: Secret<String>,
     pub permissions: Option<Vec<String>>,
 }
 
+/// 대리 세션을 시작한 실제 사용자
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Impersonator {
+    pub id: String,
+    pub name: String,
+    pub role: String,
+    /// 현재 대리 세션 시작 시각
+    pub started_at: DateTime<Utc>,
+}
+
+/// 이벤트 / 조회로 내보내는 사용자 정보. JSON 으로는 `User` 필드에 `impersonated_by` 가 추가된 형태
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionUser {
+    #[serde(flatten)]
+    pub user: User,
+    /// 대리 세션이면 원래 사용자, 아니면 null
+    pub impersonated_by: Option<Impersonator>,
+    /// 서버 없이 저장된 자격 증명으로 로그인한 세션 (권한 제한, 서버 연결 시 정상 세션으로 전환)
+    pub offline: bool,
+    /// 비밀번호는 통과했지만 2단계 인증을 기다리는 중 (이 동안은 세션이 유효하지 않다)
+    pub second_factor_pending: bool,
+}
+
+/// 로그아웃 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogoutReason {
+    /// 사용자가 직접 로그아웃
+    UserRequested,
+    /// 토큰 만료
+    TokenExpired,
+    /// 토큰 갱신 실패
+    RefreshFailed,
+    /// 잠금 해제 시도 횟수 초과
+    UnlockFailed,
+    /// 오프라인 세션의 자격 증명을 서버가 거부
+    OfflineRejected,
+    /// 2단계 인증 시도 횟수 초과
+    SecondFactorFailed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogoutEvent {
+    pub reason: LogoutReason,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionExpiring {
+    pub expires_at: DateTime<Utc>,
+    pub seconds_remaining: i64,
+}
+
 #[derive(Default)]
 pub struct AuthState {
     pub user: Mutex<Option<User>>,
     pub is_authenticated: Mutex<bool>,
+    /// 현재 토큰의 claims
+    pub claims: Mutex<Option<TokenClaims>>,
+    /// 이벤트로 내보내지 않도록 `User` 와 따로 보관
+    pub refresh_token: Mutex<Option<String>>,
+    pub backend: Mutex<BackendConfig>,
+    pub http: BackendClient,
+    /// 로그인 / 로그아웃마다 증가. 이전 세션의 만료 타이머를 무효화하는 데 사용
+    pub session_generation: Mutex<u64>,
+    /// 암호화된 세션 파일 (OS 키 저장소를 못 쓰면 None 이고 메모리에만 유지)
+    pub session_store: Mutex<Option<SessionStore>>,
+    /// 역할 / 권한 정책
+    pub policy: Mutex<RbacPolicy>,
+    /// 자리 비움 잠금
+    pub idle: Mutex<IdleLock>,
+    /// 오프라인 로그인용 자격 증명 (마지막 온라인 로그인의 Argon2 해시 + 사용자 정보)
+    pub offline: Mutex<OfflineCredentials>,
+    /// 오프라인 세션이면 Some
+    pub offline_session: Mutex<Option<OfflineSession>>,
+    /// 마지막 `login` 이 서버에 닿지 않았으면 (아이디, 시각). 오프라인 로그인은 이 직후에만 허용
+    online_login_unreachable: Mutex<Option<(String, DateTime<Utc>)>>,
+    /// 대리 세션 스택. 대리 세션을 시작할 때마다 그 전 세션을 넣고, 끝낼 때 꺼내서 되돌린다
+    pub impersonation: Mutex<Vec<SavedSession>>,
+    /// 2단계 인증 등록 정보
+    pub totp: Mutex<TotpStore>,
+    /// 2단계 인증 대기 중이면 true. 통과하기 전까지 `is_session_valid` 는 false
+    pub second_factor_pending: Mutex<bool>,
+    /// 인증 감사 로그. 다른 잠금보다 항상 나중에 잠근다
+    pub audit: Mutex<AuditLog>,
 }
 
-// === 핵심 인증 함수들만 유지 ===
+impl AuthState {
+    /// 백엔드 접속 설정 로드 (앱 시작 시 1회)
+    pub fn load_backend_config(&self, path: std::path::PathBuf) {
+        *self.backend.lock().unwrap() = BackendConfig::load(path);
+    }
 
-// 🔐 사용자 상태 설정 (로그인)
-#[tauri::command]
-pub fn set_user_state(
-    app: AppHandle,
-    state: tauri::State<AuthState>,
-    user: User,
-) -> Result<(), String> {
-    *state.user.lock().unwrap() = Some(user.clone());
-    *state.is_authenticated.lock().unwrap() = true;
+    /// 감사 로그 열기 (앱 시작 시 1회, 세션 복원 전에).
+    /// OS 키 저장소를 못 쓰면 HMAC 키를 만들 수 없으므로 파일에 기록하지 않는다
+    pub fn open_audit_log(&self, path: std::path::PathBuf) {
+        match AuditLog::for_install(path) {
+            Ok(log) => *self.audit.lock().unwrap() = log,
+            Err(e) => println!("⚠️ [AUDIT] 감사 로그를 열 수 없습니다: {}", e),
+        }
+    }
 
-    // 모든 윈도우에 로그인 이벤트 발송
-    let _ = app.emit("user-logged-in", &user);
+    /// 감사 레코드 추가. 기록 실패는 인증 흐름을 막지 않고 로그만 남긴다
+    pub(crate) fn audit_as(
+        &self,
+        user_id: Option<String>,
+        actor_id: Option<String>,
+        window: Option<&str>,
+        event: AuditEvent,
+    ) {
+        let appended = self.audit.lock().unwrap().append(
+            event,
+            user_id,
+            actor_id,
+            window.map(str::to_string),
+            Utc::now(),
+        );
+        if let Err(e) = appended {
+            println!("⚠️ [AUDIT] 감사 로그 기록 실패: {}", e);
+        }
+    }
 
-    println!("✅ [AUTH] 로그인: {} ({})", user.name, user.role);
-    Ok(())
+    /// 현재 로그인한 사용자로 감사 레코드 추가 (대리 세션이면 원래 사용자도 함께)
+    pub(crate) fn audit(&self, window: Option<&str>, event: AuditEvent) {
+        let user_id = self.user.lock().unwrap().as_ref().map(|u| u.id.clone());
+        let actor_id = self.impersonator_id();
+        self.audit_as(user_id, actor_id, window, event);
+    }
+
+    /// 대리 세션 중이면 원래 사용자 id
+    pub fn impersonator_id(&self) -> Option<String> {
+        self.impersonation
+            .lock()
+            .unwrap()
+            .first()
+            .map(|saved| saved.user.id.clone())
+    }
+
+    /// 실제로 앱을 조작하는 사용자 id (대리 세션이면 원래 사용자)
+    pub fn actor_id(&self) -> Option<String> {
+        self.impersonator_id()
+            .or_else(|| self.user.lock().unwrap().as_ref().map(|u| u.id.clone()))
+    }
+
+    pub fn is_impersonating(&self) -> bool {
+        !self.impersonation.lock().unwrap().is_empty()
+    }
+
+    /// 이벤트 / 조회용 현재 사용자
+    pub fn session_user(&self) -> Option<SessionUser> {
+        let user = self.user.lock().unwrap().clone()?;
+        let stack = self.impersonation.lock().unwrap();
+        let impersonated_by = stack
+            .first()
+            .zip(stack.last())
+            .map(|(actor, top)| Impersonator {
+                id: actor.user.id.clone(),
+                name: actor.user.name.clone(),
+                role: actor.user.role.clone(),
+                started_at: top.started_at,
+            });
+        drop(stack);
+        Some(SessionUser {
+            user,
+            impersonated_by,
+            offline: self.is_offline(),
+            second_factor_pending: self.is_second_factor_pending(),
+        })
+    }
+
+    /// 자리 비움 잠금 설정 로드 (앱 시작 시 1회, 세션 복원 전에)
+    pub fn load_idle_lock(&self, path: std::path::PathBuf) {
+        *self.idle.lock().unwrap() = IdleLock::load(path);
+    }
+
+    /// 오프라인 로그인 자격 증명 로드 (앱 시작 시 1회)
+    pub fn load_offline_credentials(&self, path: std::path::PathBuf) {
+        *self.offline.lock().unwrap() = OfflineCredentials::load(path);
+    }
+
+    /// 온라인 로그인에 성공한 자격 증명을 오프라인 로그인용으로 저장. 실패해도 로그인은 계속한다
+    pub(crate) fn remember_credentials(&self, username: &str, password: &str, user: &User) {
+        let remembered =
+            self.offline
+                .lock()
+                .unwrap()
+                .remember(username, password, user, Utc::now());
+        if let Err(e) = remembered {
+            println!("⚠️ [AUTH] 오프라인 자격 증명 저장 실패: {}", e);
+        }
+    }
+
+    /// `login` 결과 기록. 서버에 닿지 않았을 때만 오프라인 로그인 창이 열린다
+    pub fn record_online_login(&self, username: &str, unreachable: bool, now: DateTime<Utc>) {
+        *self.online_login_unreachable.lock().unwrap() =
+            unreachable.then(|| (username.trim().to_string(), now));
+    }
+
+    /// 같은 아이디의 온라인 로그인이 `OFFLINE_FALLBACK_SECS` 안에 서버에 닿지 못했는지.
+    /// 확인하면 기록을 지우므로 온라인 시도 한 번에 오프라인 로그인도 한 번이다
+    pub fn take_offline_fallback(&self, username: &str, now: DateTime<Utc>) -> bool {
+        self.online_login_unreachable
+            .lock()
+            .unwrap()
+            .take()
+            .is_some_and(|(failed, at)| {
+                failed == username.trim()
+                    && now >= at
+                    && (now - at).num_seconds() <= OFFLINE_FALLBACK_SECS
+            })
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline_session.lock().unwrap().is_some()
+    }
+
+    /// 2단계 인증 등록 정보 로드 (앱 시작 시 1회).
+    /// OS 키 저장소를 못 쓰면 비밀 키를 암호화할 수 없으므로 파일에 저장하지 않는다
+    pub fn load_totp(&self, path: std::path::PathBuf) {
+        match TotpStore::for_install(path) {
+            Ok(store) => *self.totp.lock().unwrap() = store,
+            Err(e) => println!("⚠️ [MFA] 2단계 인증 등록 정보를 열 수 없습니다: {}", e),
+        }
+    }
+
+    pub fn is_second_factor_pending(&self) -> bool {
+        *self.second_factor_pending.lock().unwrap()
+    }
+
+    /// 현재 사용자 역할(상속 포함)이 2단계 인증을 요구하는지
+    pub fn second_factor_required(&self) -> bool {
+        let user = self.user.lock().unwrap();
+        let Some(user) = user.as_ref() else {
+            return false;
+        };
+        self.policy
+            .lock()
+            .unwrap()
+            .requires_second_factor([user.role.as_str()])
+    }
+
+    /// 로그인 직후 호출. 2단계 인증이 필요한 역할이면 세션을 대기 상태로 두고
+    /// 통과할 때까지 세션 파일에 저장하지 않는다. 대기 여부 반환
+    pub(crate) fn hold_for_second_factor(&self) -> bool {
+        let required = self.second_factor_required();
+        *self.second_factor_pending.lock().unwrap() = required;
+        if required {
+            if let Some(user) = self.user.lock().unwrap().as_ref() {
+                self.totp.lock().unwrap().reset_failures(&user.id);
+            }
+            self.wipe_session();
+        }
+        required
+    }
+
+    /// 2단계 인증 통과. 대기 중이었으면 세션을 저장하고 true
+    pub(crate) fn complete_second_factor(&self) -> bool {
+        let was_pending =
+            std::mem::replace(&mut *self.second_factor_pending.lock().unwrap(), false);
+        if was_pending {
+            self.idle.lock().unwrap().reset(Utc::now());
+            self.persist_current_session();
+        }
+        was_pending
+    }
+
+    /// 역할 / 권한 정책 로드 (앱 시작 시 1회)
+    pub fn load_rbac_policy(&self, path: std::path::PathBuf) {
+        *self.policy.lock().unwrap() = RbacPolicy::load(path);
+    }
+
+    /// 현재 사용자의 유효 권한 (`User.role` 을 상속까지 펼친 것 + 개별 권한).
+    /// 오프라인 세션이면 정책의 `offline_permissions` 로 제한된다
+    pub fn effective_permissions(&self) -> BTreeSet<String> {
+        let permissions = {
+            let user = self.user.lock().unwrap();
+            let Some(user) = user.as_ref() else {
+                return BTreeSet::new();
+            };
+            self.permissions_of(user)
+        };
+        if self.is_offline() {
+            return self
+                .policy
+                .lock()
+                .unwrap()
+                .restrict_to_offline(&permissions);
+        }
+        permissions
+    }
+
+    /// 주어진 사용자의 유효 권한. 토큰 claims 의 roles 는 서명을 검증하지 않은 값이라 쓰지 않는다
+    pub fn permissions_of(&self, user: &User) -> BTreeSet<String> {
+        self.policy.lock().unwrap().effective_permissions(
+            [user.role.as_str()],
+            user.permissions.as_deref().unwrap_or_default(),
+        )
+    }
+
+    pub fn has_permission(&self, permission: &str) -> bool {
+        is_granted(&self.effective_permissions(), permission)
+    }
+
+    /// 저장된 세션을 복원하고 검증 (앱 시작 시 1회). 검증 실패 시 파일 삭제
+    pub fn restore_session(&self, path: std::path::PathBuf) {
+        let store = match SessionStore::for_install(path) {
+            Ok(store) => store,
+            Err(e) => {
+                println!("⚠️ [AUTH] 세션 저장소를 사용할 수 없습니다: {}", e);
+                return;
+            }
+        };
+
+        match store.load().and_then(|session| match session {
+            Some(session) => {
+                validate_session(&session.user, Utc::now()).map(|claims| Some((session, claims)))
+            }
+            None => Ok(None),
+        }) {
+            Ok(Some((session, claims))) => {
+                println!(
+                    "🔓 [AUTH] 세션 복원: {} ({})",
+                    session.user.name, session.user.role
+                );
+                self.begin_session(session.user, claims, session.refresh_token);
+                self.audit(None, AuditEvent::SessionRestored);
+                // 잠긴 채로 종료했으면 재시작해도 잠금 유지
+                let mut idle = self.idle.lock().unwrap();
+                idle.reset(Utc::now());
+                if session.locked {
+                    idle.lock(Utc::now(), LockReason::Restored);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                println!("⚠️ [AUTH] 저장된 세션 폐기: {}", e);
+                if let Err(e) = store.wipe() {
+                    println!("⚠️ [AUTH] 세션 파일 삭제 실패: {}", e);
+                }
+            }
+        }
+        *self.session_store.lock().unwrap() = Some(store);
+    }
+
+    /// 정상(온라인) 세션 시작 후 저장. 남아 있던 대리 세션 스택 / 오프라인 세션은 버린다
+    pub fn begin_online_session(
+        &self,
+        user: User,
+        claims: TokenClaims,
+        refresh_token: Option<String>,
+    ) -> u64 {
+        self.impersonation.lock().unwrap().clear();
+        *self.offline_session.lock().unwrap() = None;
+        *self.second_factor_pending.lock().unwrap() = false;
+        let generation = self.begin_session(user, claims, refresh_token);
+        self.persist_current_session();
+        generation
+    }
+
+    /// 오프라인 세션 시작. 토큰이 없으므로 세션 파일에는 저장하지 않고 기존 파일도 지운다
+    pub(crate) fn begin_offline_session(
+        &self,
+        user: User,
+        claims: TokenClaims,
+        session: OfflineSession,
+    ) -> u64 {
+        self.impersonation.lock().unwrap().clear();
+        *self.offline_session.lock().unwrap() = Some(session);
+        *self.second_factor_pending.lock().unwrap() = false;
+        self.wipe_session();
+        self.begin_session(user, claims, None)
+    }
+
+    /// 새 세션 시작. 이전 세션의 만료 타이머는 세대 번호가 달라져서 무시된다
+    fn begin_session(&self, user: User, claims: TokenClaims, refresh_token: Option<String>) -> u64 {
+        *self.user.lock().unwrap() = Some(user);
+        *self.claims.lock().unwrap() = Some(claims);
+        *self.refresh_token.lock().unwrap() = refresh_token;
+        *self.is_authenticated.lock().unwrap() = true;
+        self.next_generation()
+    }
+
+    /// 현재 세션을 스택에 넣고 대리 세션으로 전환. 새 세션 세대 번호 반환
+    pub fn push_session(
+        &self,
+        user: User,
+        claims: TokenClaims,
+        refresh_token: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Result<u64, String> {
+        let saved = SavedSession {
+            user: self
+                .user
+                .lock()
+                .unwrap()
+                .clone()
+                .ok_or_else(|| "로그인 상태가 아닙니다".to_string())?,
+            claims: self.claims.lock().unwrap().clone().unwrap_or_default(),
+            refresh_token: self.refresh_token.lock().unwrap().clone(),
+            started_at: now,
+        };
+        self.impersonation.lock().unwrap().push(saved);
+        Ok(self.begin_session(user, claims, refresh_token))
+    }
+
+    /// 가장 최근 대리 세션을 끝내고 그 전 세션을 그대로 되돌린다. (끝난 대리 세션 사용자, 되돌린 사용자)
+    pub fn pop_session(&self) -> Option<(User, User)> {
+        let saved = self.impersonation.lock().unwrap().pop()?;
+        let ended = self.user.lock().unwrap().clone()?;
+        self.begin_session(saved.user.clone(), saved.claims, saved.refresh_token);
+        Some((ended, saved.user))
+    }
+
+    /// 갱신된 토큰으로 교체하고 저장. 새 토큰도 검증을 통과해야 한다
+    fn apply_refreshed_tokens(
+        &self,
+        tokens: RefreshedTokens,
+        now: DateTime<Utc>,
+    ) -> Result<User, String> {
+        let mut user = self
+            .user
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| "로그인 상태가 아닙니다".to_string())?;
+        user.token = Secret::new(tokens.access_token);
+        let claims = validate_session(&user, now)?;
+        let refresh_token = tokens
+            .refresh_token
+            .or_else(|| self.refresh_token.lock().unwrap().clone());
+        self.begin_session(user.clone(), claims, refresh_token);
+        self.persist_current_session();
+        Ok(user)
+    }
+
+    fn clear_session(&self) -> Option<User> {
+        self.impersonation.lock().unwrap().clear();
+        *self.offline_session.lock().unwrap() = None;
+        let user = self.user.lock().unwrap().take();
+        *self.claims.lock().unwrap() = None;
+        *self.refresh_token.lock().unwrap() = None;
+        *self.is_authenticated.lock().unwrap() = false;
+        *self.second_factor_pending.lock().unwrap() = false;
+        self.next_generation();
+        self.idle.lock().unwrap().reset(Utc::now());
+        self.wipe_session();
+        user
+    }
+
+    fn next_generation(&self) -> u64 {
+        let mut generation = self.session_generation.lock().unwrap();
+        *generation += 1;
+        *generation
+    }
+
+    pub(crate) fn generation(&self) -> u64 {
+        *self.session_generation.lock().unwrap()
+    }
+
+    /// 토큰 만료 시각 (exp 가 없으면 None)
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.claims
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(TokenClaims::expires_at)
+    }
+
+    pub fn is_session_valid(&self, now: DateTime<Utc>) -> bool {
+        let is_auth = *self.is_authenticated.lock().unwrap();
+        let has_user = self.user.lock().unwrap().is_some();
+        let expired = self
+            .claims
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_none_or(|claims| claims.is_expired(now));
+        is_auth && has_user && !expired && !self.is_second_factor_pending()
+    }
+
+    /// 유효한 세션의 access 토큰 (`api_request` 에서 헤더로만 사용). 오프라인 세션은 토큰이 없다
+    pub fn bearer_token(&self, now: DateTime<Utc>) -> Option<String> {
+        if !self.is_session_valid(now) || self.is_offline() {
+            return None;
+        }
+        self.user
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|user| user.token.expose().clone())
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.idle.lock().unwrap().locked().is_some()
+    }
+
+    /// 현재 세션을 저장. 대리 세션 중이면 원래 사용자 세션을 저장해서 재시작 시 대리 세션으로 복원되지 않게 한다
+    pub(crate) fn persist_current_session(&self) {
+        if self.is_offline() || self.is_second_factor_pending() {
+            return;
+        }
+        let root = self
+            .impersonation
+            .lock()
+            .unwrap()
+            .first()
+            .map(|saved| (saved.user.clone(), saved.refresh_token.clone()));
+        let (user, refresh_token) = match root {
+            Some(root) => root,
+            None => {
+                let Some(user) = self.user.lock().unwrap().clone() else {
+                    return;
+                };
+                (user, self.refresh_token.lock().unwrap().clone())
+            }
+        };
+
+        if let Some(store) = self.session_store.lock().unwrap().as_ref() {
+            let session = PersistedSession::new(user, refresh_token, self.is_locked());
+            if let Err(e) = store.save(&session) {
+                println!("⚠️ [AUTH] 세션 저장 실패: {}", e);
+            }
+        }
+    }
+
+    fn wipe_session(&self) {
+        if let Some(store) = self.session_store.lock().unwrap().as_ref() {
+            if let Err(e) = store.wipe() {
+                println!("⚠️ [AUTH] 세션 파일 삭제 실패: {}", e);
+            }
+        }
+    }
 }
 
-// 🔐 사용자 정보 조회
-#[tauri::command]
-pub fn get_user_state(state: tauri::State<AuthState>) -> Option<User> {
-    state.user.lock().unwrap().clone()
+/// 세션이 쓸 수 있는 상태인지 확인하고 토큰 claims 반환
+pub(crate) fn validate_session(user: &User, now: DateTime<Utc>) -> Result<TokenClaims, String> {
+    if user.id.trim().is_empty() {
+        return Err("사용자 id 가 비어 있습니다".to_string());
+    }
+    if user.token.expose().trim().is_empty() {
+        return Err("토큰이 비어 있습니다".to_string());
+    }
+    let claims = decode_claims(user.token.expose())?;
+    if claims.is_expired(now) {
+        return Err("토큰이 만료되었습니다".to_string());
+    }
+    Ok(claims)
 }
 
-// 🔐 로그아웃
-#[tauri::command]
-pub fn logout_user(app: AppHandle, state: tauri::State<AuthState>) -> Result<(), String> {
-    let user_name = state
-        .user
-        .lock()
-        .unwrap()
-        .as_ref()
-        .map(|u| u.name.clone())
-        .unwrap_or_else(|| "Unknown".to_string());
+/// 현재 세션의 타이머 예약. refresh 토큰이 있으면 만료 전에 갱신하고,
+/// 없으면 만료 경고 후 자동 로그아웃. 그 사이 세션이 바뀌면 아무것도 하지 않는다
+pub fn schedule_session_timers<R: Runtime>(app: AppHandle<R>) {
+    let state = app.state::<AuthState>();
+    let generation = state.generation();
+    let Some(expires_at) = state.expires_at() else {
+        return;
+    };
+    let refresh_token = state.refresh_token.lock().unwrap().clone();
+    let backend = state.backend.lock().unwrap().clone();
 
-    *state.user.lock().unwrap() = None;
-    *state.is_authenticated.lock().unwrap() = false;
+    tauri::async_runtime::spawn(async move {
+        let is_current = |app: &AppHandle<R>| app.state::<AuthState>().generation() == generation;
 
-    // 모든 윈도우에 로그아웃 이벤트 발송
-    let _ = app.emit("user-logged-out", ());
+        if let Some(refresh_token) = refresh_token {
+            sleep_until(expires_at - chrono::Duration::seconds(backend.refresh_ahead_secs)).await;
+            if is_current(&app) {
+                refresh_session(&app, generation, &backend, &refresh_token).await;
+            }
+            return;
+        }
 
-    println!("✅ [AUTH] 로그아웃: {}", user_name);
-    Ok(())
+        let warn_at = expires_at - chrono::Duration::seconds(SESSION_EXPIRY_WARNING_SECS);
+        sleep_until(warn_at).await;
+        if !is_current(&app) {
+            return;
+        }
+        let remaining = (expires_at - Utc::now()).num_seconds();
+        if remaining > 0 {
+            let _ = app.emit(
+                SESSION_EXPIRING_EVENT,
+                SessionExpiring {
+                    expires_at,
+                    seconds_remaining: remaining,
+                },
+            );
+            println!("⏳ [AUTH] 세션 만료 {}초 전", remaining);
+        }
+
+        sleep_until(expires_at).await;
+        if !is_current(&app) {
+            return;
+        }
+        let state = app.state::<AuthState>();
+        if state.is_impersonating() {
+            // 대리 세션 토큰만 만료된 것이므로 원래 세션으로 돌아간다
+            impersonation::end_impersonation_session(
+                &app,
+                &state,
+                None,
+                ImpersonationEndReason::TokenExpired,
+            );
+        } else {
+            end_session(&app, &state, None, LogoutReason::TokenExpired);
+        }
+    });
 }
 
-// 🔐 인증 상태 확인
-#[tauri::command]
-pub fn is_authenticated(state: tauri::State<AuthState>) -> bool {
-    let is_auth = *state.is_authenticated.lock().unwrap();
-    let has_user = state.user.lock().unwrap().is_some();
-    is_auth && has_user
+/// 토큰 갱신 후 모든 윈도우에 새 토큰 발송. 실패하면 로그아웃
+async fn refresh_session<R: Runtime>(
+    app: &AppHandle<R>,
+    generation: u64,
+    backend: &BackendConfig,
+    refresh_token: &str,
+) {
+    let client = app.state::<AuthState>().http.client().clone();
+    let result = refresh_with_backoff(&client, backend, refresh_token).await;
+
+    let state = app.state::<AuthState>();
+    if state.generation() != generation {
+        // 갱신하는 사이 로그아웃 / 재로그인
+        return;
+    }
+    match result
+        .map_err(|e| e.to_string())
+        .and_then(|tokens| state.apply_refreshed_tokens(tokens, Utc::now()))
+    {
+        Ok(user) => {
+            state.audit(None, AuditEvent::TokenRefreshed);
+            let _ = app.emit(TOKEN_REFRESHED_EVENT, state.session_user());
+            println!("🔄 [AUTH] 토큰 갱신: {}", user.name);
+            schedule_session_timers(app.clone());
+        }
+        Err(e) => {
+            println!("⛔ [AUTH] {}", e);
+            state.audit(None, AuditEvent::TokenRefreshFailed { error: e });
+            if state.is_impersonating() {
+                impersonation::end_impersonation_session(
+                    app,
+                    &state,
+                    None,
+                    ImpersonationEndReason::RefreshFailed,
+                );
+            } else {
+                end_session(app, &state, None, LogoutReason::RefreshFailed);
+            }
+        }
+    }
 }
 
-// 🔐 권한 확인
-#[tauri::command]
-pub fn check_permission(state: tauri::State<AuthState>, permission: String) -> bool {
-    if let Some(user) = state.user.lock().unwrap().as_ref() {
-        if let Some(permissions) = &user.permissions {
-            permissions.contains(&permission)
-        } else {
-            false
+async fn sleep_until(at: DateTime<Utc>) {
+    if let Ok(wait) = (at - Utc::now()).to_std() {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// 세션 정리 + 파일 삭제 + 감사 기록 + 모든 윈도우에 로그아웃 이벤트 발송.
+/// 대리 세션 중이면 원래 세션까지 모두 끝난다.
+/// `window` 는 로그아웃을 요청한 윈도우 (타이머에 의한 로그아웃이면 None)
+pub(crate) fn end_session<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AuthState,
+    window: Option<&str>,
+    reason: LogoutReason,
+) {
+    let actor_id = state.impersonator_id();
+    let user = state.clear_session();
+    state.audit_as(
+        user.as_ref().map(|u| u.id.clone()),
+        actor_id,
+        window,
+        AuditEvent::Logout { reason },
+    );
+    let user_name = user
+        .map(|u| u.name)
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let _ = app.emit(USER_LOGGED_OUT_EVENT, LogoutEvent { reason });
+
+    match reason {
+        LogoutReason::UserRequested => println!("✅ [AUTH] 로그아웃: {}", user_name),
+        LogoutReason::TokenExpired => println!("⌛ [AUTH] 토큰 만료로 로그아웃: {}", user_name),
+        LogoutReason::RefreshFailed => {
+            println!("⌛ [AUTH] 토큰 갱신 실패로 로그아웃: {}", user_name)
+        }
+        LogoutReason::UnlockFailed => {
+            println!("🔒 [AUTH] 잠금 해제 실패 초과로 로그아웃: {}", user_name)
+        }
+        LogoutReason::OfflineRejected => {
+            println!(
+                "⛔ [AUTH] 오프라인 자격 증명 거부로 로그아웃: {}",
+                user_name
+            )
         }
+        LogoutReason::SecondFactorFailed => {
+            println!("⛔ [AUTH] 2단계 인증 실패 초과로 로그아웃: {}", user_name)
+        }
+    }
+}
+
+// === 핵심 인증 함수들만 유지 ===
+
+/// 온라인 세션 시작 + 감사 기록 + 만료 타이머 예약.
+/// 2단계 인증이 필요한 역할이면 `second_factor_pending` 인 상태로 반환되고,
+/// `verify_second_factor` 를 통과해야 세션이 유효해지고 `user-logged-in` 이 발송된다
+pub(crate) fn start_online_session<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AuthState,
+    window: Option<&str>,
+    user: User,
+    refresh_token: Option<String>,
+) -> Result<SessionUser, String> {
+    let claims = validate_session(&user, Utc::now())?;
+    state.idle.lock().unwrap().reset(Utc::now());
+    state.begin_online_session(user.clone(), claims, refresh_token);
+    let pending = state.hold_for_second_factor();
+    state.audit(window, AuditEvent::Login);
+    schedule_session_timers(app.clone());
+
+    let session_user = state.session_user().ok_or("로그인하지 못했습니다")?;
+    if pending {
+        println!(
+            "🔐 [AUTH] 로그인, 2단계 인증 대기: {} ({})",
+            user.name, user.role
+        );
     } else {
-        false
+        // 모든 윈도우에 로그인 이벤트 발송
+        let _ = app.emit(USER_LOGGED_IN_EVENT, &session_user);
+        println!("✅ [AUTH] 로그인: {} ({})", user.name, user.role);
     }
+    Ok(session_user)
 }
 
-// === 호환성을 위한 별칭들 (기존 코드가 사용) ===
+// 🔐 로그인. Rust 가 백엔드에 직접 로그인하고 토큰 / refresh 토큰은 AuthState 에만 둔다 (웹뷰로 나가지 않음).
+// 성공하면 오프라인 로그인용 자격 증명(Argon2 해시)도 저장한다. 서버에 닿지 않았으면 잠시 `offline_login` 을 허용한다.
+// 실패는 `kind` 가 `unreachable`(서버에 닿지 않음) / `rejected`(자격 증명 거부) 인 객체로 전달
+#[tauri::command]
+pub async fn login<R: Runtime>(
+    app: AppHandle<R>,
+    window: tauri::Window<R>,
+    username: String,
+    password: String,
+) -> Result<SessionUser, LoginError> {
+    let username = username.trim().to_string();
+    let password = Secret::new(password);
+    if username.is_empty() || password.expose().is_empty() {
+        return Err(LoginError::Rejected(
+            "아이디와 비밀번호가 필요합니다".to_string(),
+        ));
+    }
+    let (config, client) = {
+        let state = app.state::<AuthState>();
+        let config = state.backend.lock().unwrap().clone();
+        (config, state.http.client().clone())
+    };
 
+    let result = request_login(&client, &config, &username, password.expose()).await;
+    app.state::<AuthState>().record_online_login(
+        &username,
+        matches!(result, Err(LoginError::Unreachable(_))),
+        Utc::now(),
+    );
+    let tokens = result.inspect_err(|e| println!("⛔ [AUTH] 로그인 실패: {}", e))?;
+    // 토큰에 sub 가 없으면 아이디를 사용자 id 로 쓴다
+    let subject = decode_claims(&tokens.access_token)
+        .map_err(LoginError::Rejected)?
+        .sub
+        .unwrap_or_else(|| username.clone());
+    let user = User {
+        id: subject,
+        name: username.clone(),
+        email: None,
+        department: "고객상담팀".to_string(), // 추후 백엔드 /auth/me 에서 받아올 예정
+        role: "상담원".to_string(),           // 추후 백엔드 /auth/me 에서 받아올 예정
+        token: Secret::new(tokens.access_token),
+        permissions: None,
+    };
+
+    let state = app.state::<AuthState>();
+    let session_user = start_online_session(
+        &app,
+        &state,
+        Some(window.label()),
+        user.clone(),
+        tokens.refresh_token,
+    )
+    .map_err(LoginError::Rejected)?;
+    state.remember_credentials(&username, password.expose(), &user);
+    Ok(session_user)
+}
+
+// 🔐 사용자 정보 조회 (대리 세션이면 `impersonated_by` 포함)
 #[tauri::command]
-pub fn login_user(
+pub fn get_user_state(state: tauri::State<AuthState>) -> Option<SessionUser> {
+    state.session_user()
+}
+
+// 🔐 로그아웃
+#[tauri::command]
+pub fn logout_user(
     app: AppHandle,
+    window: tauri::Window,
     state: tauri::State<AuthState>,
-    user: User,
 ) -> Result<(), String> {
-    set_user_state(app, state, user)
+    end_session(
+        &app,
+        &state,
+        Some(window.label()),
+        LogoutReason::UserRequested,
+    );
+    Ok(())
+}
+
+// 🔐 인증 상태 확인 (토큰 만료 포함)
+#[tauri::command]
+pub fn is_authenticated(state: tauri::State<AuthState>) -> bool {
+    state.is_session_valid(Utc::now())
+}
+
+// 🔐 권한 확인 (역할 상속 + 와일드카드 적용)
+#[tauri::command]
+pub fn check_permission(state: tauri::State<AuthState>, permission: String) -> bool {
+    state.has_permission(&permission)
 }
 
+// 🔐 현재 사용자의 유효 권한 목록 (상속 / 개별 권한을 펼친 결과, 정렬됨)
+#[tauri::command]
+pub fn get_effective_permissions(state: tauri::State<AuthState>) -> Vec<String> {
+    state.effective_permissions().into_iter().collect()
+}
+
+// === 호환성을 위한 별칭들 (기존 코드가 사용) ===
+
 #[tauri::command]
-pub fn get_user(state: tauri::State<AuthState>) -> Option<User> {
+pub fn get_user(state: tauri::State<AuthState>) -> Option<SessionUser> {
     get_user_state(state)
 }
 
@@ -109,15 +886,19 @@ pub fn is_logged_in(state: tauri::State<AuthState>) -> bool {
     is_authenticated(state)
 }
 
-// 🔍 디버깅 전용 (개발 중에만 사용)
+// 🔍 디버깅 전용. `debug-commands` feature 로 빌드할 때만 포함 (토큰은 가려져 있음)
+#[cfg(feature = "debug-commands")]
 #[tauri::command]
 pub fn get_auth_debug_info(state: tauri::State<AuthState>) -> serde_json::Value {
-    let user = state.user.lock().unwrap().clone();
+    let user = state.session_user();
     let is_authenticated = *state.is_authenticated.lock().unwrap();
 
     serde_json::json!({
         "user": user,
         "is_authenticated": is_authenticated,
-        "has_valid_session": user.is_some() && is_authenticated
+        "claims": state.claims.lock().unwrap().clone(),
+        "has_refresh_token": state.refresh_token.lock().unwrap().is_some(),
+        "second_factor_pending": state.is_second_factor_pending(),
+        "has_valid_session": state.is_session_valid(Utc::now())
     })
 }
@@ -0,0 +1,198 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\backend.rs
+//! 백엔드(Spring) 접속 설정. app data 디렉터리의 `backend.json` 으로 덮어쓸 수 있다
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+pub const BACKEND_CONFIG_FILE_NAME: &str = "backend.json";
+
+/// 백엔드 호출용 HTTP 클라이언트. 리다이렉트로 허용 목록 밖 주소에 가지 않도록 따라가지 않는다
+#[derive(Debug, Clone)]
+pub struct BackendClient(reqwest::Client);
+
+impl Default for BackendClient {
+    fn default() -> Self {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap_or_default();
+        Self(client)
+    }
+}
+
+impl BackendClient {
+    pub fn client(&self) -> &reqwest::Client {
+        &self.0
+    }
+}
+
+/// 토큰 갱신 재시도 정책 (지수 백오프)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff_millis: u64,
+    pub max_backoff_millis: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff_millis: 1_000,
+            max_backoff_millis: 30_000,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `attempt` 번째(1부터) 실패 후 기다릴 시간
+    pub fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let factor = 2u64.saturating_pow(attempt.saturating_sub(1));
+        let millis = self
+            .initial_backoff_millis
+            .saturating_mul(factor)
+            .min(self.max_backoff_millis);
+        std::time::Duration::from_millis(millis)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackendConfig {
+    pub base_url: String,
+    /// `api_request` 가 base_url 외에 추가로 호출할 수 있는 주소
+    #[serde(default)]
+    pub allowed_base_urls: Vec<String>,
+    #[serde(default = "default_refresh_path")]
+    pub refresh_path: String,
+    /// 잠금 해제 시 비밀번호 확인에 사용
+    #[serde(default = "default_login_path")]
+    pub login_path: String,
+    /// 대리 세션 토큰 발급
+    #[serde(default = "default_impersonate_path")]
+    pub impersonate_path: String,
+    /// 토큰 만료 몇 초 전에 갱신할지
+    #[serde(default = "default_refresh_ahead_secs")]
+    pub refresh_ahead_secs: i64,
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    #[serde(default)]
+    pub retry: RetryPolicy,
+}
+
+fn default_refresh_path() -> String {
+    "/auth/refresh".to_string()
+}
+
+fn default_login_path() -> String {
+    "/auth/login".to_string()
+}
+
+fn default_impersonate_path() -> String {
+    "/auth/impersonate".to_string()
+}
+
+fn default_refresh_ahead_secs() -> i64 {
+    300
+}
+
+fn default_request_timeout_secs() -> u64 {
+    10
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        // 프론트엔드 `shared/api/client.ts` 와 같은 서버. localhost 가 아니면 https 만 허용한다
+        let base_url = if cfg!(debug_assertions) {
+            "http://localhost:8080"
+        } else {
+            "https://43.200.234.52"
+        };
+        Self {
+            base_url: base_url.to_string(),
+            allowed_base_urls: Vec::new(),
+            refresh_path: default_refresh_path(),
+            login_path: default_login_path(),
+            impersonate_path: default_impersonate_path(),
+            refresh_ahead_secs: default_refresh_ahead_secs(),
+            request_timeout_secs: default_request_timeout_secs(),
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+impl BackendConfig {
+    /// 설정 파일이 없거나 깨졌거나 `validate` 를 통과하지 못하면 기본값
+    pub fn load(path: PathBuf) -> Self {
+        let config: Self = match fs::read(&path) {
+            Ok(bytes) => match serde_json::from_slice(&bytes) {
+                Ok(config) => config,
+                Err(e) => {
+                    println!("⚠️ [AUTH] 백엔드 설정 파싱 실패, 기본값 사용: {}", e);
+                    return Self::default();
+                }
+            },
+            Err(_) => return Self::default(),
+        };
+        match config.validate() {
+            Ok(()) => config,
+            Err(e) => {
+                println!("⛔ [AUTH] 백엔드 설정 거부, 기본값 사용: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// base_url / allowed_base_urls 는 모두 올바른 URL 이어야 하고,
+    /// 토큰과 비밀번호가 오가므로 localhost 가 아니면 https 여야 한다
+    pub fn validate(&self) -> Result<(), String> {
+        for base in std::iter::once(&self.base_url).chain(self.allowed_base_urls.iter()) {
+            let url = reqwest::Url::parse(base)
+                .map_err(|e| format!("잘못된 백엔드 주소 {}: {}", base, e))?;
+            match url.scheme() {
+                "https" => {}
+                "http" if is_loopback_host(url.host_str()) => {}
+                "http" => {
+                    return Err(format!(
+                        "localhost 가 아닌 백엔드 주소는 https 여야 합니다: {}",
+                        base
+                    ))
+                }
+                scheme => {
+                    return Err(format!(
+                        "지원하지 않는 백엔드 주소 스킴 {}: {}",
+                        scheme, base
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    pub fn refresh_url(&self) -> String {
+        self.url(&self.refresh_path)
+    }
+
+    pub fn request_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.request_timeout_secs)
+    }
+}
+
+fn is_loopback_host(host: Option<&str>) -> bool {
+    match host {
+        Some("localhost") => true,
+        Some(host) => host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback()),
+        None => false,
+    }
+}
@@ -0,0 +1,27 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\clock.rs
+use chrono::{DateTime, Duration, Utc};
+
+/// 수동으로 진행시키는 시계. 라우팅 / 시뮬레이션을 결정적으로 재현할 때 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulatedClock {
+    now: DateTime<Utc>,
+}
+
+impl SimulatedClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self { now: start }
+    }
+
+    pub fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+
+    pub fn advance(&mut self, by: Duration) -> DateTime<Utc> {
+        self.now += by;
+        self.now
+    }
+
+    pub fn advance_secs(&mut self, secs: i64) -> DateTime<Utc> {
+        self.advance(Duration::seconds(secs))
+    }
+}
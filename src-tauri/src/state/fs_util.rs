@@ -0,0 +1,37 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\fs_util.rs
+//! 상태 파일 저장용 공통 파일 유틸리티
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+/// 임시 파일에 쓴 뒤 rename 해서 저장 중 종료되어도 파일이 깨지지 않게 한다
+pub(crate) fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, bytes).map_err(|e| e.to_string())?;
+    fs::rename(&tmp, path).map_err(|e| e.to_string())
+}
+
+/// 파일 내용을 0 으로 덮어쓰고 디스크에 반영한 뒤 삭제. 파일이 없으면 아무것도 하지 않는다
+pub(crate) fn wipe_file(path: &Path) -> Result<(), String> {
+    let len = match fs::metadata(path) {
+        Ok(meta) => meta.len() as usize,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    let zeros = vec![0u8; len];
+    let overwritten = OpenOptions::new()
+        .write(true)
+        .open(path)
+        .and_then(|mut file| {
+            file.write_all(&zeros)?;
+            file.sync_all()
+        });
+    if let Err(e) = overwritten {
+        println!("⚠️ [FS] 파일 덮어쓰기 실패 ({}): {}", path.display(), e);
+    }
+    fs::remove_file(path).map_err(|e| e.to_string())
+}
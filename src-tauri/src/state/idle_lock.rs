@@ -0,0 +1,465 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\idle_lock.rs
+//! 자리 비움 잠금. 윈도우가 보고하는 사용자 활동을 기준으로 일정 시간 입력이 없으면 세션을 잠근다.
+//! 통화 중에는 입력이 없어도 잠그지 않는다. 잠금 해제는 PIN 또는 비밀번호 재입력.
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::audit_log::AuditEvent;
+use super::auth_state::{end_session, AuthState, LogoutReason};
+use super::backend::BackendConfig;
+use super::fs_util::write_atomic;
+use super::offline_login::{request_login, LoginError};
+use super::queue_state::QueueState;
+
+pub const IDLE_LOCK_FILE_NAME: &str = "idle_lock.json";
+
+/// payload: `SessionLocked`
+pub const SESSION_LOCKED_EVENT: &str = "session-locked";
+/// payload: 없음
+pub const SESSION_UNLOCKED_EVENT: &str = "session-unlocked";
+
+/// 잠금 해제 실패가 이 횟수에 도달하면 로그아웃
+pub const MAX_UNLOCK_ATTEMPTS: u32 = 5;
+pub const MIN_IDLE_TIMEOUT_SECS: u64 = 30;
+pub const MIN_PIN_LEN: usize = 4;
+
+const IDLE_TICK_SECS: u64 = 5;
+
+/// 저장되는 잠금 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdleLockSettings {
+    pub enabled: bool,
+    pub idle_timeout_secs: u64,
+    /// 사용자 id → PIN 의 Argon2 해시 (PHC 문자열)
+    #[serde(default)]
+    pub pins: BTreeMap<String, String>,
+}
+
+impl Default for IdleLockSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            idle_timeout_secs: 300,
+            pins: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockReason {
+    /// 입력 없음
+    Idle,
+    /// 사용자가 직접 잠금
+    Manual,
+    /// 잠긴 채로 앱이 재시작됨
+    Restored,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionLocked {
+    pub locked_at: DateTime<Utc>,
+    pub reason: LockReason,
+}
+
+/// `get_lock_status` 응답
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockStatus {
+    pub locked: Option<SessionLocked>,
+    pub enabled: bool,
+    pub idle_timeout_secs: u64,
+    pub idle_secs: i64,
+    pub has_pin: bool,
+    pub failed_attempts: u32,
+}
+
+/// `unlock_session` 입력
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UnlockCredential {
+    Pin { pin: String },
+    Password { password: String },
+}
+
+/// 잠금 해제 시도 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnlockOutcome {
+    Unlocked,
+    /// 틀림. 남은 시도 횟수
+    Rejected {
+        remaining: u32,
+    },
+    /// 시도 횟수 초과
+    Exhausted,
+}
+
+/// 잠금 상태 엔진. 시각은 인자로 받는다
+#[derive(Debug, Default)]
+pub struct IdleLock {
+    settings: IdleLockSettings,
+    path: Option<PathBuf>,
+    last_activity: Option<DateTime<Utc>>,
+    locked: Option<SessionLocked>,
+    failed_attempts: u32,
+}
+
+impl IdleLock {
+    /// 설정 파일 로드. 없으면 기본값 (5분)
+    pub fn load(path: PathBuf) -> Self {
+        let settings = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                println!("⚠️ [LOCK] 잠금 설정 파싱 실패, 기본값 사용: {}", e);
+                IdleLockSettings::default()
+            }),
+            Err(_) => IdleLockSettings::default(),
+        };
+        Self {
+            settings,
+            path: Some(path),
+            ..Self::default()
+        }
+    }
+
+    pub fn settings(&self) -> &IdleLockSettings {
+        &self.settings
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let bytes = serde_json::to_vec_pretty(&self.settings).map_err(|e| e.to_string())?;
+        write_atomic(path, &bytes)
+    }
+
+    pub fn set_idle_timeout(
+        &mut self,
+        enabled: bool,
+        idle_timeout_secs: u64,
+    ) -> Result<(), String> {
+        if idle_timeout_secs < MIN_IDLE_TIMEOUT_SECS {
+            return Err(format!(
+                "잠금 시간은 {}초 이상이어야 합니다",
+                MIN_IDLE_TIMEOUT_SECS
+            ));
+        }
+        self.settings.enabled = enabled;
+        self.settings.idle_timeout_secs = idle_timeout_secs;
+        self.persist()
+    }
+
+    /// PIN 설정 (숫자 `MIN_PIN_LEN` 자리 이상). 평문은 저장하지 않는다
+    pub fn set_pin(&mut self, user_id: &str, pin: &str) -> Result<(), String> {
+        if pin.len() < MIN_PIN_LEN || !pin.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("PIN 은 숫자 {}자리 이상이어야 합니다", MIN_PIN_LEN));
+        }
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = Argon2::default()
+            .hash_password(pin.as_bytes(), &salt)
+            .map_err(|e| e.to_string())?
+            .to_string();
+        self.settings.pins.insert(user_id.to_string(), hash);
+        self.persist()
+    }
+
+    pub fn has_pin(&self, user_id: &str) -> bool {
+        self.settings.pins.contains_key(user_id)
+    }
+
+    pub fn verify_pin(&self, user_id: &str, pin: &str) -> bool {
+        self.settings
+            .pins
+            .get(user_id)
+            .and_then(|hash| PasswordHash::new(hash).ok())
+            .is_some_and(|hash| {
+                Argon2::default()
+                    .verify_password(pin.as_bytes(), &hash)
+                    .is_ok()
+            })
+    }
+
+    /// 로그인 / 로그아웃 시 초기화
+    pub fn reset(&mut self, now: DateTime<Utc>) {
+        self.last_activity = Some(now);
+        self.locked = None;
+        self.failed_attempts = 0;
+    }
+
+    pub fn locked(&self) -> Option<&SessionLocked> {
+        self.locked.as_ref()
+    }
+
+    /// 사용자 활동 기록. 잠긴 동안의 활동은 무시한다
+    pub fn record_activity(&mut self, now: DateTime<Utc>) {
+        if self.locked.is_none() {
+            self.last_activity = Some(now);
+        }
+    }
+
+    /// 잠금. 이미 잠겨 있으면 None
+    pub fn lock(&mut self, now: DateTime<Utc>, reason: LockReason) -> Option<SessionLocked> {
+        if self.locked.is_some() {
+            return None;
+        }
+        let locked = SessionLocked {
+            locked_at: now,
+            reason,
+        };
+        self.locked = Some(locked.clone());
+        self.failed_attempts = 0;
+        Some(locked)
+    }
+
+    /// 유휴 시간이 지났으면 잠근다. 통화 중이면 그 시점까지를 활동으로 본다
+    pub fn check_idle(&mut self, now: DateTime<Utc>, on_call: bool) -> Option<SessionLocked> {
+        if on_call {
+            self.record_activity(now);
+            return None;
+        }
+        if !self.settings.enabled || self.locked.is_some() {
+            return None;
+        }
+        let last_activity = *self.last_activity.get_or_insert(now);
+        let idle_secs = (now - last_activity).num_seconds();
+        if idle_secs >= self.settings.idle_timeout_secs as i64 {
+            self.lock(now, LockReason::Idle)
+        } else {
+            None
+        }
+    }
+
+    /// 자격 증명 확인 결과를 반영
+    pub fn complete_unlock(&mut self, verified: bool, now: DateTime<Utc>) -> UnlockOutcome {
+        if verified {
+            self.reset(now);
+            return UnlockOutcome::Unlocked;
+        }
+        self.failed_attempts += 1;
+        if self.failed_attempts >= MAX_UNLOCK_ATTEMPTS {
+            UnlockOutcome::Exhausted
+        } else {
+            UnlockOutcome::Rejected {
+                remaining: MAX_UNLOCK_ATTEMPTS - self.failed_attempts,
+            }
+        }
+    }
+
+    pub fn status(&self, user_id: Option<&str>, now: DateTime<Utc>) -> LockStatus {
+        LockStatus {
+            locked: self.locked.clone(),
+            enabled: self.settings.enabled,
+            idle_timeout_secs: self.settings.idle_timeout_secs,
+            idle_secs: self
+                .last_activity
+                .map(|at| (now - at).num_seconds().max(0))
+                .unwrap_or(0),
+            has_pin: user_id.is_some_and(|id| self.has_pin(id)),
+            failed_attempts: self.failed_attempts,
+        }
+    }
+}
+
+/// 로그인 엔드포인트로 비밀번호 확인 (새 토큰은 쓰지 않는다)
+pub async fn verify_password(
+    client: &reqwest::Client,
+    config: &BackendConfig,
+    username: &str,
+    password: &str,
+) -> Result<bool, String> {
+    match request_login(client, config, username, password).await {
+        Ok(_) => Ok(true),
+        Err(LoginError::Rejected(_)) => Ok(false),
+        Err(LoginError::Unreachable(e)) => Err(format!("비밀번호 확인 실패: {}", e)),
+    }
+}
+
+// === Tauri 연동 ===
+
+fn emit_locked(app: &AppHandle, state: &AuthState, window: Option<&str>, locked: &SessionLocked) {
+    state.persist_current_session();
+    state.audit(
+        window,
+        AuditEvent::SessionLocked {
+            reason: locked.reason,
+        },
+    );
+    println!("🔒 [LOCK] 세션 잠금 ({:?})", locked.reason);
+    let _ = app.emit(SESSION_LOCKED_EVENT, locked);
+}
+
+/// 로그인한 상담원이 통화 중(링 / 통화)인지
+fn is_user_on_call(app: &AppHandle, user_id: &str) -> bool {
+    app.state::<QueueState>().is_agent_on_call(user_id)
+}
+
+/// 주기적으로 유휴 시간 확인
+pub fn start_idle_monitor(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(IDLE_TICK_SECS)).await;
+
+            let auth = app.state::<AuthState>();
+            let now = Utc::now();
+            if !auth.is_session_valid(now) {
+                continue;
+            }
+            let Some(user_id) = auth.user.lock().unwrap().as_ref().map(|u| u.id.clone()) else {
+                continue;
+            };
+            let on_call = is_user_on_call(&app, &user_id);
+            let locked = auth.idle.lock().unwrap().check_idle(now, on_call);
+            if let Some(locked) = locked {
+                emit_locked(&app, &auth, None, &locked);
+            }
+        }
+    });
+}
+
+/// 윈도우에서 키보드 / 마우스 입력이 있을 때 호출 (프론트엔드에서 throttle)
+#[tauri::command]
+pub fn report_activity(state: tauri::State<AuthState>) {
+    state.idle.lock().unwrap().record_activity(Utc::now());
+}
+
+/// 즉시 잠금
+#[tauri::command]
+pub fn lock_session(
+    app: AppHandle,
+    window: tauri::Window,
+    state: tauri::State<AuthState>,
+) -> Result<(), String> {
+    if !state.is_session_valid(Utc::now()) {
+        return Err("로그인 상태가 아닙니다".to_string());
+    }
+    let locked = state
+        .idle
+        .lock()
+        .unwrap()
+        .lock(Utc::now(), LockReason::Manual);
+    if let Some(locked) = locked {
+        emit_locked(&app, &state, Some(window.label()), &locked);
+    }
+    Ok(())
+}
+
+/// PIN 또는 비밀번호로 잠금 해제. 실패가 `MAX_UNLOCK_ATTEMPTS` 번 쌓이면 로그아웃
+#[tauri::command]
+pub async fn unlock_session(
+    app: AppHandle,
+    window: tauri::Window,
+    state: tauri::State<'_, AuthState>,
+    credential: UnlockCredential,
+) -> Result<bool, String> {
+    if state.idle.lock().unwrap().locked().is_none() {
+        return Ok(true);
+    }
+    // 대리 세션 중이면 실제로 자리에 있는 원래 사용자로 확인한다
+    let user_id = state.actor_id().ok_or("로그인 상태가 아닙니다")?;
+
+    let verified = match credential {
+        UnlockCredential::Pin { pin } => state.idle.lock().unwrap().verify_pin(&user_id, &pin),
+        UnlockCredential::Password { password } => {
+            let config = state.backend.lock().unwrap().clone();
+            let client = state.http.client().clone();
+            verify_password(&client, &config, &user_id, &password).await?
+        }
+    };
+
+    let outcome = state
+        .idle
+        .lock()
+        .unwrap()
+        .complete_unlock(verified, Utc::now());
+    match outcome {
+        UnlockOutcome::Unlocked => {
+            state.persist_current_session();
+            state.audit(Some(window.label()), AuditEvent::SessionUnlocked);
+            println!("🔓 [LOCK] 잠금 해제: {}", user_id);
+            let _ = app.emit(SESSION_UNLOCKED_EVENT, ());
+            Ok(true)
+        }
+        UnlockOutcome::Rejected { remaining } => {
+            state.audit(Some(window.label()), AuditEvent::UnlockFailed { remaining });
+            println!(
+                "⚠️ [LOCK] 잠금 해제 실패: {} (남은 시도 {})",
+                user_id, remaining
+            );
+            Ok(false)
+        }
+        UnlockOutcome::Exhausted => {
+            state.audit(
+                Some(window.label()),
+                AuditEvent::UnlockFailed { remaining: 0 },
+            );
+            end_session(
+                &app,
+                &state,
+                Some(window.label()),
+                LogoutReason::UnlockFailed,
+            );
+            Err("잠금 해제 시도 횟수를 초과해 로그아웃되었습니다".to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_lock_status(state: tauri::State<AuthState>) -> LockStatus {
+    let user_id = state.actor_id();
+    state
+        .idle
+        .lock()
+        .unwrap()
+        .status(user_id.as_deref(), Utc::now())
+}
+
+/// 유휴 잠금 사용 여부 / 시간 설정 (`settings:manage` 권한 필요)
+#[tauri::command]
+pub fn set_idle_timeout(
+    window: tauri::Window,
+    state: tauri::State<AuthState>,
+    enabled: bool,
+    idle_timeout_secs: u64,
+) -> Result<(), String> {
+    if !state.has_permission("settings:manage") {
+        state.audit(
+            Some(window.label()),
+            AuditEvent::PermissionDenied {
+                target: "set_idle_timeout".to_string(),
+                reason: "settings:manage 권한 없음".to_string(),
+            },
+        );
+        return Err("잠금 설정을 바꿀 권한이 없습니다".to_string());
+    }
+    state
+        .idle
+        .lock()
+        .unwrap()
+        .set_idle_timeout(enabled, idle_timeout_secs)?;
+    println!(
+        "⏲️ [LOCK] 유휴 잠금 설정: {} ({}초)",
+        if enabled { "사용" } else { "사용 안 함" },
+        idle_timeout_secs
+    );
+    Ok(())
+}
+
+/// 현재 사용자의 잠금 해제 PIN 설정 (잠긴 상태에서는 불가, 대리 세션이면 원래 사용자 PIN)
+#[tauri::command]
+pub fn set_unlock_pin(state: tauri::State<AuthState>, pin: String) -> Result<(), String> {
+    if !state.is_session_valid(Utc::now()) {
+        return Err("로그인 상태가 아닙니다".to_string());
+    }
+    let user_id = state.actor_id().ok_or("로그인 상태가 아닙니다")?;
+    let mut idle = state.idle.lock().unwrap();
+    if idle.locked().is_some() {
+        return Err("잠긴 상태에서는 PIN 을 바꿀 수 없습니다".to_string());
+    }
+    idle.set_pin(&user_id, &pin)
+}
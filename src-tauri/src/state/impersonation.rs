@@ -0,0 +1,271 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\impersonation.rs
+//! 대리 세션. 팀장 / 슈퍼바이저가 상담원으로 잠시 전환해서 작업한다.
+//! 시작하면 현재 세션을 `AuthState.impersonation` 스택에 넣고, 끝내면 꺼내서 그대로 되돌린다.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use tauri::{AppHandle, Emitter, Runtime};
+
+use super::audit_log::AuditEvent;
+use super::auth_state::{
+    schedule_session_timers, validate_session, AuthState, SessionUser, User, USER_LOGGED_IN_EVENT,
+};
+use super::backend::BackendConfig;
+use super::jwt::TokenClaims;
+use super::rbac::is_granted;
+use super::secret::Secret;
+use super::token_refresh::ApiResponse;
+
+/// 대리 세션 시작에 필요한 권한
+pub const IMPERSONATE_PERMISSION: &str = "agents:impersonate";
+
+/// 대리 세션 시작 전 세션. 끝낼 때 이 값으로 그대로 되돌린다
+pub struct SavedSession {
+    pub user: User,
+    pub claims: TokenClaims,
+    /// 이벤트로 내보내지 않도록 `User` 와 따로 보관
+    pub(crate) refresh_token: Option<String>,
+    /// 이 세션 위에 대리 세션을 시작한 시각
+    pub started_at: DateTime<Utc>,
+}
+
+/// 대리 세션 종료 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImpersonationEndReason {
+    /// 사용자가 직접 종료
+    UserRequested,
+    /// 대리 세션 토큰 만료
+    TokenExpired,
+    /// 대리 세션 토큰 갱신 실패
+    RefreshFailed,
+}
+
+/// 대리 세션 요청 본문
+#[derive(Serialize)]
+struct ImpersonationRequest<'a> {
+    username: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<&'a str>,
+}
+
+/// 백엔드가 발급한 대상 사용자 정보 + 토큰
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImpersonationResponseData {
+    username: String,
+    token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    department: Option<String>,
+    #[serde(default)]
+    role: Option<String>,
+    #[serde(default)]
+    permissions: Option<Vec<String>>,
+}
+
+/// 대리 세션으로 쓸 사용자 / 토큰
+pub struct ImpersonationGrant {
+    pub user: User,
+    pub refresh_token: Option<String>,
+}
+
+/// 백엔드에 대상 사용자 토큰 발급 요청 (요청자 토큰으로 인증)
+pub async fn request_impersonation(
+    client: &reqwest::Client,
+    config: &BackendConfig,
+    bearer_token: &str,
+    username: &str,
+    reason: Option<&str>,
+) -> Result<ImpersonationGrant, String> {
+    let response = client
+        .post(config.url(&config.impersonate_path))
+        .timeout(config.request_timeout())
+        .bearer_auth(bearer_token)
+        .json(&ImpersonationRequest { username, reason })
+        .send()
+        .await
+        .map_err(|e| format!("대리 세션 요청 실패: {}", e.without_url()))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("대리 세션 요청 거절: HTTP {}", status.as_u16()));
+    }
+    let body: ApiResponse<ImpersonationResponseData> = response
+        .json()
+        .await
+        .map_err(|e| format!("대리 세션 응답 읽기 실패: {}", e.without_url()))?;
+    let data = match body {
+        ApiResponse {
+            success: true,
+            data: Some(data),
+            ..
+        } => data,
+        ApiResponse { message, .. } => return Err(format!("대리 세션 요청 거절: {}", message)),
+    };
+
+    Ok(ImpersonationGrant {
+        user: User {
+            id: data.username.clone(),
+            name: data.name.unwrap_or(data.username),
+            email: data.email,
+            department: data.department.unwrap_or_default(),
+            // 역할을 안 주면 가장 낮은 역할로 본다
+            role: data.role.unwrap_or_else(|| "agent".to_string()),
+            token: Secret::new(data.token),
+            permissions: data.permissions,
+        },
+        refresh_token: data.refresh_token,
+    })
+}
+
+/// 대상 사용자 권한이 요청자 권한 안에 들어가는지 (대리 세션으로 권한이 올라가지 않도록)
+pub fn ensure_no_escalation(
+    actor_permissions: &BTreeSet<String>,
+    target_permissions: &BTreeSet<String>,
+) -> Result<(), String> {
+    match target_permissions
+        .iter()
+        .find(|permission| !is_granted(actor_permissions, permission))
+    {
+        Some(permission) => Err(format!(
+            "대상 사용자에게 요청자에게 없는 권한이 있습니다: {}",
+            permission
+        )),
+        None => Ok(()),
+    }
+}
+
+// === Tauri 연동 ===
+
+/// 가장 최근 대리 세션을 끝내고 그 전 세션으로 되돌린 뒤 모든 윈도우에 알린다.
+/// `window` 는 종료를 요청한 윈도우 (타이머에 의한 종료면 None)
+pub(crate) fn end_impersonation_session<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AuthState,
+    window: Option<&str>,
+    reason: ImpersonationEndReason,
+) -> Option<SessionUser> {
+    let actor_id = state.impersonator_id();
+    let (ended, restored) = state.pop_session()?;
+    state.audit_as(
+        Some(ended.id.clone()),
+        actor_id,
+        window,
+        AuditEvent::ImpersonationEnded { reason },
+    );
+    // 되돌린 세션은 대리 세션 동안 타이머가 멈춰 있었으므로 다시 예약
+    schedule_session_timers(app.clone());
+
+    let session_user = state.session_user();
+    let _ = app.emit(USER_LOGGED_IN_EVENT, &session_user);
+    println!(
+        "↩️ [AUTH] 대리 세션 종료: {} → {} ({:?})",
+        ended.name, restored.name, reason
+    );
+    session_user
+}
+
+/// 대리 세션 시작 (`agents:impersonate` 권한 필요). 시작 후 `user-logged-in` 이 `impersonated_by` 와 함께 발송된다
+#[tauri::command]
+pub async fn start_impersonation(
+    app: AppHandle,
+    window: tauri::Window,
+    state: tauri::State<'_, AuthState>,
+    user_id: String,
+    reason: Option<String>,
+) -> Result<SessionUser, String> {
+    let now = Utc::now();
+    if state.is_locked() {
+        return Err("세션이 잠겨 있습니다".to_string());
+    }
+    let bearer_token = state.bearer_token(now).ok_or("로그인 상태가 아닙니다")?;
+    if !state.has_permission(IMPERSONATE_PERMISSION) {
+        state.audit(
+            Some(window.label()),
+            AuditEvent::PermissionDenied {
+                target: "start_impersonation".to_string(),
+                reason: format!("{} 권한 없음", IMPERSONATE_PERMISSION),
+            },
+        );
+        return Err("대리 세션을 시작할 권한이 없습니다".to_string());
+    }
+
+    let user_id = user_id.trim().to_string();
+    let current_id = state.user.lock().unwrap().as_ref().map(|u| u.id.clone());
+    let in_stack = state
+        .impersonation
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|saved| saved.user.id == user_id);
+    if user_id.is_empty() || current_id.as_deref() == Some(user_id.as_str()) || in_stack {
+        return Err("대리할 수 없는 사용자입니다".to_string());
+    }
+    let reason = reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+
+    let generation = state.generation();
+    let config = state.backend.lock().unwrap().clone();
+    let client = state.http.client().clone();
+    let grant =
+        request_impersonation(&client, &config, &bearer_token, &user_id, reason.as_deref()).await?;
+    if state.generation() != generation {
+        // 요청하는 사이 로그아웃 / 재로그인 / 토큰 갱신
+        return Err("세션이 바뀌어 대리 세션을 시작하지 않았습니다".to_string());
+    }
+
+    let claims = validate_session(&grant.user, Utc::now())?;
+    ensure_no_escalation(
+        &state.effective_permissions(),
+        &state.permissions_of(&grant.user),
+    )?;
+
+    let target_name = grant.user.name.clone();
+    state.push_session(grant.user, claims, grant.refresh_token, Utc::now())?;
+    state.audit(
+        Some(window.label()),
+        AuditEvent::ImpersonationStarted { reason },
+    );
+    schedule_session_timers(app.clone());
+
+    let session_user = state
+        .session_user()
+        .ok_or("대리 세션을 시작하지 못했습니다")?;
+    let _ = app.emit(USER_LOGGED_IN_EVENT, &session_user);
+    println!(
+        "🎭 [AUTH] 대리 세션 시작: {} → {}",
+        session_user
+            .impersonated_by
+            .as_ref()
+            .map(|actor| actor.name.as_str())
+            .unwrap_or_default(),
+        target_name
+    );
+    Ok(session_user)
+}
+
+/// 가장 최근 대리 세션 종료. 원래 세션(사용자 / 토큰 / refresh 토큰)을 그대로 되돌린다
+#[tauri::command]
+pub fn end_impersonation(
+    app: AppHandle,
+    window: tauri::Window,
+    state: tauri::State<AuthState>,
+) -> Result<SessionUser, String> {
+    if !state.is_impersonating() {
+        return Err("대리 세션 중이 아닙니다".to_string());
+    }
+    end_impersonation_session(
+        &app,
+        &state,
+        Some(window.label()),
+        ImpersonationEndReason::UserRequested,
+    )
+    .ok_or_else(|| "대리 세션을 종료하지 못했습니다".to_string())
+}
@@ -0,0 +1,75 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\jwt.rs
+//! JWT payload(claims) 디코딩. 서명 검증은 백엔드 몫이고 여기서는 만료 추적에 필요한 값만 읽는다
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenClaims {
+    #[serde(default)]
+    pub sub: Option<String>,
+    /// 만료 시각 (unix 초)
+    #[serde(default)]
+    pub exp: Option<i64>,
+    /// 발급 시각 (unix 초)
+    #[serde(default)]
+    pub iat: Option<i64>,
+    /// 배열 또는 콤마 구분 문자열 둘 다 허용.
+    /// 서명을 검증하지 않은 값이므로 표시용으로만 쓰고 권한 판단에는 쓰지 않는다
+    #[serde(default, deserialize_with = "roles_from_array_or_string")]
+    pub roles: Vec<String>,
+}
+
+impl TokenClaims {
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.exp.and_then(|exp| Utc.timestamp_opt(exp, 0).single())
+    }
+
+    pub fn issued_at(&self) -> Option<DateTime<Utc>> {
+        self.iat.and_then(|iat| Utc.timestamp_opt(iat, 0).single())
+    }
+
+    /// exp 가 없는 토큰은 만료되지 않는 것으로 본다
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at().is_some_and(|exp| exp <= now)
+    }
+}
+
+fn roles_from_array_or_string<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Roles {
+        List(Vec<String>),
+        Joined(String),
+    }
+
+    Ok(match Option::<Roles>::deserialize(deserializer)? {
+        Some(Roles::List(roles)) => roles,
+        Some(Roles::Joined(roles)) => roles
+            .split(',')
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .map(str::to_string)
+            .collect(),
+        None => Vec::new(),
+    })
+}
+
+/// `header.payload.signature` 형식의 토큰에서 payload 디코딩
+pub fn decode_claims(token: &str) -> Result<TokenClaims, String> {
+    let mut parts = token.split('.');
+    let (Some(_header), Some(payload), Some(_signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err("JWT 형식이 아닙니다".to_string());
+    };
+
+    let bytes = URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|e| format!("JWT payload 디코딩 실패: {}", e))?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("JWT claims 파싱 실패: {}", e))
+}
@@ -0,0 +1,41 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\keystore.rs
+//! 설치마다 한 번 만드는 무작위 마스터 키. OS 키 저장소(Windows 자격 증명 관리자 / macOS 키체인 /
+//! Linux Secret Service)에 보관하고, 용도별 암호화 키는 HKDF-SHA256 으로 유도한다.
+use hkdf::Hkdf;
+use rand::RngCore;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+/// OS 키 저장소 항목 이름
+const KEYRING_SERVICE: &str = "nexus-call-hub";
+const KEYRING_ACCOUNT: &str = "install-master-key";
+pub const MASTER_KEY_LEN: usize = 32;
+
+/// 이 설치의 마스터 키. 없으면 새로 만들어 저장한다
+pub fn install_master_key() -> Result<Zeroizing<Vec<u8>>, String> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, KEYRING_ACCOUNT)
+        .map_err(|e| format!("키 저장소 열기 실패: {}", e))?;
+    match entry.get_secret() {
+        Ok(key) if key.len() == MASTER_KEY_LEN => return Ok(Zeroizing::new(key)),
+        Ok(_) => println!("⚠️ [KEYSTORE] 저장된 마스터 키 길이가 올바르지 않아 새로 만듭니다"),
+        Err(keyring::Error::NoEntry) => {}
+        Err(e) => return Err(format!("마스터 키 조회 실패: {}", e)),
+    }
+
+    let mut key = Zeroizing::new(vec![0u8; MASTER_KEY_LEN]);
+    rand::rngs::OsRng.fill_bytes(&mut key);
+    entry
+        .set_secret(&key)
+        .map_err(|e| format!("마스터 키 저장 실패: {}", e))?;
+    println!("🔑 [KEYSTORE] 새 마스터 키 생성");
+    Ok(key)
+}
+
+/// 마스터 키에서 용도(`context`)별 256비트 키 유도
+pub fn derive_key(master_key: &[u8], context: &[u8]) -> Zeroizing<[u8; 32]> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Hkdf::<Sha256>::new(None, master_key)
+        .expand(context, key.as_mut_slice())
+        .expect("32바이트는 HKDF-SHA256 출력 한도 안이다");
+    key
+}
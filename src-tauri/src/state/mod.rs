@@ -1,3 +1,27 @@
 // C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\mod.rs
 
+pub mod agent_state;
+pub mod api_proxy;
+pub mod audit_log;
 pub mod auth_state;
+pub mod backend;
+pub mod clock;
+pub(crate) mod fs_util;
+pub mod idle_lock;
+pub mod impersonation;
+pub mod jwt;
+pub mod keystore;
+pub mod offline_login;
+pub mod queue_alerts;
+pub mod queue_delta;
+pub mod queue_history;
+pub mod queue_kpi;
+pub mod queue_registry;
+pub mod queue_simulation;
+pub mod queue_state;
+pub mod rbac;
+pub mod routing;
+pub mod secret;
+pub mod session_store;
+pub mod token_refresh;
+pub mod totp;
@@ -0,0 +1,469 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\offline_login.rs
+//! 오프라인 로그인. 마지막으로 온라인 로그인에 성공한 자격 증명을 Argon2 해시로 남겨 두고,
+//! 백엔드에 닿지 않을 때 그 해시로 확인해서 제한된 권한의 오프라인 세션을 연다.
+//! 오프라인 세션은 주기적으로 서버 로그인을 다시 시도해서 성공하면 정상 세션으로 올라간다.
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::audit_log::AuditEvent;
+use super::auth_state::{
+    end_session, schedule_session_timers, validate_session, AuthState, LogoutReason, SessionUser,
+    User, USER_LOGGED_IN_EVENT,
+};
+use super::backend::BackendConfig;
+use super::fs_util::write_atomic;
+use super::jwt::TokenClaims;
+use super::secret::Secret;
+use super::token_refresh::{ApiResponse, RefreshedTokens};
+
+pub const OFFLINE_CREDENTIALS_FILE_NAME: &str = "offline_credentials.json";
+
+/// 마지막 온라인 로그인 후 이 기간이 지나면 오프라인 로그인 불가
+pub const OFFLINE_CREDENTIAL_MAX_AGE_DAYS: i64 = 14;
+/// 오프라인 세션 길이. 그 안에 서버에 닿지 않으면 만료되어 로그아웃
+pub const OFFLINE_SESSION_HOURS: i64 = 8;
+/// 오프라인 로그인 실패가 이 횟수에 도달하면 저장된 자격 증명 삭제
+pub const MAX_OFFLINE_ATTEMPTS: u32 = 5;
+
+/// `login` 이 서버에 닿지 못한 뒤 이 시간(초) 안에만 같은 아이디로 오프라인 로그인 가능
+pub const OFFLINE_FALLBACK_SECS: i64 = 120;
+
+/// 오프라인 세션에서 서버 로그인을 다시 시도하는 간격
+const OFFLINE_RETRY_SECS: u64 = 30;
+
+/// 저장되는 사용자 정보 (토큰 제외)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedProfile {
+    pub id: String,
+    pub name: String,
+    pub email: Option<String>,
+    pub department: String,
+    pub role: String,
+    pub permissions: Option<Vec<String>>,
+}
+
+impl CachedProfile {
+    pub fn from_user(user: &User) -> Self {
+        Self {
+            id: user.id.clone(),
+            name: user.name.clone(),
+            email: user.email.clone(),
+            department: user.department.clone(),
+            role: user.role.clone(),
+            permissions: user.permissions.clone(),
+        }
+    }
+
+    /// 토큰 없는 `User` (오프라인 세션용)
+    pub fn to_user(&self) -> User {
+        User {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            email: self.email.clone(),
+            department: self.department.clone(),
+            role: self.role.clone(),
+            token: Secret::default(),
+            permissions: self.permissions.clone(),
+        }
+    }
+}
+
+/// 마지막으로 온라인 로그인에 성공한 자격 증명
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedCredential {
+    pub username: String,
+    /// 비밀번호의 Argon2 해시 (PHC 문자열, salt 포함). 평문은 저장하지 않는다
+    pub verifier: String,
+    pub profile: CachedProfile,
+    pub cached_at: DateTime<Utc>,
+    /// 연속 실패 횟수 (재시작해도 유지)
+    #[serde(default)]
+    pub failed_attempts: u32,
+}
+
+/// 오프라인 로그인 확인 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum OfflineVerification {
+    Verified(CachedProfile),
+    /// 틀림. 남은 시도 횟수 (0 이면 자격 증명이 삭제됨)
+    Rejected {
+        remaining: u32,
+    },
+    /// 저장된 자격 증명이 없거나 만료됨
+    Unavailable(String),
+}
+
+/// 오프라인 자격 증명 저장소. 시각은 인자로 받는다
+#[derive(Debug, Default)]
+pub struct OfflineCredentials {
+    path: Option<PathBuf>,
+    cached: Option<CachedCredential>,
+}
+
+impl OfflineCredentials {
+    /// 저장 파일 로드. 없거나 깨졌으면 비어 있는 상태
+    pub fn load(path: PathBuf) -> Self {
+        let cached = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| {
+                    println!("⚠️ [AUTH] 오프라인 자격 증명 파싱 실패: {}", e);
+                })
+                .ok(),
+            Err(_) => None,
+        };
+        Self {
+            path: Some(path),
+            cached,
+        }
+    }
+
+    pub fn cached(&self) -> Option<&CachedCredential> {
+        self.cached.as_ref()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        match &self.cached {
+            Some(cached) => {
+                let bytes = serde_json::to_vec_pretty(cached).map_err(|e| e.to_string())?;
+                write_atomic(path, &bytes)
+            }
+            None => match fs::remove_file(path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.to_string()),
+                _ => Ok(()),
+            },
+        }
+    }
+
+    /// 온라인 로그인 성공 시 호출. 이전 사용자의 자격 증명은 덮어쓴다
+    pub fn remember(
+        &mut self,
+        username: &str,
+        password: &str,
+        user: &User,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        if username.trim().is_empty() || password.is_empty() {
+            return Err("아이디와 비밀번호가 필요합니다".to_string());
+        }
+        let salt = SaltString::generate(&mut OsRng);
+        let verifier = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|e| e.to_string())?
+            .to_string();
+        self.cached = Some(CachedCredential {
+            username: username.trim().to_string(),
+            verifier,
+            profile: CachedProfile::from_user(user),
+            cached_at: now,
+            failed_attempts: 0,
+        });
+        self.persist()
+    }
+
+    /// 오프라인 로그인 확인. 실패 횟수를 기록하고, 초과하면 자격 증명을 지운다
+    pub fn verify(
+        &mut self,
+        username: &str,
+        password: &str,
+        now: DateTime<Utc>,
+    ) -> Result<OfflineVerification, String> {
+        let Some(cached) = self.cached.as_mut() else {
+            return Ok(OfflineVerification::Unavailable(
+                "이 PC 에 저장된 로그인 정보가 없습니다".to_string(),
+            ));
+        };
+        if now - cached.cached_at > Duration::days(OFFLINE_CREDENTIAL_MAX_AGE_DAYS) {
+            return Ok(OfflineVerification::Unavailable(format!(
+                "마지막 온라인 로그인 후 {}일이 지나 오프라인 로그인을 할 수 없습니다",
+                OFFLINE_CREDENTIAL_MAX_AGE_DAYS
+            )));
+        }
+
+        let matches = cached.username == username.trim()
+            && PasswordHash::new(&cached.verifier).is_ok_and(|hash| {
+                Argon2::default()
+                    .verify_password(password.as_bytes(), &hash)
+                    .is_ok()
+            });
+        if matches {
+            let profile = cached.profile.clone();
+            cached.failed_attempts = 0;
+            self.persist()?;
+            return Ok(OfflineVerification::Verified(profile));
+        }
+
+        cached.failed_attempts += 1;
+        let remaining = MAX_OFFLINE_ATTEMPTS.saturating_sub(cached.failed_attempts);
+        if remaining == 0 {
+            self.cached = None;
+        }
+        self.persist()?;
+        Ok(OfflineVerification::Rejected { remaining })
+    }
+
+    /// 저장된 자격 증명 삭제 (서버가 비밀번호를 거부했을 때)
+    pub fn forget(&mut self) -> Result<(), String> {
+        self.cached = None;
+        self.persist()
+    }
+}
+
+/// 오프라인 세션 토큰 대신 쓰는 claims. 만료 시각은 `OFFLINE_SESSION_HOURS` 뒤
+pub fn offline_claims(user: &User, now: DateTime<Utc>) -> TokenClaims {
+    TokenClaims {
+        sub: Some(user.id.clone()),
+        exp: Some((now + Duration::hours(OFFLINE_SESSION_HOURS)).timestamp()),
+        iat: Some(now.timestamp()),
+        roles: Vec::new(),
+    }
+}
+
+/// 진행 중인 오프라인 세션. 서버에 다시 로그인할 비밀번호는 메모리에만 둔다
+pub struct OfflineSession {
+    pub username: String,
+    pub(crate) password: Secret<String>,
+    pub started_at: DateTime<Utc>,
+}
+
+/// 로그인 요청 실패 종류 (프론트엔드에는 `kind` / `message` 객체로 전달)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum LoginError {
+    /// 서버에 닿지 않음 (네트워크 오류 / 401·403 이 아닌 오류 상태 / 읽을 수 없는 응답)
+    Unreachable(String),
+    /// 서버가 자격 증명을 거부 (401 / 403 / `success: false`)
+    Rejected(String),
+}
+
+impl std::fmt::Display for LoginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoginError::Unreachable(message) => write!(f, "서버 연결 실패: {}", message),
+            LoginError::Rejected(message) => write!(f, "로그인 거절: {}", message),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoginResponseData {
+    token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+}
+
+/// 로그인 엔드포인트 1회 호출
+pub async fn request_login(
+    client: &reqwest::Client,
+    config: &BackendConfig,
+    username: &str,
+    password: &str,
+) -> Result<RefreshedTokens, LoginError> {
+    let response = client
+        .post(config.url(&config.login_path))
+        .timeout(config.request_timeout())
+        .json(&serde_json::json!({ "username": username, "password": password }))
+        .send()
+        .await
+        .map_err(|e| LoginError::Unreachable(e.without_url().to_string()))?;
+
+    // 401 / 403 이나 `success: false` 본문만 자격 증명 거부로 본다.
+    // 그 밖의 상태 / 읽을 수 없는 본문은 서버 쪽 문제로 보고 오프라인 로그인을 막지 않는다
+    let status = response.status();
+    if matches!(status.as_u16(), 401 | 403) {
+        return Err(LoginError::Rejected(format!("HTTP {}", status.as_u16())));
+    }
+    let body = response
+        .json::<ApiResponse<LoginResponseData>>()
+        .await
+        .map_err(|e| e.without_url().to_string());
+    match body {
+        Ok(ApiResponse {
+            success: true,
+            data: Some(data),
+            ..
+        }) if status.is_success() => Ok(RefreshedTokens {
+            access_token: data.token,
+            refresh_token: data.refresh_token,
+        }),
+        Ok(ApiResponse {
+            success: false,
+            message,
+            ..
+        }) => Err(LoginError::Rejected(message)),
+        Ok(_) => Err(LoginError::Unreachable(format!(
+            "HTTP {}: 로그인 응답에 토큰이 없습니다",
+            status.as_u16()
+        ))),
+        Err(_) if !status.is_success() => {
+            Err(LoginError::Unreachable(format!("HTTP {}", status.as_u16())))
+        }
+        Err(e) => Err(LoginError::Unreachable(e)),
+    }
+}
+
+// === Tauri 연동 ===
+
+/// 오프라인 세션 동안 주기적으로 서버 로그인을 시도. 세션이 바뀌면 멈춘다
+fn start_upgrade_loop(app: AppHandle, generation: u64) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(OFFLINE_RETRY_SECS)).await;
+
+            let state = app.state::<AuthState>();
+            if state.generation() != generation {
+                return;
+            }
+            // 2단계 인증을 마치기 전에는 정상 세션으로 올리지 않는다
+            if state.is_second_factor_pending() {
+                continue;
+            }
+            let Some((username, password)) = state
+                .offline_session
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|session| (session.username.clone(), session.password.clone()))
+            else {
+                return;
+            };
+            let config = state.backend.lock().unwrap().clone();
+            let client = state.http.client().clone();
+            let result = request_login(&client, &config, &username, password.expose()).await;
+
+            let state = app.state::<AuthState>();
+            if state.generation() != generation {
+                return;
+            }
+            match result {
+                Ok(tokens) => {
+                    if let Err(e) = upgrade_session(&app, &state, &username, &password, tokens) {
+                        println!("⚠️ [AUTH] 정상 세션 전환 실패: {}", e);
+                    }
+                    return;
+                }
+                Err(LoginError::Unreachable(_)) => continue,
+                Err(LoginError::Rejected(e)) => {
+                    // 오프라인 동안 비밀번호가 바뀌었거나 계정이 막힘
+                    println!("⛔ [AUTH] 서버가 오프라인 세션 자격 증명을 거부: {}", e);
+                    if let Err(e) = state.offline.lock().unwrap().forget() {
+                        println!("⚠️ [AUTH] 오프라인 자격 증명 삭제 실패: {}", e);
+                    }
+                    end_session(&app, &state, None, LogoutReason::OfflineRejected);
+                    return;
+                }
+            }
+        }
+    });
+}
+
+/// 서버 로그인 성공 → 오프라인 세션을 정상 세션으로 교체하고 모든 윈도우에 알린다
+fn upgrade_session(
+    app: &AppHandle,
+    state: &AuthState,
+    username: &str,
+    password: &Secret<String>,
+    tokens: RefreshedTokens,
+) -> Result<(), String> {
+    let mut user = state
+        .user
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| "로그인 상태가 아닙니다".to_string())?;
+    user.token = Secret::new(tokens.access_token);
+    let claims = validate_session(&user, Utc::now())?;
+    state.begin_online_session(user.clone(), claims, tokens.refresh_token);
+    state.remember_credentials(username, password.expose(), &user);
+    state.audit(None, AuditEvent::SessionUpgraded);
+    schedule_session_timers(app.clone());
+
+    let _ = app.emit(USER_LOGGED_IN_EVENT, state.session_user());
+    println!(
+        "🌐 [AUTH] 서버 연결 복구, 정상 세션으로 전환: {}",
+        user.name
+    );
+    Ok(())
+}
+
+/// 서버에 닿지 않을 때 저장된 자격 증명으로 로그인. 권한은 정책의 `offline_permissions` 로 제한된다.
+/// 바로 전 `login` 이 같은 아이디로 서버에 닿지 못했을 때만 허용한다
+#[tauri::command]
+pub fn offline_login(
+    app: AppHandle,
+    window: tauri::Window,
+    state: tauri::State<AuthState>,
+    username: String,
+    password: String,
+) -> Result<SessionUser, String> {
+    let now = Utc::now();
+    // 서버가 살아 있는데 오프라인 세션(제한 권한이지만 서버 비밀번호 확인 없음)을 열지 않도록
+    if !state.take_offline_fallback(&username, now) {
+        return Err("오프라인 로그인은 서버에 연결할 수 없을 때만 사용할 수 있습니다".to_string());
+    }
+    let password = Secret::new(password);
+    let verification = state
+        .offline
+        .lock()
+        .unwrap()
+        .verify(&username, password.expose(), now)?;
+
+    let profile = match verification {
+        OfflineVerification::Verified(profile) => profile,
+        OfflineVerification::Rejected { remaining } => {
+            state.audit_as(
+                Some(username.trim().to_string()),
+                None,
+                Some(window.label()),
+                AuditEvent::OfflineLoginFailed { remaining },
+            );
+            println!(
+                "⚠️ [AUTH] 오프라인 로그인 실패: {} (남은 시도 {})",
+                username.trim(),
+                remaining
+            );
+            return Err(if remaining == 0 {
+                "실패 횟수를 초과해 저장된 로그인 정보를 삭제했습니다".to_string()
+            } else {
+                format!(
+                    "아이디 또는 비밀번호가 일치하지 않습니다 (남은 시도 {})",
+                    remaining
+                )
+            });
+        }
+        OfflineVerification::Unavailable(reason) => return Err(reason),
+    };
+
+    let user = profile.to_user();
+    let generation = state.begin_offline_session(
+        user.clone(),
+        offline_claims(&user, now),
+        OfflineSession {
+            username: username.trim().to_string(),
+            password,
+            started_at: now,
+        },
+    );
+    state.idle.lock().unwrap().reset(now);
+    // 2단계 인증은 기기에서 확인하므로 오프라인에서도 똑같이 요구한다
+    let pending = state.hold_for_second_factor();
+    state.audit(Some(window.label()), AuditEvent::OfflineLogin);
+    schedule_session_timers(app.clone());
+    start_upgrade_loop(app.clone(), generation);
+
+    let session_user = state.session_user().ok_or("로그인하지 못했습니다")?;
+    if !pending {
+        let _ = app.emit(USER_LOGGED_IN_EVENT, &session_user);
+    }
+    println!("📴 [AUTH] 오프라인 로그인: {} ({})", user.name, user.role);
+    Ok(session_user)
+}
@@ -0,0 +1,398 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\queue_alerts.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fs;
+use std::path::PathBuf;
+
+use super::agent_state::{AgentState, BreakReason};
+use super::fs_util::write_atomic;
+use super::queue_registry::{QueueRegistry, QueueView};
+use super::queue_state::{AgentStatus, QueueStatus};
+
+pub const ALERT_RULES_FILE_NAME: &str = "queue_alert_rules.json";
+
+/// 해소된 알림 보관 개수
+const RESOLVED_HISTORY_LIMIT: usize = 200;
+
+/// 알림 조건
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertCondition {
+    /// 인바운드 대기 콜 수가 limit 초과
+    InboundWaitingAbove { limit: u32 },
+    /// 인바운드 상담원이 있는데 응대 가능 인원이 0
+    NoAgentsAvailable,
+    /// 상담원 휴식 시간이 정책(분) 초과. reason 지정 시 해당 사유만
+    AgentBreakExceeded {
+        max_minutes: u32,
+        #[serde(default)]
+        reason: Option<BreakReason>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+/// 사용자 정의 알림 규칙
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertRule {
+    /// 비워서 저장하면 자동 생성
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    pub condition: AlertCondition,
+    /// 조건이 이 시간(초) 동안 유지되어야 알림 발생
+    #[serde(default)]
+    pub hold_secs: u32,
+    /// 같은 대상에 대해 다시 알림을 띄우기까지의 최소 간격(초)
+    #[serde(default)]
+    pub cooldown_secs: u32,
+    pub severity: AlertSeverity,
+    /// 지정 시 해당 대기열(과 그 대기열 상담원)만 평가
+    #[serde(default)]
+    pub queue_id: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl AlertRule {
+    /// 대기열 지정 규칙은 `queues` 에 있는 대기열이어야 한다
+    pub fn validate(&self, queues: &QueueRegistry) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("알림 규칙 이름이 비어 있습니다".to_string());
+        }
+        if let Some(queue_id) = &self.queue_id {
+            queues.definition(queue_id)?;
+        }
+        if let AlertCondition::AgentBreakExceeded { max_minutes: 0, .. } = self.condition {
+            return Err("휴식 허용 시간은 1분 이상이어야 합니다".to_string());
+        }
+        Ok(())
+    }
+
+    /// 조건을 만족하는 대상 목록 (대상 없음 = 대기열 전체 → None) 과 메시지
+    fn matches(
+        &self,
+        status: &QueueStatus,
+        agents: &[AgentStatus],
+        now: DateTime<Utc>,
+    ) -> Vec<(Option<String>, String)> {
+        match &self.condition {
+            AlertCondition::InboundWaitingAbove { limit } => {
+                if status.inbound_waiting > *limit {
+                    vec![(
+                        None,
+                        format!(
+                            "인바운드 대기 {}건 (기준 {}건 초과)",
+                            status.inbound_waiting, limit
+                        ),
+                    )]
+                } else {
+                    vec![]
+                }
+            }
+            AlertCondition::NoAgentsAvailable => {
+                if status.inbound_agents_total > 0 && status.inbound_agents_available == 0 {
+                    vec![(
+                        None,
+                        format!(
+                            "응대 가능한 상담원이 없습니다 (대기 {}건)",
+                            status.inbound_waiting
+                        ),
+                    )]
+                } else {
+                    vec![]
+                }
+            }
+            AlertCondition::AgentBreakExceeded {
+                max_minutes,
+                reason,
+            } => agents
+                .iter()
+                .filter(|a| a.status() == AgentState::Break)
+                .filter(|a| reason.is_none() || a.state.break_reason == *reason)
+                .filter(|a| a.state.seconds_in_state(now) > *max_minutes as i64 * 60)
+                .map(|a| {
+                    (
+                        Some(a.id.clone()),
+                        format!(
+                            "{} 상담원 휴식 {}분 경과 (정책 {}분)",
+                            a.name,
+                            a.state.seconds_in_state(now) / 60,
+                            max_minutes
+                        ),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 기본 규칙 (규칙 파일이 없을 때)
+pub fn default_rules() -> Vec<AlertRule> {
+    vec![
+        AlertRule {
+            id: "inbound-waiting".to_string(),
+            name: "인바운드 대기 과다".to_string(),
+            enabled: true,
+            condition: AlertCondition::InboundWaitingAbove { limit: 10 },
+            hold_secs: 30,
+            cooldown_secs: 300,
+            severity: AlertSeverity::Warning,
+            queue_id: None,
+        },
+        AlertRule {
+            id: "no-agents-available".to_string(),
+            name: "응대 가능 상담원 없음".to_string(),
+            enabled: true,
+            condition: AlertCondition::NoAgentsAvailable,
+            hold_secs: 10,
+            cooldown_secs: 300,
+            severity: AlertSeverity::Critical,
+            queue_id: None,
+        },
+        AlertRule {
+            id: "break-exceeded".to_string(),
+            name: "휴식 시간 초과".to_string(),
+            enabled: true,
+            condition: AlertCondition::AgentBreakExceeded {
+                max_minutes: 30,
+                reason: None,
+            },
+            hold_secs: 0,
+            cooldown_secs: 900,
+            severity: AlertSeverity::Info,
+            queue_id: None,
+        },
+    ]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertStatus {
+    Active,
+    Resolved,
+}
+
+/// `queue-alert` 이벤트 payload (발생 / 해소 모두 같은 형태)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueAlert {
+    pub id: String,
+    pub rule_id: String,
+    pub rule_name: String,
+    pub severity: AlertSeverity,
+    pub queue_id: Option<String>,
+    /// 상담원 단위 규칙이면 상담원 id
+    pub subject: Option<String>,
+    pub message: String,
+    pub status: AlertStatus,
+    pub raised_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+type AlertKey = (String, Option<String>);
+
+/// 규칙 평가 엔진. 시각은 인자로 받는다.
+#[derive(Debug, Default)]
+pub struct AlertEngine {
+    rules: Vec<AlertRule>,
+    path: Option<PathBuf>,
+    /// 조건이 처음 참이 된 시각 (hold 계산용)
+    pending_since: HashMap<AlertKey, DateTime<Utc>>,
+    last_raised: HashMap<AlertKey, DateTime<Utc>>,
+    active: HashMap<AlertKey, QueueAlert>,
+    resolved: VecDeque<QueueAlert>,
+    next_seq: u64,
+}
+
+impl AlertEngine {
+    /// 규칙 파일 로드. 없으면 기본 규칙
+    pub fn load(path: PathBuf) -> Self {
+        let rules = match fs::read(&path) {
+            Ok(bytes) => match serde_json::from_slice::<Vec<AlertRule>>(&bytes) {
+                Ok(rules) => rules,
+                Err(e) => {
+                    println!("⚠️ [ALERT] 규칙 파일 파싱 실패, 기본 규칙 사용: {}", e);
+                    default_rules()
+                }
+            },
+            Err(_) => default_rules(),
+        };
+        println!("🚨 [ALERT] 알림 규칙 {}개 로드", rules.len());
+        Self {
+            rules,
+            path: Some(path),
+            ..Self::default()
+        }
+    }
+
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+
+    pub fn active(&self) -> Vec<QueueAlert> {
+        let mut alerts: Vec<QueueAlert> = self.active.values().cloned().collect();
+        alerts.sort_by_key(|a| a.raised_at);
+        alerts
+    }
+
+    /// 최근 해소된 알림 (최신순)
+    pub fn resolved(&self) -> Vec<QueueAlert> {
+        self.resolved.iter().rev().cloned().collect()
+    }
+
+    /// 규칙 추가/수정 후 저장. 저장된 규칙 반환
+    pub fn upsert_rule(
+        &mut self,
+        mut rule: AlertRule,
+        queues: &QueueRegistry,
+    ) -> Result<AlertRule, String> {
+        rule.validate(queues)?;
+        if rule.id.trim().is_empty() {
+            rule.id = format!("rule-{}", Utc::now().timestamp_millis());
+        }
+        match self.rules.iter_mut().find(|r| r.id == rule.id) {
+            Some(existing) => *existing = rule.clone(),
+            None => self.rules.push(rule.clone()),
+        }
+        self.persist()?;
+        Ok(rule)
+    }
+
+    pub fn remove_rule(&mut self, rule_id: &str) -> Result<bool, String> {
+        let before = self.rules.len();
+        self.rules.retain(|r| r.id != rule_id);
+        let removed = self.rules.len() != before;
+        if removed {
+            self.persist()?;
+        }
+        Ok(removed)
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let bytes = serde_json::to_vec_pretty(&self.rules).map_err(|e| e.to_string())?;
+        write_atomic(path, &bytes)
+    }
+
+    /// 발생 중인 알림을 모두 `now` 에 해소하고 hold / cooldown 기록 초기화 (규칙은 유지).
+    /// 해소된 알림 반환
+    pub fn reset(&mut self, now: DateTime<Utc>) -> Vec<QueueAlert> {
+        self.pending_since.clear();
+        self.last_raised.clear();
+        let mut cleared: Vec<QueueAlert> = self
+            .active
+            .drain()
+            .map(|(_, mut alert)| {
+                alert.status = AlertStatus::Resolved;
+                alert.resolved_at = Some(now);
+                alert
+            })
+            .collect();
+        cleared.sort_by_key(|a| a.raised_at);
+        self.resolved.extend(cleared.iter().cloned());
+        while self.resolved.len() > RESOLVED_HISTORY_LIMIT {
+            self.resolved.pop_front();
+        }
+        cleared
+    }
+
+    /// 현재 상태로 규칙 평가. 새로 발생하거나 해소된 알림 반환.
+    /// 대기열 지정 규칙은 `queues` 의 해당 대기열 상태로 평가한다
+    pub fn evaluate(
+        &mut self,
+        status: &QueueStatus,
+        agents: &[AgentStatus],
+        queues: &BTreeMap<String, QueueView>,
+        now: DateTime<Utc>,
+    ) -> Vec<QueueAlert> {
+        let mut changes = Vec::new();
+        let mut matched: HashMap<AlertKey, (&AlertRule, String)> = HashMap::new();
+
+        for rule in self.rules.iter().filter(|r| r.enabled) {
+            let results = match &rule.queue_id {
+                Some(queue_id) => match queues.get(queue_id) {
+                    Some(view) => rule
+                        .matches(&view.status, &view.agents, now)
+                        .into_iter()
+                        .map(|(subject, message)| (subject, format!("[{}] {}", queue_id, message)))
+                        .collect(),
+                    None => vec![],
+                },
+                None => rule.matches(status, agents, now),
+            };
+            for (subject, message) in results {
+                matched.insert((rule.id.clone(), subject), (rule, message));
+            }
+        }
+
+        // 조건이 풀렸거나 규칙이 삭제/비활성화된 알림 해소
+        let cleared: Vec<AlertKey> = self
+            .active
+            .keys()
+            .filter(|key| !matched.contains_key(*key))
+            .cloned()
+            .collect();
+        for key in cleared {
+            if let Some(mut alert) = self.active.remove(&key) {
+                alert.status = AlertStatus::Resolved;
+                alert.resolved_at = Some(now);
+                self.resolved.push_back(alert.clone());
+                changes.push(alert);
+            }
+        }
+        while self.resolved.len() > RESOLVED_HISTORY_LIMIT {
+            self.resolved.pop_front();
+        }
+        self.pending_since
+            .retain(|key, _| matched.contains_key(key));
+
+        for (key, (rule, message)) in matched {
+            if self.active.contains_key(&key) {
+                continue;
+            }
+            let since = *self.pending_since.entry(key.clone()).or_insert(now);
+            if (now - since).num_seconds() < rule.hold_secs as i64 {
+                continue;
+            }
+            let cooling_down = self
+                .last_raised
+                .get(&key)
+                .is_some_and(|last| (now - *last).num_seconds() < rule.cooldown_secs as i64);
+            if cooling_down {
+                continue;
+            }
+
+            self.next_seq += 1;
+            let alert = QueueAlert {
+                id: format!("alert-{}-{}", now.timestamp_millis(), self.next_seq),
+                rule_id: rule.id.clone(),
+                rule_name: rule.name.clone(),
+                severity: rule.severity,
+                queue_id: rule.queue_id.clone(),
+                subject: key.1.clone(),
+                message,
+                status: AlertStatus::Active,
+                raised_at: now,
+                resolved_at: None,
+            };
+            self.last_raised.insert(key.clone(), now);
+            self.active.insert(key, alert.clone());
+            changes.push(alert);
+        }
+
+        changes
+    }
+}
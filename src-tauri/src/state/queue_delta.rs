@@ -0,0 +1,226 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\queue_delta.rs
+//! 순번이 붙은 대기열 변경분(delta) 로그.
+//! 윈도우는 seq 가 연속인지 확인하고, 빠진 구간이 있으면 `get_queue_snapshot_since` 로 따라잡는다.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+
+use super::queue_state::{AgentStatus, QueueStatus};
+
+/// 보관하는 최근 delta 수. 이보다 오래 뒤처진 윈도우는 전체 스냅샷을 받는다
+pub const QUEUE_DELTA_LOG_LIMIT: usize = 2048;
+
+/// 바뀐 필드만 담은 대기열 상태
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueueStatusPatch {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbound_waiting: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbound_agents_available: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbound_agents_total: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outbound_active_campaigns: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outbound_calls_in_progress: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outbound_calls_today: Option<u32>,
+}
+
+impl QueueStatusPatch {
+    /// `before` → `after` 변경분. 바뀐 게 없으면 None
+    pub fn diff(before: &QueueStatus, after: &QueueStatus) -> Option<Self> {
+        fn changed(before: u32, after: u32) -> Option<u32> {
+            (before != after).then_some(after)
+        }
+        let patch = Self {
+            inbound_waiting: changed(before.inbound_waiting, after.inbound_waiting),
+            inbound_agents_available: changed(
+                before.inbound_agents_available,
+                after.inbound_agents_available,
+            ),
+            inbound_agents_total: changed(before.inbound_agents_total, after.inbound_agents_total),
+            outbound_active_campaigns: changed(
+                before.outbound_active_campaigns,
+                after.outbound_active_campaigns,
+            ),
+            outbound_calls_in_progress: changed(
+                before.outbound_calls_in_progress,
+                after.outbound_calls_in_progress,
+            ),
+            outbound_calls_today: changed(before.outbound_calls_today, after.outbound_calls_today),
+        };
+        (patch != Self::default()).then_some(patch)
+    }
+}
+
+/// 변경 내용
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QueueChange {
+    /// 대기열 상태 변경. `queue_id` 가 None 이면 전체 상태
+    QueueStatus {
+        queue_id: Option<String>,
+        patch: QueueStatusPatch,
+    },
+    /// 대기열 삭제
+    QueueRemoved { queue_id: String },
+    /// 상담원 추가 / 변경 (상담원 하나 전체)
+    Agent { agent: AgentStatus },
+    /// 상담원 제거 (시뮬레이션 종료 등)
+    AgentRemoved { agent_id: String },
+    /// 대기열 정의 / 스킬 배정 변경. 값은 `list_queues` 로 조회
+    QueueDefinitions,
+}
+
+/// payload: `queue-delta` 이벤트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueDelta {
+    pub seq: u64,
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub change: QueueChange,
+}
+
+/// 특정 seq 시점 이후의 전체 상태
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueueSnapshot {
+    pub seq: u64,
+    pub status: QueueStatus,
+    pub queues: BTreeMap<String, QueueStatus>,
+    pub agents: Vec<AgentStatus>,
+}
+
+/// `get_queue_snapshot_since` 응답
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QueueResync {
+    /// 요청한 seq 이후 빠진 delta 만 (순서대로)
+    Deltas { seq: u64, deltas: Vec<QueueDelta> },
+    /// 요청한 seq 가 보관 범위를 벗어났거나 생략된 경우 전체 상태
+    Snapshot(QueueSnapshot),
+}
+
+/// delta 에 순번을 붙이고 최근 delta + 그 결과 상태를 함께 보관.
+/// 상태 사본을 같이 들고 있어야 스냅샷과 seq 가 항상 일치한다
+#[derive(Debug, Default)]
+pub struct QueueDeltaLog {
+    snapshot: QueueSnapshot,
+    deltas: VecDeque<QueueDelta>,
+}
+
+impl QueueDeltaLog {
+    pub fn seq(&self) -> u64 {
+        self.snapshot.seq
+    }
+
+    fn push(&mut self, change: QueueChange, at: DateTime<Utc>) -> QueueDelta {
+        self.snapshot.seq += 1;
+        let delta = QueueDelta {
+            seq: self.snapshot.seq,
+            at,
+            change,
+        };
+        self.deltas.push_back(delta.clone());
+        while self.deltas.len() > QUEUE_DELTA_LOG_LIMIT {
+            self.deltas.pop_front();
+        }
+        delta
+    }
+
+    /// 대기열 상태 기록. 바뀐 필드가 없으면 seq 를 소비하지 않는다
+    pub fn record_status(
+        &mut self,
+        queue_id: Option<&str>,
+        status: &QueueStatus,
+        at: DateTime<Utc>,
+    ) -> Option<QueueDelta> {
+        let current = match queue_id {
+            Some(id) => self.snapshot.queues.entry(id.to_string()).or_default(),
+            None => &mut self.snapshot.status,
+        };
+        let patch = QueueStatusPatch::diff(current, status)?;
+        *current = status.clone();
+        Some(self.push(
+            QueueChange::QueueStatus {
+                queue_id: queue_id.map(str::to_string),
+                patch,
+            },
+            at,
+        ))
+    }
+
+    pub fn record_agent(&mut self, agent: &AgentStatus, at: DateTime<Utc>) -> Option<QueueDelta> {
+        let agents = &mut self.snapshot.agents;
+        match agents.iter_mut().find(|a| a.id == agent.id) {
+            Some(existing) if existing == agent => return None,
+            Some(existing) => *existing = agent.clone(),
+            None => agents.push(agent.clone()),
+        }
+        Some(self.push(
+            QueueChange::Agent {
+                agent: agent.clone(),
+            },
+            at,
+        ))
+    }
+
+    pub fn record_agent_removed(
+        &mut self,
+        agent_id: &str,
+        at: DateTime<Utc>,
+    ) -> Option<QueueDelta> {
+        let agents = &mut self.snapshot.agents;
+        let index = agents.iter().position(|a| a.id == agent_id)?;
+        agents.remove(index);
+        Some(self.push(
+            QueueChange::AgentRemoved {
+                agent_id: agent_id.to_string(),
+            },
+            at,
+        ))
+    }
+
+    pub fn record_queue_removed(
+        &mut self,
+        queue_id: &str,
+        at: DateTime<Utc>,
+    ) -> Option<QueueDelta> {
+        self.snapshot.queues.remove(queue_id)?;
+        Some(self.push(
+            QueueChange::QueueRemoved {
+                queue_id: queue_id.to_string(),
+            },
+            at,
+        ))
+    }
+
+    pub fn record_queue_definitions(&mut self, at: DateTime<Utc>) -> QueueDelta {
+        self.push(QueueChange::QueueDefinitions, at)
+    }
+
+    /// `since` 이후 변경분. 보관 범위 밖이면 전체 스냅샷
+    pub fn since(&self, since: Option<u64>) -> QueueResync {
+        let seq = self.seq();
+        match since {
+            Some(since) if since == seq => QueueResync::Deltas {
+                seq,
+                deltas: Vec::new(),
+            },
+            Some(since)
+                if since < seq && self.deltas.front().is_some_and(|d| d.seq <= since + 1) =>
+            {
+                QueueResync::Deltas {
+                    seq,
+                    deltas: self
+                        .deltas
+                        .iter()
+                        .filter(|d| d.seq > since)
+                        .cloned()
+                        .collect(),
+                }
+            }
+            _ => QueueResync::Snapshot(self.snapshot.clone()),
+        }
+    }
+}
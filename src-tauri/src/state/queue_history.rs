@@ -0,0 +1,337 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\queue_history.rs
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::path::PathBuf;
+
+use super::agent_state::AgentState;
+use super::fs_util::write_atomic;
+use super::queue_state::{AgentStatus, QueueStatus};
+
+pub const HISTORY_FILE_NAME: &str = "queue_history.json";
+
+/// 샘플링 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryConfig {
+    /// 샘플 간격 (초)
+    pub resolution_secs: u32,
+    /// 보관 기간 (시간). 용량 = 보관 기간 / 샘플 간격
+    pub retention_hours: u32,
+    /// 디스크 저장 간격 (초)
+    pub persist_every_secs: u32,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            resolution_secs: 10,
+            retention_hours: 24,
+            persist_every_secs: 60,
+        }
+    }
+}
+
+impl HistoryConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.resolution_secs == 0 {
+            return Err("샘플 간격은 1초 이상이어야 합니다".to_string());
+        }
+        if self.retention_hours == 0 {
+            return Err("보관 기간은 1시간 이상이어야 합니다".to_string());
+        }
+        if self.persist_every_secs == 0 {
+            return Err("저장 간격은 1초 이상이어야 합니다".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn capacity(&self) -> usize {
+        (self.retention_hours as usize * 3600 / self.resolution_secs as usize).max(1)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AgentCounts {
+    pub available: u32,
+    pub ringing: u32,
+    pub busy: u32,
+    pub wrap_up: u32,
+    #[serde(rename = "break")]
+    pub on_break: u32,
+    pub offline: u32,
+}
+
+impl AgentCounts {
+    pub fn from_agents(agents: &[AgentStatus]) -> Self {
+        let mut counts = Self::default();
+        for agent in agents {
+            match agent.status() {
+                AgentState::Available => counts.available += 1,
+                AgentState::Ringing => counts.ringing += 1,
+                AgentState::Busy => counts.busy += 1,
+                AgentState::WrapUp => counts.wrap_up += 1,
+                AgentState::Break => counts.on_break += 1,
+                AgentState::Offline => counts.offline += 1,
+            }
+        }
+        counts
+    }
+}
+
+/// 대기열 하나의 스냅샷
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueSample {
+    pub queue: QueueStatus,
+    pub agents: AgentCounts,
+}
+
+/// 한 시점의 대기열 / 상담원 스냅샷
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistorySample {
+    pub at: DateTime<Utc>,
+    pub queue: QueueStatus,
+    pub agents: AgentCounts,
+    /// 대기열 id 별 스냅샷 (등록된 대기열이 있을 때만)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub queues: BTreeMap<String, QueueSample>,
+}
+
+impl HistorySample {
+    /// 전체 또는 특정 대기열의 (대기열 상태, 상담원 수)
+    fn view(&self, queue_id: Option<&str>) -> Option<(&QueueStatus, &AgentCounts)> {
+        match queue_id {
+            Some(id) => self.queues.get(id).map(|q| (&q.queue, &q.agents)),
+            None => Some((&self.queue, &self.agents)),
+        }
+    }
+}
+
+/// 다운샘플된 한 구간 (구간 내 샘플 평균)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HistoryPoint {
+    /// 구간 시작 시각
+    pub at: DateTime<Utc>,
+    pub samples: u32,
+    pub inbound_waiting: f64,
+    pub inbound_waiting_max: u32,
+    pub inbound_agents_available: f64,
+    pub inbound_agents_total: f64,
+    pub outbound_calls_in_progress: f64,
+    pub outbound_calls_today: f64,
+    pub agents_available: f64,
+    pub agents_ringing: f64,
+    pub agents_busy: f64,
+    pub agents_wrap_up: f64,
+    pub agents_break: f64,
+    pub agents_offline: f64,
+}
+
+/// `get_queue_history` 응답
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueHistorySeries {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub resolution_secs: u32,
+    pub points: Vec<HistoryPoint>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HistoryFile {
+    config: HistoryConfig,
+    samples: Vec<HistorySample>,
+}
+
+/// 고정 용량 링 버퍼 기반 시계열 저장소
+#[derive(Debug, Default)]
+pub struct QueueHistory {
+    config: HistoryConfig,
+    samples: VecDeque<HistorySample>,
+    path: Option<PathBuf>,
+    last_persisted: Option<DateTime<Utc>>,
+}
+
+fn bucket_of(at: DateTime<Utc>, resolution_secs: u32) -> i64 {
+    at.timestamp().div_euclid(resolution_secs as i64)
+}
+
+impl QueueHistory {
+    /// 파일에서 복원. 파일이 없거나 손상되었으면 빈 저장소로 시작
+    pub fn load(path: PathBuf, now: DateTime<Utc>) -> Self {
+        let mut history = Self {
+            path: Some(path.clone()),
+            ..Self::default()
+        };
+
+        match fs::read(&path) {
+            Ok(bytes) => match serde_json::from_slice::<HistoryFile>(&bytes) {
+                Ok(file) if file.config.validate().is_ok() => {
+                    history.config = file.config;
+                    history.samples = file.samples.into();
+                    history.enforce_retention(now);
+                    println!("📈 [QUEUE] 히스토리 복원: {}개 샘플", history.samples.len());
+                }
+                Ok(_) => println!("⚠️ [QUEUE] 히스토리 설정이 잘못되어 무시합니다"),
+                Err(e) => println!("⚠️ [QUEUE] 히스토리 파일 파싱 실패: {}", e),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => println!("⚠️ [QUEUE] 히스토리 파일 읽기 실패: {}", e),
+        }
+        history
+    }
+
+    pub fn config(&self) -> &HistoryConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: HistoryConfig, now: DateTime<Utc>) -> Result<(), String> {
+        config.validate()?;
+        self.config = config;
+        self.enforce_retention(now);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// 샘플 기록. 같은 해상도 구간 안의 샘플은 마지막 값으로 덮어쓴다
+    pub fn record(&mut self, sample: HistorySample) {
+        let resolution = self.config.resolution_secs;
+        if let Some(last) = self.samples.back_mut() {
+            if sample.at < last.at {
+                return;
+            }
+            if bucket_of(last.at, resolution) == bucket_of(sample.at, resolution) {
+                *last = sample;
+                return;
+            }
+        }
+        self.samples.push_back(sample);
+        let now = self.samples.back().map(|s| s.at).unwrap_or_else(Utc::now);
+        self.enforce_retention(now);
+    }
+
+    fn enforce_retention(&mut self, now: DateTime<Utc>) {
+        let oldest = now - chrono::Duration::hours(self.config.retention_hours as i64);
+        while self.samples.front().is_some_and(|s| s.at < oldest) {
+            self.samples.pop_front();
+        }
+        let capacity = self.config.capacity();
+        while self.samples.len() > capacity {
+            self.samples.pop_front();
+        }
+    }
+
+    /// `[from, to]` 구간을 `resolution_secs` 단위로 다운샘플. `queue_id` 지정 시 해당 대기열만
+    pub fn query(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        resolution_secs: Option<u32>,
+        queue_id: Option<&str>,
+    ) -> QueueHistorySeries {
+        let resolution = resolution_secs
+            .unwrap_or(self.config.resolution_secs)
+            .max(self.config.resolution_secs)
+            .max(1);
+
+        let mut points: Vec<HistoryPoint> = Vec::new();
+        let mut current_bucket = None;
+        for sample in self.samples.iter().filter(|s| s.at >= from && s.at <= to) {
+            let Some((queue, agents)) = sample.view(queue_id) else {
+                continue;
+            };
+            let bucket = bucket_of(sample.at, resolution);
+            if current_bucket != Some(bucket) {
+                current_bucket = Some(bucket);
+                points.push(HistoryPoint {
+                    at: Utc
+                        .timestamp_opt(bucket * resolution as i64, 0)
+                        .single()
+                        .unwrap_or(sample.at),
+                    ..HistoryPoint::default()
+                });
+            }
+            let point = points.last_mut().expect("point pushed above");
+            point.samples += 1;
+            point.inbound_waiting += queue.inbound_waiting as f64;
+            point.inbound_waiting_max = point.inbound_waiting_max.max(queue.inbound_waiting);
+            point.inbound_agents_available += queue.inbound_agents_available as f64;
+            point.inbound_agents_total += queue.inbound_agents_total as f64;
+            point.outbound_calls_in_progress += queue.outbound_calls_in_progress as f64;
+            point.outbound_calls_today += queue.outbound_calls_today as f64;
+            point.agents_available += agents.available as f64;
+            point.agents_ringing += agents.ringing as f64;
+            point.agents_busy += agents.busy as f64;
+            point.agents_wrap_up += agents.wrap_up as f64;
+            point.agents_break += agents.on_break as f64;
+            point.agents_offline += agents.offline as f64;
+        }
+
+        for point in &mut points {
+            let n = point.samples as f64;
+            point.inbound_waiting /= n;
+            point.inbound_agents_available /= n;
+            point.inbound_agents_total /= n;
+            point.outbound_calls_in_progress /= n;
+            point.outbound_calls_today /= n;
+            point.agents_available /= n;
+            point.agents_ringing /= n;
+            point.agents_busy /= n;
+            point.agents_wrap_up /= n;
+            point.agents_break /= n;
+            point.agents_offline /= n;
+        }
+
+        QueueHistorySeries {
+            from,
+            to,
+            resolution_secs: resolution,
+            points,
+        }
+    }
+
+    /// 마지막 저장 이후 `persist_every_secs` 가 지났으면 저장할 스냅샷
+    pub fn snapshot_if_due(&mut self, now: DateTime<Utc>) -> Option<HistorySnapshot> {
+        let due = self
+            .last_persisted
+            .is_none_or(|last| (now - last).num_seconds() >= self.config.persist_every_secs as i64);
+        if due {
+            self.snapshot(now)
+        } else {
+            None
+        }
+    }
+
+    /// 저장용 스냅샷. 파일 쓰기는 잠금을 놓은 뒤 `HistorySnapshot::write` 로 한다.
+    /// 저장 시각은 지금으로 기록하므로 쓰기에 실패하면 다음 주기에 다시 저장된다
+    pub fn snapshot(&mut self, now: DateTime<Utc>) -> Option<HistorySnapshot> {
+        let path = self.path.clone()?;
+        self.last_persisted = Some(now);
+        Some(HistorySnapshot {
+            path,
+            file: HistoryFile {
+                config: self.config.clone(),
+                samples: self.samples.iter().cloned().collect(),
+            },
+        })
+    }
+}
+
+/// 디스크에 쓸 히스토리 내용
+pub struct HistorySnapshot {
+    path: PathBuf,
+    file: HistoryFile,
+}
+
+impl HistorySnapshot {
+    pub fn write(&self) -> Result<(), String> {
+        let bytes = serde_json::to_vec(&self.file).map_err(|e| e.to_string())?;
+        write_atomic(&self.path, &bytes)
+    }
+}
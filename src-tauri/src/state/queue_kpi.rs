@@ -0,0 +1,364 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\queue_kpi.rs
+use chrono::{DateTime, Duration, Local, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::agent_state::AgentState;
+
+/// 서비스 레벨 기본 기준 (80/20 중 20초)
+pub const DEFAULT_SERVICE_LEVEL_SECS: u32 = 20;
+
+/// KPI 집계 구간
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KpiWindow {
+    FifteenMinutes,
+    OneHour,
+    Today,
+}
+
+impl KpiWindow {
+    pub const ALL: [KpiWindow; 3] = [
+        KpiWindow::FifteenMinutes,
+        KpiWindow::OneHour,
+        KpiWindow::Today,
+    ];
+
+    /// 구간 시작 시각 (Today 는 로컬 자정 기준)
+    pub fn start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            KpiWindow::FifteenMinutes => now - Duration::minutes(15),
+            KpiWindow::OneHour => now - Duration::hours(1),
+            KpiWindow::Today => start_of_local_day(now),
+        }
+    }
+}
+
+fn start_of_local_day(now: DateTime<Utc>) -> DateTime<Utc> {
+    let midnight = now
+        .with_timezone(&Local)
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is always valid");
+    Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or(now - Duration::hours(24))
+}
+
+/// PBX / 프론트엔드에서 보고하는 콜 이벤트. `at` 생략 시 수신 시각 사용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CallEvent {
+    /// 대기열 진입
+    Enqueued {
+        call_id: String,
+        #[serde(default)]
+        at: Option<DateTime<Utc>>,
+    },
+    /// 상담원 응답
+    Answered {
+        call_id: String,
+        agent_id: String,
+        #[serde(default)]
+        at: Option<DateTime<Utc>>,
+    },
+    /// 응답 전 포기
+    Abandoned {
+        call_id: String,
+        #[serde(default)]
+        at: Option<DateTime<Utc>>,
+    },
+    /// 후처리까지 완료 (응답 ~ 완료 = 처리 시간)
+    Completed {
+        call_id: String,
+        #[serde(default)]
+        at: Option<DateTime<Utc>>,
+    },
+}
+
+impl CallEvent {
+    pub fn call_id(&self) -> &str {
+        match self {
+            CallEvent::Enqueued { call_id, .. }
+            | CallEvent::Answered { call_id, .. }
+            | CallEvent::Abandoned { call_id, .. }
+            | CallEvent::Completed { call_id, .. } => call_id,
+        }
+    }
+
+    fn at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            CallEvent::Enqueued { at, .. }
+            | CallEvent::Answered { at, .. }
+            | CallEvent::Abandoned { at, .. }
+            | CallEvent::Completed { at, .. } => *at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct CallRecord {
+    enqueued_at: Option<DateTime<Utc>>,
+    answered_at: Option<DateTime<Utc>>,
+    abandoned_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+}
+
+impl CallRecord {
+    fn is_waiting(&self) -> bool {
+        self.enqueued_at.is_some() && self.answered_at.is_none() && self.abandoned_at.is_none()
+    }
+
+    fn wait_secs(&self) -> Option<f64> {
+        let answered = self.answered_at?;
+        let enqueued = self.enqueued_at?;
+        Some(secs_between(enqueued, answered))
+    }
+
+    fn handle_secs(&self) -> Option<f64> {
+        Some(secs_between(self.answered_at?, self.completed_at?))
+    }
+
+    fn last_activity(&self) -> Option<DateTime<Utc>> {
+        [
+            self.enqueued_at,
+            self.answered_at,
+            self.abandoned_at,
+            self.completed_at,
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+    }
+}
+
+/// 상담원 상태 구간 (end 가 None 이면 현재 진행 중)
+#[derive(Debug, Clone)]
+struct AgentInterval {
+    agent_id: String,
+    state: AgentState,
+    start: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+}
+
+fn secs_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    ((to - from).num_milliseconds().max(0) as f64) / 1000.0
+}
+
+/// 구간별 KPI. 표본이 없으면 비율/평균 값은 None
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueKpis {
+    pub window: KpiWindow,
+    pub window_start: DateTime<Utc>,
+    pub calls_offered: u32,
+    pub calls_answered: u32,
+    pub calls_abandoned: u32,
+    pub calls_completed: u32,
+    /// 기준 시간 내 응답 비율 (0.0 ~ 1.0)
+    pub service_level: Option<f64>,
+    /// 평균 응답 속도 (초)
+    pub average_speed_of_answer_secs: Option<f64>,
+    /// 포기율 (0.0 ~ 1.0)
+    pub abandonment_rate: Option<f64>,
+    /// 평균 처리 시간 (초, 후처리 포함)
+    pub average_handle_time_secs: Option<f64>,
+    /// 점유율 = (통화 + 후처리) / (대기 + 호출 + 통화 + 후처리)
+    pub occupancy: Option<f64>,
+}
+
+/// `get_queue_kpis` 응답 / `queue-kpis-updated` 이벤트 payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueKpiReport {
+    pub generated_at: DateTime<Utc>,
+    pub service_level_threshold_secs: u32,
+    /// 현재 대기 중인 콜 수
+    pub calls_waiting: u32,
+    /// 현재 가장 오래 기다린 콜의 대기 시간 (초)
+    pub longest_waiting_secs: Option<f64>,
+    pub fifteen_minutes: QueueKpis,
+    pub one_hour: QueueKpis,
+    pub today: QueueKpis,
+}
+
+impl QueueKpiReport {
+    pub fn window(&self, window: KpiWindow) -> &QueueKpis {
+        match window {
+            KpiWindow::FifteenMinutes => &self.fifteen_minutes,
+            KpiWindow::OneHour => &self.one_hour,
+            KpiWindow::Today => &self.today,
+        }
+    }
+}
+
+/// 콜/상담원 이벤트를 누적해 KPI 를 계산하는 엔진.
+/// 시각은 모두 인자로 받으므로 테스트에서 시계를 고정할 수 있다.
+#[derive(Debug)]
+pub struct KpiEngine {
+    pub service_level_threshold_secs: u32,
+    calls: HashMap<String, CallRecord>,
+    intervals: Vec<AgentInterval>,
+}
+
+impl Default for KpiEngine {
+    fn default() -> Self {
+        Self {
+            service_level_threshold_secs: DEFAULT_SERVICE_LEVEL_SECS,
+            calls: HashMap::new(),
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl KpiEngine {
+    pub fn record_call_event(&mut self, event: &CallEvent, now: DateTime<Utc>) {
+        let at = event.at().unwrap_or(now);
+        let record = self.calls.entry(event.call_id().to_string()).or_default();
+        match event {
+            CallEvent::Enqueued { .. } => record.enqueued_at = Some(at),
+            CallEvent::Answered { .. } => record.answered_at = Some(at),
+            CallEvent::Abandoned { .. } => record.abandoned_at = Some(at),
+            CallEvent::Completed { .. } => record.completed_at = Some(at),
+        }
+        self.prune(now);
+    }
+
+    /// 상담원 상태 변경 기록 (점유율 계산용)
+    pub fn record_agent_state(&mut self, agent_id: &str, state: AgentState, at: DateTime<Utc>) {
+        if let Some(open) = self
+            .intervals
+            .iter_mut()
+            .rev()
+            .find(|i| i.agent_id == agent_id && i.end.is_none())
+        {
+            if open.state == state {
+                return;
+            }
+            open.end = Some(at);
+        }
+        self.intervals.push(AgentInterval {
+            agent_id: agent_id.to_string(),
+            state,
+            start: at,
+            end: None,
+        });
+        self.prune(at);
+    }
+
+    /// 누적된 콜 / 상담원 기록 삭제 (서비스 레벨 기준 시간은 유지)
+    pub fn clear(&mut self) {
+        self.calls.clear();
+        self.intervals.clear();
+    }
+
+    /// 가장 긴 구간(오늘 / 최근 1시간)보다 오래된 기록 정리.
+    /// 오늘 이전에 들어와서 응답 / 포기 이벤트가 끝내 오지 않은 대기 콜도 버린다
+    fn prune(&mut self, now: DateTime<Utc>) {
+        let today = KpiWindow::Today.start(now);
+        let horizon = today.min(KpiWindow::OneHour.start(now));
+        self.calls.retain(|_, c| {
+            if c.is_waiting() {
+                c.enqueued_at.is_some_and(|t| t >= today)
+            } else {
+                c.last_activity().is_some_and(|t| t >= horizon)
+            }
+        });
+        self.intervals
+            .retain(|i| i.end.is_none_or(|end| end >= horizon));
+    }
+
+    pub fn report(&self, now: DateTime<Utc>) -> QueueKpiReport {
+        let waiting: Vec<f64> = self
+            .calls
+            .values()
+            .filter(|c| c.is_waiting())
+            .filter_map(|c| c.enqueued_at.map(|t| secs_between(t, now)))
+            .collect();
+
+        QueueKpiReport {
+            generated_at: now,
+            service_level_threshold_secs: self.service_level_threshold_secs,
+            calls_waiting: waiting.len() as u32,
+            longest_waiting_secs: waiting.into_iter().reduce(f64::max),
+            fifteen_minutes: self.compute(KpiWindow::FifteenMinutes, now),
+            one_hour: self.compute(KpiWindow::OneHour, now),
+            today: self.compute(KpiWindow::Today, now),
+        }
+    }
+
+    pub fn compute(&self, window: KpiWindow, now: DateTime<Utc>) -> QueueKpis {
+        let start = window.start(now);
+        let in_window = |t: Option<DateTime<Utc>>| t.is_some_and(|t| t >= start && t <= now);
+
+        let answered: Vec<&CallRecord> = self
+            .calls
+            .values()
+            .filter(|c| in_window(c.answered_at))
+            .collect();
+        let abandoned = self
+            .calls
+            .values()
+            .filter(|c| c.answered_at.is_none() && in_window(c.abandoned_at))
+            .count() as u32;
+        let completed: Vec<f64> = self
+            .calls
+            .values()
+            .filter(|c| in_window(c.completed_at))
+            .filter_map(CallRecord::handle_secs)
+            .collect();
+
+        let calls_answered = answered.len() as u32;
+        let calls_offered = calls_answered + abandoned;
+        let waits: Vec<f64> = answered.iter().filter_map(|c| c.wait_secs()).collect();
+        let threshold = self.service_level_threshold_secs as f64;
+        let within_threshold = answered
+            .iter()
+            .filter(|c| c.wait_secs().unwrap_or(0.0) <= threshold)
+            .count();
+
+        QueueKpis {
+            window,
+            window_start: start,
+            calls_offered,
+            calls_answered,
+            calls_abandoned: abandoned,
+            calls_completed: completed.len() as u32,
+            service_level: ratio(within_threshold as f64, calls_offered as f64),
+            average_speed_of_answer_secs: average(&waits),
+            abandonment_rate: ratio(abandoned as f64, calls_offered as f64),
+            average_handle_time_secs: average(&completed),
+            occupancy: self.occupancy(start, now),
+        }
+    }
+
+    fn occupancy(&self, start: DateTime<Utc>, now: DateTime<Utc>) -> Option<f64> {
+        let mut working = 0.0;
+        let mut staffed = 0.0;
+        for interval in &self.intervals {
+            let from = interval.start.max(start);
+            let to = interval.end.unwrap_or(now).min(now);
+            if to <= from {
+                continue;
+            }
+            let secs = secs_between(from, to);
+            match interval.state {
+                AgentState::Busy | AgentState::WrapUp => {
+                    working += secs;
+                    staffed += secs;
+                }
+                AgentState::Available | AgentState::Ringing => staffed += secs,
+                AgentState::Break | AgentState::Offline => {}
+            }
+        }
+        ratio(working, staffed)
+    }
+}
+
+fn ratio(numerator: f64, denominator: f64) -> Option<f64> {
+    (denominator > 0.0).then(|| numerator / denominator)
+}
+
+fn average(values: &[f64]) -> Option<f64> {
+    ratio(values.iter().sum(), values.len() as f64)
+}
@@ -0,0 +1,379 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\queue_registry.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::agent_state::AgentState;
+use super::fs_util::write_atomic;
+use super::queue_history::{AgentCounts, QueueSample};
+use super::queue_kpi::{KpiEngine, QueueKpiReport};
+use super::queue_state::{AgentStatus, QueueStatus};
+
+pub const QUEUE_REGISTRY_FILE_NAME: &str = "queue_registry.json";
+
+/// 숙련도 범위 (1 = 초급, 5 = 전문가)
+pub const MIN_PROFICIENCY: u8 = 1;
+pub const MAX_PROFICIENCY: u8 = 5;
+
+/// 대기열(스킬 그룹) 정의
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueDefinition {
+    pub id: String,
+    pub name: String,
+    /// 이 대기열을 처리하는 스킬. 생략 시 id 를 스킬명으로 사용
+    #[serde(default)]
+    pub skill: Option<String>,
+}
+
+impl QueueDefinition {
+    pub fn skill(&self) -> &str {
+        self.skill.as_deref().unwrap_or(&self.id)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("대기열 id 가 비어 있습니다".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("대기열 이름이 비어 있습니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 상담원 스킬 + 숙련도
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentSkill {
+    pub skill: String,
+    pub proficiency: u8,
+}
+
+/// 스킬명 / 숙련도 검사. 같은 스킬이 여러 번 오면 마지막 값만 남긴다
+pub fn validate_agent_skills(skills: Vec<AgentSkill>) -> Result<Vec<AgentSkill>, String> {
+    let mut merged: Vec<AgentSkill> = Vec::new();
+    for skill in skills {
+        if skill.skill.trim().is_empty() {
+            return Err("스킬명이 비어 있습니다".to_string());
+        }
+        if !(MIN_PROFICIENCY..=MAX_PROFICIENCY).contains(&skill.proficiency) {
+            return Err(format!(
+                "숙련도는 {}~{} 사이여야 합니다: {} = {}",
+                MIN_PROFICIENCY, MAX_PROFICIENCY, skill.skill, skill.proficiency
+            ));
+        }
+        merged.retain(|s| s.skill != skill.skill);
+        merged.push(skill);
+    }
+    Ok(merged)
+}
+
+/// 대기열 하나의 현재 상태와 해당 대기열을 처리하는 상담원
+#[derive(Debug, Clone)]
+pub struct QueueView {
+    pub status: QueueStatus,
+    pub agents: Vec<AgentStatus>,
+}
+
+/// `list_queues` 응답 항목
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueSummary {
+    pub queue: QueueDefinition,
+    pub status: QueueStatus,
+    pub agents: AgentCounts,
+    pub kpis: QueueKpiReport,
+}
+
+#[derive(Debug)]
+struct QueueEntry {
+    definition: QueueDefinition,
+    status: QueueStatus,
+    kpis: KpiEngine,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct RegistryFile {
+    queues: Vec<QueueDefinition>,
+    agent_skills: BTreeMap<String, Vec<AgentSkill>>,
+}
+
+/// 대기열 id 로 관리하는 대기열 목록 + 상담원 스킬 배정
+#[derive(Debug, Default)]
+pub struct QueueRegistry {
+    queues: BTreeMap<String, QueueEntry>,
+    agent_skills: BTreeMap<String, Vec<AgentSkill>>,
+    path: Option<PathBuf>,
+}
+
+impl QueueRegistry {
+    /// 파일에서 대기열 정의와 스킬 배정 복원
+    pub fn load(path: PathBuf) -> Self {
+        let file = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice::<RegistryFile>(&bytes).unwrap_or_else(|e| {
+                println!("⚠️ [QUEUE] 대기열 목록 파싱 실패: {}", e);
+                RegistryFile::default()
+            }),
+            Err(_) => RegistryFile::default(),
+        };
+
+        let mut registry = Self {
+            agent_skills: file.agent_skills,
+            path: Some(path),
+            ..Self::default()
+        };
+        for definition in file.queues {
+            registry.insert(definition);
+        }
+        println!("🗂️ [QUEUE] 대기열 {}개 로드", registry.queues.len());
+        registry
+    }
+
+    fn insert(&mut self, definition: QueueDefinition) {
+        match self.queues.get_mut(&definition.id) {
+            Some(entry) => entry.definition = definition,
+            None => {
+                self.queues.insert(
+                    definition.id.clone(),
+                    QueueEntry {
+                        definition,
+                        status: QueueStatus::default(),
+                        kpis: KpiEngine::default(),
+                    },
+                );
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queues.is_empty()
+    }
+
+    pub fn definitions(&self) -> Vec<QueueDefinition> {
+        self.queues.values().map(|q| q.definition.clone()).collect()
+    }
+
+    pub fn definition(&self, queue_id: &str) -> Result<&QueueDefinition, String> {
+        self.entry(queue_id).map(|q| &q.definition)
+    }
+
+    fn entry(&self, queue_id: &str) -> Result<&QueueEntry, String> {
+        self.queues
+            .get(queue_id)
+            .ok_or_else(|| format!("알 수 없는 대기열입니다: {}", queue_id))
+    }
+
+    fn entry_mut(&mut self, queue_id: &str) -> Result<&mut QueueEntry, String> {
+        self.queues
+            .get_mut(queue_id)
+            .ok_or_else(|| format!("알 수 없는 대기열입니다: {}", queue_id))
+    }
+
+    /// 대기열 추가/수정 후 저장
+    pub fn upsert_queue(&mut self, definition: QueueDefinition) -> Result<QueueDefinition, String> {
+        definition.validate()?;
+        self.insert(definition.clone());
+        self.persist()?;
+        Ok(definition)
+    }
+
+    pub fn remove_queue(&mut self, queue_id: &str) -> Result<bool, String> {
+        let removed = self.queues.remove(queue_id).is_some();
+        if removed {
+            self.persist()?;
+        }
+        Ok(removed)
+    }
+
+    pub fn agent_skills(&self, agent_id: &str) -> &[AgentSkill] {
+        self.agent_skills
+            .get(agent_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    pub fn all_agent_skills(&self) -> &BTreeMap<String, Vec<AgentSkill>> {
+        &self.agent_skills
+    }
+
+    /// 상담원 스킬 배정 교체 (같은 스킬이 여러 번 오면 마지막 값 사용).
+    /// `current` 는 상담원의 지금 상태로, 새로 맡게 된 대기열 KPI 에 `at` 부터 기록하고
+    /// 더 이상 맡지 않는 대기열에서는 `at` 에 오프라인으로 닫는다
+    pub fn set_agent_skills(
+        &mut self,
+        agent_id: &str,
+        skills: Vec<AgentSkill>,
+        current: Option<AgentState>,
+        at: DateTime<Utc>,
+    ) -> Result<Vec<AgentSkill>, String> {
+        let merged = validate_agent_skills(skills)?;
+        let before = self.queues_for_agent(agent_id);
+        if merged.is_empty() {
+            self.agent_skills.remove(agent_id);
+        } else {
+            self.agent_skills
+                .insert(agent_id.to_string(), merged.clone());
+        }
+        let after = self.queues_for_agent(agent_id);
+        if let Some(state) = current {
+            for queue_id in after.iter().filter(|id| !before.contains(id)) {
+                if let Some(entry) = self.queues.get_mut(queue_id) {
+                    entry.kpis.record_agent_state(agent_id, state, at);
+                }
+            }
+            for queue_id in before.iter().filter(|id| !after.contains(id)) {
+                if let Some(entry) = self.queues.get_mut(queue_id) {
+                    entry
+                        .kpis
+                        .record_agent_state(agent_id, AgentState::Offline, at);
+                }
+            }
+        }
+        self.persist()?;
+        Ok(merged)
+    }
+
+    /// 상담원들의 스킬 배정 삭제 (KPI 기록은 건드리지 않는다)
+    pub fn remove_agents(&mut self, agent_ids: &[String]) -> Result<(), String> {
+        let before = self.agent_skills.len();
+        self.agent_skills.retain(|id, _| !agent_ids.contains(id));
+        if self.agent_skills.len() != before {
+            self.persist()?;
+        }
+        Ok(())
+    }
+
+    /// 상담원의 해당 대기열 숙련도. 스킬이 없으면 None
+    pub fn proficiency(&self, agent_id: &str, queue_id: &str) -> Option<u8> {
+        let skill = self.queues.get(queue_id)?.definition.skill();
+        self.agent_skills(agent_id)
+            .iter()
+            .find(|s| s.skill == skill)
+            .map(|s| s.proficiency)
+    }
+
+    pub fn serves(&self, agent_id: &str, queue_id: &str) -> bool {
+        self.proficiency(agent_id, queue_id).is_some()
+    }
+
+    /// 상담원이 처리할 수 있는 대기열 id 목록
+    pub fn queues_for_agent(&self, agent_id: &str) -> Vec<String> {
+        self.queues
+            .keys()
+            .filter(|id| self.serves(agent_id, id))
+            .cloned()
+            .collect()
+    }
+
+    /// 해당 대기열을 처리하는 상담원만 필터링
+    pub fn agents_for(&self, queue_id: &str, agents: &[AgentStatus]) -> Vec<AgentStatus> {
+        agents
+            .iter()
+            .filter(|a| self.serves(&a.id, queue_id))
+            .cloned()
+            .collect()
+    }
+
+    pub fn status(&self, queue_id: &str) -> Result<QueueStatus, String> {
+        self.entry(queue_id).map(|q| q.status.clone())
+    }
+
+    pub fn set_status(&mut self, queue_id: &str, status: QueueStatus) -> Result<(), String> {
+        self.entry_mut(queue_id)?.status = status;
+        Ok(())
+    }
+
+    /// 모든 대기열 상태 합계
+    pub fn aggregate_status(&self) -> QueueStatus {
+        self.queues
+            .values()
+            .fold(QueueStatus::default(), |mut total, q| {
+                total.inbound_waiting += q.status.inbound_waiting;
+                total.inbound_agents_available += q.status.inbound_agents_available;
+                total.inbound_agents_total += q.status.inbound_agents_total;
+                total.outbound_active_campaigns += q.status.outbound_active_campaigns;
+                total.outbound_calls_in_progress += q.status.outbound_calls_in_progress;
+                total.outbound_calls_today += q.status.outbound_calls_today;
+                total
+            })
+    }
+
+    pub fn kpis(&self, queue_id: &str) -> Result<&KpiEngine, String> {
+        self.entry(queue_id).map(|q| &q.kpis)
+    }
+
+    pub fn kpis_mut(&mut self, queue_id: &str) -> Result<&mut KpiEngine, String> {
+        self.entry_mut(queue_id).map(|q| &mut q.kpis)
+    }
+
+    /// 모든 대기열의 KPI 기록 삭제 (대기열별 서비스 레벨 기준 시간은 유지)
+    pub fn clear_kpis(&mut self) {
+        for entry in self.queues.values_mut() {
+            entry.kpis.clear();
+        }
+    }
+
+    /// 상담원이 처리하는 모든 대기열의 점유율 계산에 상태 변경 반영
+    pub fn record_agent_state(&mut self, agent_id: &str, state: AgentState, at: DateTime<Utc>) {
+        for queue_id in self.queues_for_agent(agent_id) {
+            if let Some(entry) = self.queues.get_mut(&queue_id) {
+                entry.kpis.record_agent_state(agent_id, state, at);
+            }
+        }
+    }
+
+    pub fn views(&self, agents: &[AgentStatus]) -> BTreeMap<String, QueueView> {
+        self.queues
+            .iter()
+            .map(|(id, q)| {
+                (
+                    id.clone(),
+                    QueueView {
+                        status: q.status.clone(),
+                        agents: self.agents_for(id, agents),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// 히스토리 기록용 대기열별 스냅샷
+    pub fn samples(&self, agents: &[AgentStatus]) -> BTreeMap<String, QueueSample> {
+        self.queues
+            .iter()
+            .map(|(id, q)| {
+                (
+                    id.clone(),
+                    QueueSample {
+                        queue: q.status.clone(),
+                        agents: AgentCounts::from_agents(&self.agents_for(id, agents)),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    pub fn summaries(&self, agents: &[AgentStatus], now: DateTime<Utc>) -> Vec<QueueSummary> {
+        self.queues
+            .iter()
+            .map(|(id, q)| QueueSummary {
+                queue: q.definition.clone(),
+                status: q.status.clone(),
+                agents: AgentCounts::from_agents(&self.agents_for(id, agents)),
+                kpis: q.kpis.report(now),
+            })
+            .collect()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let file = RegistryFile {
+            queues: self.definitions(),
+            agent_skills: self.agent_skills.clone(),
+        };
+        let bytes = serde_json::to_vec_pretty(&file).map_err(|e| e.to_string())?;
+        write_atomic(path, &bytes)
+    }
+}
@@ -0,0 +1,789 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\queue_simulation.rs
+//! PBX 없이 대기열 화면을 채우기 위한 시뮬레이션 (데모 / 교육 / 부하 테스트)
+use chrono::{DateTime, Duration, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::agent_state::{AgentState, BreakReason};
+use super::clock::SimulatedClock;
+use super::queue_kpi::CallEvent;
+use super::queue_registry::AgentSkill;
+use super::queue_state::{self, AgentStatusUpdate, QueueState, QueueStatus};
+
+/// 실제 시간 기준 시뮬레이션 진행 주기 (밀리초)
+const SIMULATION_TICK_MILLIS: u64 = 250;
+/// 허용 배속 범위
+const MAX_SPEED: f64 = 1000.0;
+
+/// 처리 시간 / 후처리 시간 / 휴식 시간 분포 (초)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DurationDistribution {
+    Fixed { secs: f64 },
+    Uniform { min_secs: f64, max_secs: f64 },
+    Exponential { mean_secs: f64 },
+    LogNormal { mean_secs: f64, std_dev_secs: f64 },
+}
+
+impl DurationDistribution {
+    fn validate(&self, label: &str) -> Result<(), String> {
+        let ok = match self {
+            DurationDistribution::Fixed { secs } => *secs >= 0.0,
+            DurationDistribution::Uniform { min_secs, max_secs } => {
+                *min_secs >= 0.0 && max_secs >= min_secs
+            }
+            DurationDistribution::Exponential { mean_secs } => *mean_secs > 0.0,
+            DurationDistribution::LogNormal {
+                mean_secs,
+                std_dev_secs,
+            } => *mean_secs > 0.0 && *std_dev_secs >= 0.0,
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(format!("{} 분포 설정이 잘못되었습니다", label))
+        }
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> f64 {
+        match self {
+            DurationDistribution::Fixed { secs } => *secs,
+            DurationDistribution::Uniform { min_secs, max_secs } => {
+                min_secs + (max_secs - min_secs) * rng.gen::<f64>()
+            }
+            DurationDistribution::Exponential { mean_secs } => exponential(rng, *mean_secs),
+            DurationDistribution::LogNormal {
+                mean_secs,
+                std_dev_secs,
+            } => {
+                // 평균 / 표준편차로 로그 정규 분포의 μ, σ 계산
+                let variance = (1.0 + (std_dev_secs / mean_secs).powi(2)).ln();
+                let mu = mean_secs.ln() - variance / 2.0;
+                (mu + variance.sqrt() * standard_normal(rng)).exp()
+            }
+        }
+    }
+}
+
+fn exponential(rng: &mut StdRng, mean: f64) -> f64 {
+    -mean * (1.0 - rng.gen::<f64>()).ln()
+}
+
+/// Box-Muller
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = 1.0 - rng.gen::<f64>();
+    let u2: f64 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// 상담원 휴식 패턴
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreakPattern {
+    /// 휴식 간 평균 간격 (분, 지수 분포)
+    pub every_minutes: f64,
+    pub duration: DurationDistribution,
+    pub reason: BreakReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationConfig {
+    /// 같은 seed 면 같은 콜 / 상태 흐름이 재현된다. 생략 시 현재 시각으로 생성
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// 포아송 도착률 (시간당 콜 수)
+    pub arrivals_per_hour: f64,
+    /// 시뮬레이션 상담원 수
+    pub agents: u32,
+    pub handle_time: DurationDistribution,
+    pub wrap_up_time: DurationDistribution,
+    /// 호출 후 상담원이 응답하기까지 (초)
+    #[serde(default = "default_ring_secs")]
+    pub ring_secs: f64,
+    /// 평균 인내 시간 (초, 지수 분포). 생략 시 포기 없음
+    #[serde(default)]
+    pub average_patience_secs: Option<f64>,
+    #[serde(default)]
+    pub breaks: Option<BreakPattern>,
+    /// 지정 시 해당 대기열로 보고하고 상담원에게 대기열 스킬 배정
+    #[serde(default)]
+    pub queue_id: Option<String>,
+    /// 배속 (1.0 = 실시간)
+    #[serde(default = "default_speed")]
+    pub speed: f64,
+}
+
+fn default_ring_secs() -> f64 {
+    3.0
+}
+
+fn default_speed() -> f64 {
+    1.0
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            arrivals_per_hour: 120.0,
+            agents: 12,
+            handle_time: DurationDistribution::LogNormal {
+                mean_secs: 240.0,
+                std_dev_secs: 90.0,
+            },
+            wrap_up_time: DurationDistribution::Uniform {
+                min_secs: 10.0,
+                max_secs: 40.0,
+            },
+            ring_secs: default_ring_secs(),
+            average_patience_secs: Some(90.0),
+            breaks: Some(BreakPattern {
+                every_minutes: 45.0,
+                duration: DurationDistribution::Uniform {
+                    min_secs: 300.0,
+                    max_secs: 900.0,
+                },
+                reason: BreakReason::Rest,
+            }),
+            queue_id: None,
+            speed: default_speed(),
+        }
+    }
+}
+
+impl SimulationConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.arrivals_per_hour > 0.0 && self.arrivals_per_hour.is_finite()) {
+            return Err("도착률은 0보다 커야 합니다".to_string());
+        }
+        if self.agents == 0 {
+            return Err("상담원은 1명 이상이어야 합니다".to_string());
+        }
+        if self.ring_secs < 0.0 {
+            return Err("호출 시간은 0 이상이어야 합니다".to_string());
+        }
+        if self.average_patience_secs.is_some_and(|p| p <= 0.0) {
+            return Err("평균 인내 시간은 0보다 커야 합니다".to_string());
+        }
+        self.handle_time.validate("처리 시간")?;
+        self.wrap_up_time.validate("후처리 시간")?;
+        if let Some(breaks) = &self.breaks {
+            if breaks.every_minutes <= 0.0 {
+                return Err("휴식 간격은 0보다 커야 합니다".to_string());
+            }
+            breaks.duration.validate("휴식 시간")?;
+        }
+        validate_speed(self.speed)
+    }
+}
+
+fn validate_speed(speed: f64) -> Result<(), String> {
+    if speed > 0.0 && speed <= MAX_SPEED {
+        Ok(())
+    } else {
+        Err(format!("배속은 0 초과 {} 이하여야 합니다", MAX_SPEED))
+    }
+}
+
+/// 시뮬레이터가 만들어내는 입력. 실제 PBX 데이터와 같은 명령 경로로 반영된다.
+/// 시각은 모두 시뮬레이션 시각 (배속과 무관하게 KPI / 상태 지속 시간이 맞도록)
+#[derive(Debug, Clone)]
+pub enum SimulationEvent {
+    Agent {
+        update: AgentStatusUpdate,
+        at: DateTime<Utc>,
+    },
+    Call(CallEvent),
+    Queue(QueueStatus),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SimulationStats {
+    pub calls_generated: u32,
+    pub calls_answered: u32,
+    pub calls_abandoned: u32,
+    pub calls_completed: u32,
+}
+
+/// `get_simulation_status` 응답
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationStatus {
+    pub running: bool,
+    pub seed: Option<u64>,
+    pub speed: f64,
+    /// 시뮬레이션 내부 경과 시간 (초)
+    pub simulated_secs: i64,
+    pub waiting: u32,
+    pub stats: SimulationStats,
+    pub config: Option<SimulationConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Step {
+    Arrival,
+    Abandon { call_id: String },
+    Answer { agent: usize, call_id: String },
+    HangUp { agent: usize },
+    WrapUpDone { agent: usize },
+    BreakDue { agent: usize },
+    BreakEnd { agent: usize },
+}
+
+#[derive(Debug)]
+struct SimAgent {
+    id: String,
+    name: String,
+    state: AgentState,
+    call: Option<String>,
+    /// 통화 중 휴식 시간이 되면 후처리 후 휴식
+    break_due: bool,
+    idle_since: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct SimCall {
+    id: String,
+}
+
+/// 이산 사건 시뮬레이터. 시뮬레이션 시간으로만 동작하므로 seed 가 같으면 결과가 같다
+pub struct Simulator {
+    config: SimulationConfig,
+    seed: u64,
+    rng: StdRng,
+    clock: SimulatedClock,
+    started_at: DateTime<Utc>,
+    schedule: BinaryHeap<Reverse<(DateTime<Utc>, u64, Step)>>,
+    next_seq: u64,
+    next_call: u64,
+    agents: Vec<SimAgent>,
+    waiting: VecDeque<SimCall>,
+    stats: SimulationStats,
+    last_queue: Option<QueueStatus>,
+}
+
+impl Simulator {
+    /// 상담원 로그인 이벤트와 함께 시뮬레이터 생성. `skill` 은 상담원에게 배정할 대기열 스킬
+    pub fn new(
+        config: SimulationConfig,
+        seed: u64,
+        skill: Option<String>,
+        start: DateTime<Utc>,
+    ) -> (Self, Vec<SimulationEvent>) {
+        let mut sim = Self {
+            rng: StdRng::seed_from_u64(seed),
+            seed,
+            clock: SimulatedClock::new(start),
+            started_at: start,
+            schedule: BinaryHeap::new(),
+            next_seq: 0,
+            next_call: 0,
+            agents: (1..=config.agents)
+                .map(|n| SimAgent {
+                    id: format!("sim-{}", n),
+                    name: format!("시뮬 상담원 {}", n),
+                    state: AgentState::Offline,
+                    call: None,
+                    break_due: false,
+                    idle_since: start,
+                })
+                .collect(),
+            waiting: VecDeque::new(),
+            stats: SimulationStats::default(),
+            last_queue: None,
+            config,
+        };
+
+        let skills = skill.map(|skill| {
+            vec![AgentSkill {
+                skill,
+                proficiency: 3,
+            }]
+        });
+        let mut events = Vec::new();
+        for agent in 0..sim.agents.len() {
+            sim.agents[agent].state = AgentState::Available;
+            let mut update = sim.agent_update(agent, None);
+            update.skills = skills.clone();
+            events.push(SimulationEvent::Agent { update, at: start });
+            sim.schedule_next_break(agent, start);
+        }
+        let first_arrival = sim.next_arrival_gap();
+        sim.schedule_in(start, first_arrival, Step::Arrival);
+        sim.push_queue_status(&mut events);
+        (sim, events)
+    }
+
+    pub fn config(&self) -> &SimulationConfig {
+        &self.config
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn stats(&self) -> &SimulationStats {
+        &self.stats
+    }
+
+    pub fn waiting(&self) -> u32 {
+        self.waiting.len() as u32
+    }
+
+    /// 현재 시뮬레이션 시각
+    pub fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    pub fn simulated_secs(&self) -> i64 {
+        (self.clock.now() - self.started_at).num_seconds()
+    }
+
+    fn schedule_in(&mut self, from: DateTime<Utc>, secs: f64, step: Step) {
+        let at = from + Duration::milliseconds((secs.max(0.0) * 1000.0) as i64);
+        self.next_seq += 1;
+        self.schedule.push(Reverse((at, self.next_seq, step)));
+    }
+
+    fn next_arrival_gap(&mut self) -> f64 {
+        exponential(&mut self.rng, 3600.0 / self.config.arrivals_per_hour)
+    }
+
+    fn schedule_next_break(&mut self, agent: usize, from: DateTime<Utc>) {
+        if let Some(breaks) = &self.config.breaks {
+            let gap = exponential(&mut self.rng, breaks.every_minutes * 60.0);
+            self.schedule_in(from, gap, Step::BreakDue { agent });
+        }
+    }
+
+    fn agent_update(&self, agent: usize, break_reason: Option<BreakReason>) -> AgentStatusUpdate {
+        let a = &self.agents[agent];
+        AgentStatusUpdate {
+            id: a.id.clone(),
+            name: a.name.clone(),
+            status: a.state,
+            break_reason,
+            current_call: a.call.clone(),
+            call_duration: None,
+            skills: None,
+            simulated: true,
+        }
+    }
+
+    fn set_agent(
+        &mut self,
+        agent: usize,
+        state: AgentState,
+        break_reason: Option<BreakReason>,
+        at: DateTime<Utc>,
+        events: &mut Vec<SimulationEvent>,
+    ) {
+        self.agents[agent].state = state;
+        if state == AgentState::Available {
+            self.agents[agent].idle_since = at;
+        }
+        events.push(SimulationEvent::Agent {
+            update: self.agent_update(agent, break_reason),
+            at,
+        });
+    }
+
+    fn queue_status(&self) -> QueueStatus {
+        QueueStatus {
+            inbound_waiting: self.waiting.len() as u32,
+            inbound_agents_available: self
+                .agents
+                .iter()
+                .filter(|a| a.state == AgentState::Available)
+                .count() as u32,
+            inbound_agents_total: self
+                .agents
+                .iter()
+                .filter(|a| a.state != AgentState::Offline)
+                .count() as u32,
+            ..QueueStatus::default()
+        }
+    }
+
+    fn push_queue_status(&mut self, events: &mut Vec<SimulationEvent>) {
+        let status = self.queue_status();
+        if self.last_queue.as_ref() != Some(&status) {
+            self.last_queue = Some(status.clone());
+            events.push(SimulationEvent::Queue(status));
+        }
+    }
+
+    /// 시뮬레이션 시간을 `by` 만큼 진행하고 그 사이 발생한 이벤트 반환
+    pub fn advance(&mut self, by: Duration) -> Vec<SimulationEvent> {
+        let until = self.clock.advance(by);
+        let mut events = Vec::new();
+        while let Some(Reverse((at, _, _))) = self.schedule.peek() {
+            if *at > until {
+                break;
+            }
+            let Some(Reverse((at, _, step))) = self.schedule.pop() else {
+                break;
+            };
+            self.process(step, at, &mut events);
+        }
+        self.push_queue_status(&mut events);
+        events
+    }
+
+    fn process(&mut self, step: Step, at: DateTime<Utc>, events: &mut Vec<SimulationEvent>) {
+        match step {
+            Step::Arrival => {
+                self.next_call += 1;
+                let call_id = format!("sim-call-{}", self.next_call);
+                self.stats.calls_generated += 1;
+                events.push(SimulationEvent::Call(CallEvent::Enqueued {
+                    call_id: call_id.clone(),
+                    at: Some(at),
+                }));
+                if let Some(patience) = self.config.average_patience_secs {
+                    let wait = exponential(&mut self.rng, patience);
+                    self.schedule_in(
+                        at,
+                        wait,
+                        Step::Abandon {
+                            call_id: call_id.clone(),
+                        },
+                    );
+                }
+                self.waiting.push_back(SimCall { id: call_id });
+                let gap = self.next_arrival_gap();
+                self.schedule_in(at, gap, Step::Arrival);
+            }
+            Step::Abandon { call_id } => {
+                // 이미 상담원에게 연결된 콜은 무시
+                if let Some(index) = self.waiting.iter().position(|c| c.id == call_id) {
+                    self.waiting.remove(index);
+                    self.stats.calls_abandoned += 1;
+                    events.push(SimulationEvent::Call(CallEvent::Abandoned {
+                        call_id,
+                        at: Some(at),
+                    }));
+                }
+            }
+            Step::Answer { agent, call_id } => {
+                self.set_agent(agent, AgentState::Busy, None, at, events);
+                self.stats.calls_answered += 1;
+                events.push(SimulationEvent::Call(CallEvent::Answered {
+                    call_id,
+                    agent_id: self.agents[agent].id.clone(),
+                    at: Some(at),
+                }));
+                let handle = self.config.handle_time.sample(&mut self.rng);
+                self.schedule_in(at, handle, Step::HangUp { agent });
+            }
+            Step::HangUp { agent } => {
+                self.set_agent(agent, AgentState::WrapUp, None, at, events);
+                let wrap_up = self.config.wrap_up_time.sample(&mut self.rng);
+                self.schedule_in(at, wrap_up, Step::WrapUpDone { agent });
+            }
+            Step::WrapUpDone { agent } => {
+                if let Some(call_id) = self.agents[agent].call.take() {
+                    self.stats.calls_completed += 1;
+                    events.push(SimulationEvent::Call(CallEvent::Completed {
+                        call_id,
+                        at: Some(at),
+                    }));
+                }
+                if self.agents[agent].break_due {
+                    self.start_break(agent, at, events);
+                } else {
+                    self.set_agent(agent, AgentState::Available, None, at, events);
+                }
+            }
+            Step::BreakDue { agent } => {
+                if self.agents[agent].state == AgentState::Available {
+                    self.start_break(agent, at, events);
+                } else {
+                    self.agents[agent].break_due = true;
+                }
+            }
+            Step::BreakEnd { agent } => {
+                self.set_agent(agent, AgentState::Available, None, at, events);
+                self.schedule_next_break(agent, at);
+            }
+        }
+        self.dispatch(at, events);
+    }
+
+    fn start_break(&mut self, agent: usize, at: DateTime<Utc>, events: &mut Vec<SimulationEvent>) {
+        let Some(breaks) = self.config.breaks.clone() else {
+            return;
+        };
+        self.agents[agent].break_due = false;
+        self.set_agent(agent, AgentState::Break, Some(breaks.reason), at, events);
+        let duration = breaks.duration.sample(&mut self.rng);
+        self.schedule_in(at, duration, Step::BreakEnd { agent });
+    }
+
+    /// 대기 콜을 가장 오래 대기한 상담원부터 연결
+    fn dispatch(&mut self, at: DateTime<Utc>, events: &mut Vec<SimulationEvent>) {
+        while !self.waiting.is_empty() {
+            let Some(agent) = self
+                .agents
+                .iter()
+                .enumerate()
+                .filter(|(_, a)| a.state == AgentState::Available)
+                .min_by_key(|(index, a)| (a.idle_since, *index))
+                .map(|(index, _)| index)
+            else {
+                break;
+            };
+            let call = self.waiting.pop_front().expect("checked non-empty");
+            self.agents[agent].call = Some(call.id.clone());
+            self.set_agent(agent, AgentState::Ringing, None, at, events);
+            self.schedule_in(
+                at,
+                self.config.ring_secs,
+                Step::Answer {
+                    agent,
+                    call_id: call.id,
+                },
+            );
+        }
+    }
+
+    /// 정지: 진행 중인 콜을 정리하고 상담원을 허용된 전이만으로 offline 까지 보낸다
+    pub fn shutdown(&mut self) -> Vec<SimulationEvent> {
+        let at = self.clock.now();
+        let mut events = Vec::new();
+        self.schedule.clear();
+
+        for call in std::mem::take(&mut self.waiting) {
+            self.stats.calls_abandoned += 1;
+            events.push(SimulationEvent::Call(CallEvent::Abandoned {
+                call_id: call.id,
+                at: Some(at),
+            }));
+        }
+
+        for agent in 0..self.agents.len() {
+            let path: &[AgentState] = match self.agents[agent].state {
+                AgentState::Ringing => &[AgentState::Available, AgentState::Offline],
+                AgentState::Busy => &[
+                    AgentState::WrapUp,
+                    AgentState::Available,
+                    AgentState::Offline,
+                ],
+                AgentState::WrapUp | AgentState::Break | AgentState::Available => {
+                    &[AgentState::Offline]
+                }
+                AgentState::Offline => &[],
+            };
+
+            if let Some(call_id) = self.agents[agent].call.take() {
+                let event = match self.agents[agent].state {
+                    AgentState::Ringing => {
+                        self.stats.calls_abandoned += 1;
+                        CallEvent::Abandoned {
+                            call_id,
+                            at: Some(at),
+                        }
+                    }
+                    _ => {
+                        self.stats.calls_completed += 1;
+                        CallEvent::Completed {
+                            call_id,
+                            at: Some(at),
+                        }
+                    }
+                };
+                events.push(SimulationEvent::Call(event));
+            }
+            for state in path {
+                self.set_agent(agent, *state, None, at, &mut events);
+            }
+        }
+        self.push_queue_status(&mut events);
+        events
+    }
+}
+
+// === Tauri 연동 ===
+
+struct SimulationRunner {
+    simulator: Simulator,
+    speed: f64,
+    /// stop / 재시작 시 이전 루프를 끝내기 위한 식별자
+    run_id: u64,
+}
+
+/// 시뮬레이션 실행 상태
+#[derive(Default)]
+pub struct SimulationState {
+    runner: Mutex<Option<SimulationRunner>>,
+    next_run_id: Mutex<u64>,
+}
+
+impl SimulationState {
+    pub fn status(&self) -> SimulationStatus {
+        match self.runner.lock().unwrap().as_ref() {
+            Some(runner) => SimulationStatus {
+                running: true,
+                seed: Some(runner.simulator.seed()),
+                speed: runner.speed,
+                simulated_secs: runner.simulator.simulated_secs(),
+                waiting: runner.simulator.waiting(),
+                stats: runner.simulator.stats().clone(),
+                config: Some(runner.simulator.config().clone()),
+            },
+            None => SimulationStatus {
+                running: false,
+                seed: None,
+                speed: default_speed(),
+                simulated_secs: 0,
+                waiting: 0,
+                stats: SimulationStats::default(),
+                config: None,
+            },
+        }
+    }
+}
+
+/// 시뮬레이터 이벤트를 실제 데이터와 같은 명령 함수로 QueueState 에 반영
+fn apply_events<R: Runtime>(
+    app: &AppHandle<R>,
+    queue_id: Option<&String>,
+    events: Vec<SimulationEvent>,
+) {
+    for event in events {
+        let state = app.state::<QueueState>();
+        let result = match event {
+            SimulationEvent::Agent { update, at } => {
+                queue_state::update_agent_status_at(app, &state, update, at)
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            }
+            SimulationEvent::Call(call) => {
+                queue_state::record_call_event(app.clone(), state, call, queue_id.cloned())
+            }
+            SimulationEvent::Queue(status) => {
+                queue_state::update_queue_status(app.clone(), state, status, queue_id.cloned())
+            }
+        };
+        if let Err(e) = result {
+            println!("⚠️ [SIMULATION] 이벤트 반영 실패: {}", e);
+        }
+    }
+}
+
+fn run_loop<R: Runtime>(app: AppHandle<R>, run_id: u64) {
+    tauri::async_runtime::spawn(async move {
+        let tick = std::time::Duration::from_millis(SIMULATION_TICK_MILLIS);
+        loop {
+            tokio::time::sleep(tick).await;
+            let sim = app.state::<SimulationState>();
+            // 적용이 끝날 때까지 runner 를 잡아 둔다. 정지 후 정리된 상태에 이전 진행분이 다시 쓰이지 않도록
+            let mut guard = sim.runner.lock().unwrap();
+            let runner = match guard.as_mut() {
+                Some(runner) if runner.run_id == run_id => runner,
+                _ => break,
+            };
+            let by = Duration::milliseconds((SIMULATION_TICK_MILLIS as f64 * runner.speed) as i64);
+            let events = runner.simulator.advance(by);
+            app.state::<QueueState>()
+                .set_simulated_now(Some(runner.simulator.now()));
+            apply_events(&app, runner.simulator.config().queue_id.as_ref(), events);
+        }
+    });
+}
+
+// === 시뮬레이션 Tauri Commands ===
+
+/// 시뮬레이션 시작 (실행 중이면 정지 후 새로 시작). 사용된 seed 반환
+#[tauri::command]
+pub fn start_simulation<R: Runtime>(
+    app: AppHandle<R>,
+    sim: tauri::State<'_, SimulationState>,
+    config: Option<SimulationConfig>,
+) -> Result<SimulationStatus, String> {
+    let config = config.unwrap_or_default();
+    config.validate()?;
+    let skill = match &config.queue_id {
+        Some(queue_id) => {
+            let state = app.state::<QueueState>();
+            let queues = state.queues.lock().unwrap();
+            Some(queues.definition(queue_id)?.skill().to_string())
+        }
+        None if !app.state::<QueueState>().queues.lock().unwrap().is_empty() => {
+            return Err(
+                "대기열이 정의되어 있으면 시뮬레이션할 대기열을 지정해야 합니다".to_string(),
+            )
+        }
+        None => None,
+    };
+
+    stop_simulation(app.clone(), sim.clone())?;
+
+    let seed = config
+        .seed
+        .unwrap_or_else(|| Utc::now().timestamp_nanos_opt().unwrap_or_default() as u64);
+    let queue_id = config.queue_id.clone();
+    let speed = config.speed;
+    let (simulator, events) = Simulator::new(config, seed, skill, Utc::now());
+    app.state::<QueueState>()
+        .set_simulated_now(Some(simulator.now()));
+
+    let run_id = {
+        let mut next = sim.next_run_id.lock().unwrap();
+        *next += 1;
+        *next
+    };
+    *sim.runner.lock().unwrap() = Some(SimulationRunner {
+        simulator,
+        speed,
+        run_id,
+    });
+    apply_events(&app, queue_id.as_ref(), events);
+    run_loop(app, run_id);
+
+    println!("🎲 [SIMULATION] 시작 (seed {}, {}배속)", seed, speed);
+    Ok(sim.status())
+}
+
+/// 시뮬레이션 정지. 진행 중인 콜은 정리하고 상담원은 offline 으로
+#[tauri::command]
+pub fn stop_simulation<R: Runtime>(
+    app: AppHandle<R>,
+    sim: tauri::State<'_, SimulationState>,
+) -> Result<(), String> {
+    let Some(mut runner) = sim.runner.lock().unwrap().take() else {
+        return Ok(());
+    };
+    let events = runner.simulator.shutdown();
+    apply_events(&app, runner.simulator.config().queue_id.as_ref(), events);
+    queue_state::end_simulation(&app, &app.state::<QueueState>());
+    println!(
+        "⏹️ [SIMULATION] 정지 (생성 {}건)",
+        runner.simulator.stats().calls_generated
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_simulation_speed(
+    sim: tauri::State<'_, SimulationState>,
+    speed: f64,
+) -> Result<(), String> {
+    validate_speed(speed)?;
+    match sim.runner.lock().unwrap().as_mut() {
+        Some(runner) => {
+            runner.speed = speed;
+            println!("⏩ [SIMULATION] {}배속", speed);
+            Ok(())
+        }
+        None => Err("실행 중인 시뮬레이션이 없습니다".to_string()),
+    }
+}
+
+#[tauri::command]
+pub fn get_simulation_status(sim: tauri::State<'_, SimulationState>) -> SimulationStatus {
+    sim.status()
+}
@@ -1,9 +1,36 @@
 // C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\queue_state.rs
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use super::agent_state::{AgentState, AgentStateMachine, AgentTransitionError, BreakReason};
+use super::queue_alerts::{AlertEngine, AlertRule, AlertStatus, QueueAlert};
+use super::queue_delta::{QueueDelta, QueueDeltaLog, QueueResync};
+use super::queue_history::{
+    AgentCounts, HistoryConfig, HistorySample, HistorySnapshot, QueueHistory, QueueHistorySeries,
+};
+use super::queue_kpi::{CallEvent, KpiEngine, QueueKpiReport};
+use super::queue_registry::{
+    validate_agent_skills, AgentSkill, QueueDefinition, QueueRegistry, QueueSummary,
+};
+use super::routing::RoutingEngine;
+
+// === 대기열 이벤트 계약 ===
+// 프론트엔드(QueueMonitorApp 등)는 아래 이벤트명으로 listen 한다.
+// 모든 이벤트는 app 전체(모든 윈도우)로 브로드캐스트된다.
+// 상태 스냅샷은 브로드캐스트하지 않는다. 전체 상태는 재동기화할 때만 명령으로 조회한다.
+
+/// payload: `QueueKpiReport` (전체 KPI. 대기열별 KPI 는 `list_queues` 로 조회)
+pub const QUEUE_KPIS_UPDATED_EVENT: &str = "queue-kpis-updated";
+/// payload: `QueueAlert` (발생 / 해소)
+pub const QUEUE_ALERT_EVENT: &str = "queue-alert";
+/// payload: `QueueDelta` (seq 가 1씩 증가. 빠진 seq 가 있으면 `get_queue_snapshot_since` 로 재동기화)
+pub const QUEUE_DELTA_EVENT: &str = "queue-delta";
+
+/// 알림 규칙 평가 주기 (초)
+const ALERT_TICK_SECS: u64 = 5;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct QueueStatus {
     pub inbound_waiting: u32,
     pub inbound_agents_available: u32,
@@ -13,26 +40,47 @@ pub struct QueueStatus {
     pub outbound_calls_today: u32,
 }
 
-impl Default for QueueStatus {
-    fn default() -> Self {
-        Self {
-            inbound_waiting: 0,
-            inbound_agents_available: 0,
-            inbound_agents_total: 0,
-            outbound_active_campaigns: 0,
-            outbound_calls_in_progress: 0,
-            outbound_calls_today: 0,
-        }
+/// 프론트엔드 `AgentInfo` 인터페이스와 맞추기 위해 camelCase 로 직렬화
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentStatus {
+    pub id: String,
+    pub name: String,
+    /// status / breakReason / statusSince / previousStatus
+    #[serde(flatten)]
+    pub state: AgentStateMachine,
+    pub current_call: Option<String>,
+    pub call_duration: Option<u32>,
+    /// 시뮬레이션 상담원 (실제 콜은 분배하지 않는다)
+    #[serde(default)]
+    pub simulated: bool,
+}
+
+impl AgentStatus {
+    pub fn status(&self) -> AgentState {
+        self.state.status
     }
 }
 
+/// `update_agent_status` 입력. 상태 진입 시각은 Rust 쪽에서 기록한다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AgentStatus {
+#[serde(rename_all = "camelCase")]
+pub struct AgentStatusUpdate {
     pub id: String,
     pub name: String,
-    pub status: String, // "available", "busy", "break", "offline"
+    pub status: AgentState,
+    #[serde(default)]
+    pub break_reason: Option<BreakReason>,
+    #[serde(default)]
     pub current_call: Option<String>,
+    #[serde(default)]
     pub call_duration: Option<u32>,
+    /// 지정 시 스킬 배정 교체 (생략 시 기존 배정 유지)
+    #[serde(default)]
+    pub skills: Option<Vec<AgentSkill>>,
+    /// 시뮬레이터만 설정 (프론트엔드 입력에서는 받지 않음)
+    #[serde(skip)]
+    pub simulated: bool,
 }
 
 // 대기열 관련 상태
@@ -41,108 +89,765 @@ pub struct QueueState {
     pub queue_status: Mutex<QueueStatus>,
     pub agents: Mutex<Vec<AgentStatus>>,
     pub last_updated: Mutex<Option<String>>,
+    pub kpis: Mutex<KpiEngine>,
+    pub history: Mutex<QueueHistory>,
+    /// 히스토리 파일 쓰기 직렬화. 디스크 I/O 동안 `history` 대신 이것만 잡는다
+    history_writes: Mutex<()>,
+    pub alerts: Mutex<AlertEngine>,
+    pub queues: Mutex<QueueRegistry>,
+    pub routing: Mutex<RoutingEngine>,
+    /// 대기열 / 상담원 변경분 로그. 항상 다른 잠금을 잡은 상태에서 마지막에 잠근다
+    pub deltas: Mutex<QueueDeltaLog>,
+    /// 시뮬레이션 실행 중이면 시뮬레이션 시각 (KPI / 알림 계산 기준)
+    simulated_now: Mutex<Option<chrono::DateTime<chrono::Utc>>>,
+}
+
+impl QueueState {
+    fn touch(&self) {
+        *self.last_updated.lock().unwrap() = Some(chrono::Utc::now().to_rfc3339());
+    }
+
+    /// KPI / 알림 기준 시각. 시뮬레이션 중에는 배속이 반영된 시뮬레이션 시각
+    pub fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        self.simulated_now
+            .lock()
+            .unwrap()
+            .unwrap_or_else(chrono::Utc::now)
+    }
+
+    /// 시뮬레이터가 매 진행마다 호출. 정지 시 None
+    pub fn set_simulated_now(&self, now: Option<chrono::DateTime<chrono::Utc>>) {
+        *self.simulated_now.lock().unwrap() = now;
+    }
+
+    pub fn kpi_report(&self) -> QueueKpiReport {
+        self.kpis.lock().unwrap().report(self.now())
+    }
+
+    /// 디스크에 저장된 히스토리 복원 (앱 시작 시 1회)
+    pub fn load_history(&self, path: std::path::PathBuf) {
+        *self.history.lock().unwrap() = QueueHistory::load(path, chrono::Utc::now());
+    }
+
+    /// 저장된 알림 규칙 로드 (앱 시작 시 1회)
+    pub fn load_alert_rules(&self, path: std::path::PathBuf) {
+        *self.alerts.lock().unwrap() = AlertEngine::load(path);
+    }
+
+    /// 저장된 대기열 목록 / 스킬 배정 로드 (앱 시작 시 1회)
+    pub fn load_queue_registry(&self, path: std::path::PathBuf) {
+        *self.queues.lock().unwrap() = QueueRegistry::load(path);
+    }
+
+    /// 현재 대기열 / 상담원 상태를 히스토리에 기록
+    pub fn sample_history(&self, now: chrono::DateTime<chrono::Utc>) {
+        let agents = self.agents.lock().unwrap().clone();
+        let sample = HistorySample {
+            at: now,
+            queue: self.queue_status.lock().unwrap().clone(),
+            agents: AgentCounts::from_agents(&agents),
+            queues: self.queues.lock().unwrap().samples(&agents),
+        };
+        self.history.lock().unwrap().record(sample);
+    }
+
+    /// 저장 주기가 됐으면 히스토리를 파일에 저장
+    pub fn persist_history_if_due(&self, now: chrono::DateTime<chrono::Utc>) -> Result<(), String> {
+        self.write_history(|history| history.snapshot_if_due(now))
+    }
+
+    pub fn persist_history(&self, now: chrono::DateTime<chrono::Utc>) -> Result<(), String> {
+        self.write_history(|history| history.snapshot(now))
+    }
+
+    /// 스냅샷만 `history` 잠금 안에서 뜨고 파일 쓰기는 잠금 밖에서 한다.
+    /// 쓰기 잠금을 먼저 잡아 먼저 뜬 스냅샷이 나중 것을 덮어쓰지 않게 한다
+    fn write_history(
+        &self,
+        snapshot: impl FnOnce(&mut QueueHistory) -> Option<HistorySnapshot>,
+    ) -> Result<(), String> {
+        let _writing = self.history_writes.lock().unwrap();
+        let snapshot = snapshot(&mut self.history.lock().unwrap());
+        match snapshot {
+            Some(snapshot) => snapshot.write(),
+            None => Ok(()),
+        }
+    }
+
+    /// 전체 또는 특정 대기열의 상태
+    pub fn status_for(&self, queue_id: Option<&str>) -> Result<QueueStatus, String> {
+        match queue_id {
+            Some(id) => self.queues.lock().unwrap().status(id),
+            None => Ok(self.queue_status.lock().unwrap().clone()),
+        }
+    }
+
+    /// 상담원이 통화 중(링 / 통화)인지. 자리 비움 잠금 판단에 사용
+    pub fn is_agent_on_call(&self, agent_id: &str) -> bool {
+        self.agents.lock().unwrap().iter().any(|agent| {
+            agent.id == agent_id && matches!(agent.status(), AgentState::Ringing | AgentState::Busy)
+        })
+    }
+
+    /// 전체 또는 특정 대기열을 처리하는 상담원
+    pub fn agents_for(&self, queue_id: Option<&str>) -> Result<Vec<AgentStatus>, String> {
+        let agents = self.agents.lock().unwrap().clone();
+        match queue_id {
+            Some(id) => {
+                let queues = self.queues.lock().unwrap();
+                queues.definition(id)?;
+                Ok(queues.agents_for(id, &agents))
+            }
+            None => Ok(agents),
+        }
+    }
+
+    /// 전체 또는 특정 대기열의 KPI
+    pub fn kpi_report_for(&self, queue_id: Option<&str>) -> Result<QueueKpiReport, String> {
+        match queue_id {
+            Some(id) => Ok(self.queues.lock().unwrap().kpis(id)?.report(self.now())),
+            None => Ok(self.kpi_report()),
+        }
+    }
+
+    pub fn queue_summaries(&self) -> Vec<QueueSummary> {
+        let agents = self.agents.lock().unwrap().clone();
+        self.queues.lock().unwrap().summaries(&agents, self.now())
+    }
+}
+
+/// 설정된 해상도마다 히스토리를 샘플링하고 주기적으로 디스크에 저장
+pub fn start_history_sampler<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let resolution = app
+                .state::<QueueState>()
+                .history
+                .lock()
+                .unwrap()
+                .config()
+                .resolution_secs;
+            tokio::time::sleep(std::time::Duration::from_secs(resolution as u64)).await;
+
+            let state = app.state::<QueueState>();
+            let now = chrono::Utc::now();
+            state.sample_history(now);
+            if let Err(e) = state.persist_history_if_due(now) {
+                println!("⚠️ [QUEUE] 히스토리 저장 실패: {}", e);
+            }
+        }
+    });
+}
+
+/// 홀드 시간 / 휴식 시간처럼 업데이트 없이도 변하는 조건을 위해 주기적으로 규칙 평가
+pub fn start_alert_monitor<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(ALERT_TICK_SECS)).await;
+            evaluate_alerts(&app, &app.state::<QueueState>());
+        }
+    });
+}
+
+/// 알림 규칙 평가 후 변경분을 이벤트 + 데스크톱 알림으로 발송
+fn evaluate_alerts<R: Runtime>(app: &AppHandle<R>, state: &QueueState) {
+    let status = state.queue_status.lock().unwrap().clone();
+    let agents = state.agents.lock().unwrap().clone();
+    let views = state.queues.lock().unwrap().views(&agents);
+    let changes = state
+        .alerts
+        .lock()
+        .unwrap()
+        .evaluate(&status, &agents, &views, state.now());
+
+    for alert in changes {
+        let _ = app.emit(QUEUE_ALERT_EVENT, &alert);
+        match alert.status {
+            AlertStatus::Active => {
+                println!("🚨 [ALERT] {}: {}", alert.rule_name, alert.message);
+                notify_desktop(app, &alert);
+            }
+            AlertStatus::Resolved => {
+                println!("✅ [ALERT] 해소: {}", alert.rule_name);
+            }
+        }
+    }
+}
+
+fn notify_desktop<R: Runtime>(app: &AppHandle<R>, alert: &QueueAlert) {
+    // 알림 플러그인이 등록되지 않은 앱(테스트 mock 등)에서는 이벤트만 보낸다
+    let Some(notification) = app.try_state::<tauri_plugin_notification::Notification<R>>() else {
+        return;
+    };
+    if let Err(e) = notification
+        .builder()
+        .title(format!("Nexus Call Hub - {}", alert.rule_name))
+        .body(&alert.message)
+        .show()
+    {
+        println!("⚠️ [ALERT] 데스크톱 알림 실패: {}", e);
+    }
+}
+
+/// 최신 KPI 를 모든 윈도우에 발송
+fn emit_kpis<R: Runtime>(app: &AppHandle<R>, state: &QueueState) {
+    let _ = app.emit(QUEUE_KPIS_UPDATED_EVENT, state.kpi_report());
+}
+
+/// 변경분을 seq 순서대로 모든 윈도우에 발송
+fn emit_deltas<R: Runtime>(app: &AppHandle<R>, deltas: impl IntoIterator<Item = QueueDelta>) {
+    for delta in deltas {
+        let _ = app.emit(QUEUE_DELTA_EVENT, &delta);
+    }
 }
 
 // === 대기열 관련 Tauri Commands ===
 
+/// `queue_id` 지정 시 해당 대기열만 갱신하고 전체 상태는 대기열 합계로 다시 계산.
+/// 대기열이 하나라도 있으면 `queue_id` 없이 전체 상태를 덮어쓸 수 없다
 #[tauri::command]
-pub fn update_queue_status(
-    app: AppHandle,
-    state: tauri::State<QueueState>,
+pub fn update_queue_status<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
     status: QueueStatus,
+    queue_id: Option<String>,
 ) -> Result<(), String> {
-    // 1. 상태 업데이트
-    *state.queue_status.lock().unwrap() = status.clone();
-    *state.last_updated.lock().unwrap() = Some(chrono::Utc::now().to_rfc3339());
+    // 1. 상태 업데이트 (delta 기록까지 같은 잠금 안에서 해야 seq 순서가 상태 순서와 같다)
+    let now = chrono::Utc::now();
+    let deltas = match &queue_id {
+        Some(id) => {
+            let mut queues = state.queues.lock().unwrap();
+            queues.set_status(id, status.clone())?;
+            let total = queues.aggregate_status();
+            *state.queue_status.lock().unwrap() = total.clone();
+            let mut log = state.deltas.lock().unwrap();
+            [
+                log.record_status(Some(id), &status, now),
+                log.record_status(None, &total, now),
+            ]
+        }
+        None => {
+            // 대기열이 정의되어 있으면 전체 상태는 항상 대기열 합계다
+            let queues = state.queues.lock().unwrap();
+            if !queues.is_empty() {
+                return Err("대기열이 정의되어 있으면 queue_id 를 지정해야 합니다".to_string());
+            }
+            let mut current = state.queue_status.lock().unwrap();
+            *current = status.clone();
+            let delta = state
+                .deltas
+                .lock()
+                .unwrap()
+                .record_status(None, &status, now);
+            [delta, None]
+        }
+    };
+    state.touch();
+    state.sample_history(now);
 
-    // 2. 모든 윈도우에 대기열 상태 변경 이벤트 발송
-    app.emit("queue-status-updated", &status)
-        .map_err(|e| e.to_string())?;
+    // 2. 모든 윈도우에 변경분 발송
+    emit_deltas(&app, deltas.into_iter().flatten());
+    evaluate_alerts(&app, &state);
 
-    println!("📊 [QUEUE] 대기열 상태 업데이트");
+    println!(
+        "📊 [QUEUE] 대기열 상태 업데이트 ({})",
+        queue_id.as_deref().unwrap_or("전체")
+    );
     Ok(())
 }
 
 #[tauri::command]
-pub fn get_queue_status(state: tauri::State<QueueState>) -> QueueStatus {
-    state.queue_status.lock().unwrap().clone()
+pub fn get_queue_status(
+    state: tauri::State<'_, QueueState>,
+    queue_id: Option<String>,
+) -> Result<QueueStatus, String> {
+    state.status_for(queue_id.as_deref())
 }
 
 #[tauri::command]
-pub fn update_agent_status(
-    app: AppHandle,
-    state: tauri::State<QueueState>,
-    agent: AgentStatus,
-) -> Result<(), String> {
-    let mut agents = state.agents.lock().unwrap();
+pub fn update_agent_status<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    agent: AgentStatusUpdate,
+) -> Result<AgentStatus, AgentTransitionError> {
+    update_agent_status_at(&app, &state, agent, chrono::Utc::now())
+}
 
-    // 기존 상담원 찾아서 업데이트 또는 새로 추가
-    if let Some(existing_agent) = agents.iter_mut().find(|a| a.id == agent.id) {
-        *existing_agent = agent.clone();
-    } else {
-        agents.push(agent.clone());
-    }
+/// `update_agent_status` 와 같지만 상태 진입 시각을 지정한다 (시뮬레이터용)
+pub fn update_agent_status_at<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &QueueState,
+    agent: AgentStatusUpdate,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<AgentStatus, AgentTransitionError> {
+    // 스킬이 잘못됐으면 아무것도 바꾸기 전에 거부한다
+    let invalid_skills = |reason| AgentTransitionError::InvalidSkills {
+        agent_id: agent.id.clone(),
+        reason,
+    };
+    let skills = match agent.skills.clone().map(validate_agent_skills).transpose() {
+        Ok(skills) => skills,
+        Err(reason) => {
+            let e = invalid_skills(reason);
+            println!("⛔ [QUEUE] 상담원 상태 업데이트 거부: {}", e);
+            return Err(e);
+        }
+    };
 
-    // 모든 윈도우에 상담원 상태 변경 이벤트 발송
-    app.emit("agent-status-updated", &agent)
-        .map_err(|e| e.to_string())?;
+    let (updated, delta) = {
+        let mut agents = state.agents.lock().unwrap();
+
+        // 기존 상담원 찾아서 업데이트 또는 새로 추가 (신규 상담원은 offline 에서 시작)
+        let index = agents.iter().position(|a| a.id == agent.id);
+        let mut updated = match index {
+            Some(index) => agents[index].clone(),
+            None => AgentStatus {
+                id: agent.id.clone(),
+                name: agent.name.clone(),
+                state: AgentStateMachine::new(now),
+                current_call: None,
+                call_duration: None,
+                simulated: agent.simulated,
+            },
+        };
+
+        let changed =
+            match updated
+                .state
+                .transition(&agent.id, agent.status, agent.break_reason, now)
+            {
+                Ok(changed) => changed,
+                Err(e) => {
+                    println!("⛔ [QUEUE] 상담원 상태 전이 거부: {}", e);
+                    return Err(e);
+                }
+            };
+
+        let mut queues = state.queues.lock().unwrap();
+        if let Some(skills) = skills {
+            queues
+                .set_agent_skills(&agent.id, skills, Some(updated.status()), now)
+                .map_err(invalid_skills)?;
+        }
+        if changed {
+            // KPI 는 한 시계로만 쌓는다. 시뮬레이션 중 실제 상담원 변경은 시뮬레이션 시각에 기록
+            let status = updated.status();
+            let at = if agent.simulated {
+                updated.state.status_since
+            } else {
+                state.now()
+            };
+            state
+                .kpis
+                .lock()
+                .unwrap()
+                .record_agent_state(&agent.id, status, at);
+            queues.record_agent_state(&agent.id, status, at);
+        }
+        drop(queues);
+
+        updated.name = agent.name;
+        updated.current_call = agent.current_call;
+        updated.call_duration = agent.call_duration;
+        updated.simulated = agent.simulated;
+        match index {
+            Some(index) => agents[index] = updated.clone(),
+            None => agents.push(updated.clone()),
+        }
+        let delta = state.deltas.lock().unwrap().record_agent(&updated, now);
+        (updated, delta)
+    };
+    state.touch();
+    // 히스토리는 실제 시각 기준 (디스크에 남는 시계열)
+    state.sample_history(chrono::Utc::now());
+
+    // 모든 윈도우에 상담원 상태 변경 이벤트 발송 (점유율이 바뀌므로 KPI 도)
+    emit_deltas(app, delta);
+    emit_kpis(app, state);
+    evaluate_alerts(app, state);
 
     println!(
         "👤 [QUEUE] 상담원 상태 업데이트: {} -> {}",
-        agent.name, agent.status
+        updated.name,
+        updated.status()
     );
+    Ok(updated)
+}
+
+/// 시뮬레이션 종료 후 정리. 시뮬레이션 상담원을 제거하고, 시뮬레이션 시각으로 쌓인
+/// KPI / 알림 상태를 버린 뒤 실제 시각으로 돌아간다.
+/// 시뮬레이션 중 기록은 실제 기록과 구분할 수 없으므로 모두 지우고, 남은 상담원의 현재 상태만 다시 기록한다
+pub fn end_simulation<R: Runtime>(app: &AppHandle<R>, state: &QueueState) {
+    state.set_simulated_now(None);
+    let now = chrono::Utc::now();
+
+    let (removed, deltas) = {
+        let mut agents = state.agents.lock().unwrap();
+        let removed: Vec<String> = agents
+            .iter()
+            .filter(|a| a.simulated)
+            .map(|a| a.id.clone())
+            .collect();
+        agents.retain(|a| !a.simulated);
+
+        let mut queues = state.queues.lock().unwrap();
+        if let Err(e) = queues.remove_agents(&removed) {
+            println!("⚠️ [QUEUE] 시뮬레이션 상담원 스킬 삭제 실패: {}", e);
+        }
+        queues.clear_kpis();
+        let mut kpis = state.kpis.lock().unwrap();
+        kpis.clear();
+        for agent in agents.iter() {
+            kpis.record_agent_state(&agent.id, agent.status(), now);
+            queues.record_agent_state(&agent.id, agent.status(), now);
+        }
+        drop((kpis, queues));
+
+        let mut log = state.deltas.lock().unwrap();
+        let deltas: Vec<QueueDelta> = removed
+            .iter()
+            .filter_map(|id| log.record_agent_removed(id, now))
+            .collect();
+        (removed, deltas)
+    };
+    let resolved = state.alerts.lock().unwrap().reset(now);
+    state.touch();
+
+    emit_deltas(app, deltas);
+    for alert in resolved {
+        let _ = app.emit(QUEUE_ALERT_EVENT, &alert);
+    }
+    emit_kpis(app, state);
+    evaluate_alerts(app, state);
+
+    println!(
+        "🧹 [QUEUE] 시뮬레이션 정리: 상담원 {}명 제거, KPI / 알림 초기화",
+        removed.len()
+    );
+}
+
+/// `seq` 이후 빠진 변경분. 보관 범위를 벗어났거나 `seq` 생략 시 전체 스냅샷
+#[tauri::command]
+pub fn get_queue_snapshot_since(
+    state: tauri::State<'_, QueueState>,
+    seq: Option<u64>,
+) -> QueueResync {
+    state.deltas.lock().unwrap().since(seq)
+}
+
+#[tauri::command]
+pub fn get_all_agents(
+    state: tauri::State<'_, QueueState>,
+    queue_id: Option<String>,
+) -> Result<Vec<AgentStatus>, String> {
+    state.agents_for(queue_id.as_deref())
+}
+
+#[tauri::command]
+pub fn get_available_agents(
+    state: tauri::State<'_, QueueState>,
+    queue_id: Option<String>,
+) -> Result<Vec<AgentStatus>, String> {
+    let mut agents = state.agents_for(queue_id.as_deref())?;
+    agents.retain(|agent| agent.status() == AgentState::Available);
+    Ok(agents)
+}
+
+#[tauri::command]
+pub fn get_busy_agents(
+    state: tauri::State<'_, QueueState>,
+    queue_id: Option<String>,
+) -> Result<Vec<AgentStatus>, String> {
+    let mut agents = state.agents_for(queue_id.as_deref())?;
+    agents.retain(|agent| agent.status() == AgentState::Busy);
+    Ok(agents)
+}
+
+// 실시간 통계 계산
+#[tauri::command]
+pub fn get_queue_statistics(
+    state: tauri::State<'_, QueueState>,
+    queue_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let queue_id = queue_id.as_deref();
+    let queue_status = state.status_for(queue_id)?;
+    let agents = state.agents_for(queue_id)?;
+
+    let mut agent_summary = serde_json::Map::new();
+    agent_summary.insert("total".to_string(), agents.len().into());
+    for status in AgentState::ALL {
+        let count = agents.iter().filter(|a| a.status() == status).count();
+        agent_summary.insert(status.as_str().to_string(), count.into());
+    }
+
+    Ok(serde_json::json!({
+        "queue_id": queue_id,
+        "queue_status": queue_status,
+        "agent_summary": agent_summary,
+        "kpis": state.kpi_report_for(queue_id)?,
+        "last_updated": state.last_updated.lock().unwrap().clone()
+    }))
+}
+
+// === KPI ===
+
+/// 콜 이벤트(진입/응답/포기/완료) 기록 후 KPI 갱신 이벤트 발송.
+/// `queue_id` 지정 시 전체 KPI 와 해당 대기열 KPI 에 함께 기록
+#[tauri::command]
+pub fn record_call_event<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    event: CallEvent,
+    queue_id: Option<String>,
+) -> Result<(), String> {
+    let now = state.now();
+    if let Some(id) = &queue_id {
+        state
+            .queues
+            .lock()
+            .unwrap()
+            .kpis_mut(id)?
+            .record_call_event(&event, now);
+    }
+    state.kpis.lock().unwrap().record_call_event(&event, now);
+    state.touch();
+    emit_kpis(&app, &state);
     Ok(())
 }
 
 #[tauri::command]
-pub fn get_all_agents(state: tauri::State<QueueState>) -> Vec<AgentStatus> {
-    state.agents.lock().unwrap().clone()
+pub fn get_queue_kpis(
+    state: tauri::State<'_, QueueState>,
+    queue_id: Option<String>,
+) -> Result<QueueKpiReport, String> {
+    state.kpi_report_for(queue_id.as_deref())
 }
 
+/// 서비스 레벨 기준 시간(초) 변경. `queue_id` 지정 시 해당 대기열만
+#[tauri::command]
+pub fn set_service_level_threshold<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    seconds: u32,
+    queue_id: Option<String>,
+) -> Result<(), String> {
+    if seconds == 0 {
+        return Err("서비스 레벨 기준 시간은 1초 이상이어야 합니다".to_string());
+    }
+    match &queue_id {
+        Some(id) => {
+            state
+                .queues
+                .lock()
+                .unwrap()
+                .kpis_mut(id)?
+                .service_level_threshold_secs = seconds;
+        }
+        None => {
+            state.kpis.lock().unwrap().service_level_threshold_secs = seconds;
+        }
+    }
+    emit_kpis(&app, &state);
+    Ok(())
+}
+
+// === 히스토리 ===
+
+/// `[from, to]` 구간의 대기열 시계열. `resolution` (초) 생략 시 저장 해상도 그대로
 #[tauri::command]
-pub fn get_available_agents(state: tauri::State<QueueState>) -> Vec<AgentStatus> {
-    state
-        .agents
+pub fn get_queue_history(
+    state: tauri::State<'_, QueueState>,
+    from: chrono::DateTime<chrono::Utc>,
+    to: chrono::DateTime<chrono::Utc>,
+    resolution: Option<u32>,
+    queue_id: Option<String>,
+) -> Result<QueueHistorySeries, String> {
+    if from > to {
+        return Err("from 은 to 보다 이전이어야 합니다".to_string());
+    }
+    Ok(state
+        .history
         .lock()
         .unwrap()
+        .query(from, to, resolution, queue_id.as_deref()))
+}
+
+#[tauri::command]
+pub fn get_queue_history_config(state: tauri::State<'_, QueueState>) -> HistoryConfig {
+    state.history.lock().unwrap().config().clone()
+}
+
+#[tauri::command]
+pub fn set_queue_history_config(
+    state: tauri::State<'_, QueueState>,
+    config: HistoryConfig,
+) -> Result<(), String> {
+    let now = chrono::Utc::now();
+    state.history.lock().unwrap().set_config(config, now)?;
+    state.persist_history(now)
+}
+
+// === 알림 규칙 ===
+
+#[tauri::command]
+pub fn get_alert_rules(
+    state: tauri::State<'_, QueueState>,
+    queue_id: Option<String>,
+) -> Vec<AlertRule> {
+    let alerts = state.alerts.lock().unwrap();
+    alerts
+        .rules()
         .iter()
-        .filter(|agent| agent.status == "available")
+        .filter(|rule| queue_id.is_none() || rule.queue_id == queue_id)
         .cloned()
         .collect()
 }
 
+/// 규칙 추가 또는 수정 (id 가 같으면 교체)
 #[tauri::command]
-pub fn get_busy_agents(state: tauri::State<QueueState>) -> Vec<AgentStatus> {
-    state
-        .agents
+pub fn save_alert_rule<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    rule: AlertRule,
+) -> Result<AlertRule, String> {
+    let saved = {
+        let queues = state.queues.lock().unwrap();
+        state.alerts.lock().unwrap().upsert_rule(rule, &queues)?
+    };
+    println!("🚨 [ALERT] 규칙 저장: {} ({})", saved.name, saved.id);
+    evaluate_alerts(&app, &state);
+    Ok(saved)
+}
+
+#[tauri::command]
+pub fn delete_alert_rule<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    rule_id: String,
+) -> Result<bool, String> {
+    let removed = state.alerts.lock().unwrap().remove_rule(&rule_id)?;
+    // 삭제된 규칙의 활성 알림은 해소 처리
+    evaluate_alerts(&app, &state);
+    Ok(removed)
+}
+
+#[tauri::command]
+pub fn get_active_alerts(
+    state: tauri::State<'_, QueueState>,
+    queue_id: Option<String>,
+) -> Vec<QueueAlert> {
+    let mut alerts = state.alerts.lock().unwrap().active();
+    if queue_id.is_some() {
+        alerts.retain(|alert| alert.queue_id == queue_id);
+    }
+    alerts
+}
+
+#[tauri::command]
+pub fn get_resolved_alerts(
+    state: tauri::State<'_, QueueState>,
+    queue_id: Option<String>,
+) -> Vec<QueueAlert> {
+    let mut alerts = state.alerts.lock().unwrap().resolved();
+    if queue_id.is_some() {
+        alerts.retain(|alert| alert.queue_id == queue_id);
+    }
+    alerts
+}
+
+// === 대기열(스킬 그룹) 관리 ===
+
+/// 등록된 대기열별 상태 / 상담원 수 / KPI
+#[tauri::command]
+pub fn list_queues(state: tauri::State<'_, QueueState>) -> Vec<QueueSummary> {
+    state.queue_summaries()
+}
+
+/// 대기열 추가 또는 수정 (id 가 같으면 교체)
+#[tauri::command]
+pub fn save_queue<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    queue: QueueDefinition,
+) -> Result<QueueDefinition, String> {
+    let saved = state.queues.lock().unwrap().upsert_queue(queue)?;
+    println!("🗂️ [QUEUE] 대기열 저장: {} ({})", saved.name, saved.id);
+    let delta = state
+        .deltas
         .lock()
         .unwrap()
-        .iter()
-        .filter(|agent| agent.status == "busy")
-        .cloned()
-        .collect()
+        .record_queue_definitions(chrono::Utc::now());
+    emit_deltas(&app, [delta]);
+    Ok(saved)
 }
 
-// 실시간 통계 계산
 #[tauri::command]
-pub fn get_queue_statistics(state: tauri::State<QueueState>) -> serde_json::Value {
-    let queue_status = state.queue_status.lock().unwrap().clone();
-    let agents = state.agents.lock().unwrap();
+pub fn delete_queue<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    queue_id: String,
+) -> Result<bool, String> {
+    let mut queues = state.queues.lock().unwrap();
+    let removed = queues.remove_queue(&queue_id)?;
+    if removed {
+        // 전체 상태는 남은 대기열 합계로 다시 계산
+        let now = chrono::Utc::now();
+        let total = queues.aggregate_status();
+        *state.queue_status.lock().unwrap() = total.clone();
+        let deltas = {
+            let mut log = state.deltas.lock().unwrap();
+            [
+                log.record_queue_removed(&queue_id, now),
+                log.record_status(None, &total, now),
+            ]
+        };
+        drop(queues);
+        println!("🗂️ [QUEUE] 대기열 삭제: {}", queue_id);
 
-    let available_count = agents.iter().filter(|a| a.status == "available").count();
-    let busy_count = agents.iter().filter(|a| a.status == "busy").count();
-    let break_count = agents.iter().filter(|a| a.status == "break").count();
-    let offline_count = agents.iter().filter(|a| a.status == "offline").count();
+        emit_deltas(&app, deltas.into_iter().flatten());
+        evaluate_alerts(&app, &state);
+    }
+    Ok(removed)
+}
 
-    serde_json::json!({
-        "queue_status": queue_status,
-        "agent_summary": {
-            "total": agents.len(),
-            "available": available_count,
-            "busy": busy_count,
-            "break": break_count,
-            "offline": offline_count
-        },
-        "last_updated": state.last_updated.lock().unwrap().clone()
-    })
+/// 상담원 스킬 배정 조회. `agent_id` 생략 시 전체
+#[tauri::command]
+pub fn get_agent_skills(
+    state: tauri::State<'_, QueueState>,
+    agent_id: Option<String>,
+) -> std::collections::BTreeMap<String, Vec<AgentSkill>> {
+    let queues = state.queues.lock().unwrap();
+    let mut skills = queues.all_agent_skills().clone();
+    if let Some(agent_id) = agent_id {
+        skills.retain(|id, _| *id == agent_id);
+    }
+    skills
+}
+
+/// 상담원 스킬 배정 교체 (빈 목록이면 배정 해제)
+#[tauri::command]
+pub fn set_agent_skills<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    agent_id: String,
+    skills: Vec<AgentSkill>,
+) -> Result<Vec<AgentSkill>, String> {
+    // 이미 통화 중인 상담원이면 새로 맡은 대기열 점유율에 지금 상태부터 반영
+    let saved = {
+        let agents = state.agents.lock().unwrap();
+        let current = agents.iter().find(|a| a.id == agent_id).map(|a| a.status());
+        state
+            .queues
+            .lock()
+            .unwrap()
+            .set_agent_skills(&agent_id, skills, current, state.now())?
+    };
+    println!("🎯 [QUEUE] 스킬 배정: {} -> {}개", agent_id, saved.len());
+    let delta = state
+        .deltas
+        .lock()
+        .unwrap()
+        .record_queue_definitions(chrono::Utc::now());
+    emit_deltas(&app, [delta]);
+    Ok(saved)
 }
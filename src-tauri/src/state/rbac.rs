@@ -0,0 +1,321 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\rbac.rs
+//! 역할 기반 권한 모델. 역할은 상위 역할의 권한을 상속하고,
+//! 권한 문자열은 `:` 로 구분된 네임스페이스와 `*` 와일드카드를 지원한다.
+//! 예) `queue:*` 는 `queue:view`, `queue:agents:assign` 모두 허용
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+pub const RBAC_POLICY_FILE_NAME: &str = "rbac_policy.json";
+
+/// 역할 하나의 정의
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoleDefinition {
+    /// 화면 표시용 이름
+    pub display_name: String,
+    /// 권한을 물려받을 역할 id
+    #[serde(default)]
+    pub inherits: Vec<String>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    /// `User.role` 에서 이 역할로 인식할 다른 이름 (대소문자 무시)
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// 로그인 시 2단계 인증(TOTP)을 요구할지. 이 역할을 물려받는 역할에도 적용된다
+    #[serde(default)]
+    pub require_second_factor: bool,
+}
+
+/// 역할 정의 모음 (key = 역할 id)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RbacPolicy {
+    pub roles: BTreeMap<String, RoleDefinition>,
+    /// 오프라인 로그인 세션에서 허용할 권한. 역할 권한 중 여기에 해당하는 것만 남는다
+    #[serde(default = "default_offline_permissions")]
+    pub offline_permissions: Vec<String>,
+}
+
+/// 서버 없이도 쓸 수 있는 화면 (공유 업무 / 개인 일정 조회, 본인 상태)
+fn default_offline_permissions() -> Vec<String> {
+    vec![
+        "tasks:shared:view".to_string(),
+        "agents:self:status".to_string(),
+    ]
+}
+
+fn role(
+    display_name: &str,
+    inherits: &[&str],
+    permissions: &[&str],
+    aliases: &[&str],
+) -> RoleDefinition {
+    let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+    RoleDefinition {
+        display_name: display_name.to_string(),
+        inherits: strings(inherits),
+        permissions: strings(permissions),
+        aliases: strings(aliases),
+        require_second_factor: false,
+    }
+}
+
+impl Default for RbacPolicy {
+    /// 정책 파일이 없을 때 쓰는 기본 역할: 상담원 < 팀장 < 슈퍼바이저 < 관리자
+    fn default() -> Self {
+        let roles = [
+            (
+                "agent",
+                role(
+                    "상담원",
+                    &[],
+                    &[
+                        "calls:inbound:answer",
+                        "calls:outbound:dial",
+                        "queue:view",
+                        "agents:self:status",
+                        "chatbot:use",
+                        "chat:company",
+                        "tasks:shared:view",
+                    ],
+                    &["상담원"],
+                ),
+            ),
+            (
+                "team_lead",
+                role(
+                    "팀장",
+                    &["agent"],
+                    &[
+                        "agents:view",
+                        "agents:impersonate",
+                        "statistics:view",
+                        "calls:transfer",
+                    ],
+                    &["팀장", "team lead"],
+                ),
+            ),
+            (
+                "supervisor",
+                RoleDefinition {
+                    require_second_factor: true,
+                    ..role(
+                        "슈퍼바이저",
+                        &["team_lead"],
+                        &[
+                            "queue:*",
+                            "agents:*",
+                            "statistics:*",
+                            "calls:*",
+                            "audit:view",
+                        ],
+                        &["슈퍼바이저", "매니저", "manager"],
+                    )
+                },
+            ),
+            (
+                "admin",
+                role(
+                    "관리자",
+                    &["supervisor"],
+                    &["*"],
+                    &["관리자", "administrator"],
+                ),
+            ),
+        ];
+        Self {
+            roles: roles
+                .into_iter()
+                .map(|(id, definition)| (id.to_string(), definition))
+                .collect(),
+            offline_permissions: default_offline_permissions(),
+        }
+    }
+}
+
+impl RbacPolicy {
+    /// 정책 파일 로드. 없거나 깨졌거나 검증에 실패하면 기본 정책
+    pub fn load(path: PathBuf) -> Self {
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(_) => return Self::default(),
+        };
+        let policy = serde_json::from_slice::<Self>(&bytes)
+            .map_err(|e| e.to_string())
+            .and_then(|policy| policy.validate().map(|_| policy));
+        match policy {
+            Ok(policy) => {
+                println!("🛡️ [RBAC] 권한 정책 로드: 역할 {}개", policy.roles.len());
+                policy
+            }
+            Err(e) => {
+                println!("⚠️ [RBAC] 권한 정책 오류, 기본값 사용: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// 상속 대상이 존재하는지, 순환 상속이 없는지 확인
+    pub fn validate(&self) -> Result<(), String> {
+        for (id, definition) in &self.roles {
+            for parent in &definition.inherits {
+                if !self.roles.contains_key(parent) {
+                    return Err(format!(
+                        "역할 '{}' 이 없는 역할 '{}' 을 상속합니다",
+                        id, parent
+                    ));
+                }
+            }
+            for permission in &definition.permissions {
+                validate_permission(permission)
+                    .map_err(|e| format!("역할 '{}' 의 권한 '{}': {}", id, permission, e))?;
+            }
+        }
+        for permission in &self.offline_permissions {
+            validate_permission(permission)
+                .map_err(|e| format!("오프라인 권한 '{}': {}", permission, e))?;
+        }
+        for id in self.roles.keys() {
+            let mut path = Vec::new();
+            self.check_cycle(id, &mut path)?;
+        }
+        Ok(())
+    }
+
+    fn check_cycle<'a>(&'a self, id: &'a str, path: &mut Vec<&'a str>) -> Result<(), String> {
+        if path.contains(&id) {
+            path.push(id);
+            return Err(format!("순환 상속: {}", path.join(" → ")));
+        }
+        path.push(id);
+        if let Some(definition) = self.roles.get(id) {
+            for parent in &definition.inherits {
+                self.check_cycle(parent, path)?;
+            }
+        }
+        path.pop();
+        Ok(())
+    }
+
+    /// 역할 id 또는 별칭으로 역할 찾기. Spring 스타일 `ROLE_` 접두어는 무시
+    pub fn resolve_role(&self, name: &str) -> Option<&str> {
+        let name = name.trim();
+        let name = name.strip_prefix("ROLE_").unwrap_or(name);
+        self.roles
+            .iter()
+            .find(|(id, definition)| {
+                id.eq_ignore_ascii_case(name)
+                    || definition
+                        .aliases
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(name))
+            })
+            .map(|(id, _)| id.as_str())
+    }
+
+    /// 역할들과 상속된 상위 역할까지 모두 (id 기준)
+    pub fn expand_roles<'a>(
+        &'a self,
+        roles: impl IntoIterator<Item = &'a str>,
+    ) -> BTreeSet<&'a str> {
+        let mut expanded = BTreeSet::new();
+        let mut pending: Vec<&str> = roles.into_iter().collect();
+        while let Some(id) = pending.pop() {
+            if let Some((id, definition)) = self.roles.get_key_value(id) {
+                if expanded.insert(id.as_str()) {
+                    pending.extend(definition.inherits.iter().map(String::as_str));
+                }
+            }
+        }
+        expanded
+    }
+
+    /// 역할 이름(별칭 포함)들에서 유효 권한 집합 계산. `extra` 는 사용자 개별 권한
+    pub fn effective_permissions<'a>(
+        &self,
+        role_names: impl IntoIterator<Item = &'a str>,
+        extra: &[String],
+    ) -> BTreeSet<String> {
+        let role_ids: Vec<&str> = role_names
+            .into_iter()
+            .filter_map(|name| self.resolve_role(name))
+            .collect();
+        self.expand_roles(role_ids)
+            .into_iter()
+            .filter_map(|id| self.roles.get(id))
+            .flat_map(|definition| definition.permissions.iter().cloned())
+            .chain(extra.iter().cloned())
+            .collect()
+    }
+
+    /// 역할 중 하나라도 (상속 포함) 2단계 인증을 요구하는지
+    pub fn requires_second_factor<'a>(
+        &self,
+        role_names: impl IntoIterator<Item = &'a str>,
+    ) -> bool {
+        let role_ids: Vec<&str> = role_names
+            .into_iter()
+            .filter_map(|name| self.resolve_role(name))
+            .collect();
+        self.expand_roles(role_ids)
+            .into_iter()
+            .filter_map(|id| self.roles.get(id))
+            .any(|definition| definition.require_second_factor)
+    }
+
+    /// 오프라인 세션 권한: 원래 권한 중 허용 목록에 해당하는 것 + 허용 목록 중 원래 권한으로 허용되는 것
+    pub fn restrict_to_offline(&self, permissions: &BTreeSet<String>) -> BTreeSet<String> {
+        let allowed: BTreeSet<String> = self.offline_permissions.iter().cloned().collect();
+        permissions
+            .iter()
+            .filter(|permission| is_granted(&allowed, permission))
+            .chain(
+                allowed
+                    .iter()
+                    .filter(|permission| is_granted(permissions, permission)),
+            )
+            .cloned()
+            .collect()
+    }
+}
+
+pub(crate) fn validate_permission(permission: &str) -> Result<(), String> {
+    if permission
+        .split(':')
+        .any(|segment| segment.trim().is_empty())
+    {
+        return Err("빈 네임스페이스 구간이 있습니다".to_string());
+    }
+    if permission
+        .split(':')
+        .any(|segment| segment.contains('*') && segment != "*")
+    {
+        return Err("`*` 는 구간 전체로만 쓸 수 있습니다".to_string());
+    }
+    Ok(())
+}
+
+/// `granted` 권한이 `required` 를 허용하는지.
+/// 중간의 `*` 는 한 구간, 마지막 `*` 는 나머지 모든 하위 구간과 일치한다
+pub fn permission_matches(granted: &str, required: &str) -> bool {
+    let granted: Vec<&str> = granted.split(':').collect();
+    let required: Vec<&str> = required.split(':').collect();
+
+    for (index, segment) in granted.iter().enumerate() {
+        let is_last = index + 1 == granted.len();
+        match required.get(index) {
+            Some(_) if *segment == "*" && is_last => return true,
+            Some(_) if *segment == "*" => continue,
+            Some(required_segment) if segment == required_segment => continue,
+            _ => return false,
+        }
+    }
+    granted.len() == required.len()
+}
+
+/// 권한 집합 중 하나라도 `required` 를 허용하면 true
+pub fn is_granted<'a>(permissions: impl IntoIterator<Item = &'a String>, required: &str) -> bool {
+    permissions
+        .into_iter()
+        .any(|granted| permission_matches(granted, required))
+}
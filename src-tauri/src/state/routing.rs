@@ -0,0 +1,883 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\routing.rs
+//! 로컬 ACD(자동 호 분배) 라우팅 엔진
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use super::agent_state::AgentState;
+use super::queue_kpi::CallEvent;
+use super::queue_registry::QueueRegistry;
+use super::queue_state::{self, AgentStatus, AgentStatusUpdate, QueueState};
+
+/// payload: `RoutingOffer` (상담원에게 콜 제안)
+pub const ROUTING_OFFER_EVENT: &str = "routing-offer";
+/// payload: `RoutingOffer` (거절 / 링 타임아웃으로 제안 철회)
+pub const ROUTING_OFFER_WITHDRAWN_EVENT: &str = "routing-offer-withdrawn";
+
+/// 라우팅 로그 보관 개수
+const ROUTING_LOG_LIMIT: usize = 500;
+/// 링 타임아웃 / 재분배 확인 주기 (초)
+const ROUTING_TICK_SECS: u64 = 1;
+
+/// 분배 전략
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingStrategy {
+    /// 가장 오래 대기한 상담원
+    #[default]
+    LongestIdle,
+    /// 상담원 순번대로
+    RoundRobin,
+    /// 숙련도 높은 순 (같으면 오래 대기한 순)
+    SkillBased,
+    /// 재문의 고객은 이전 응대 상담원 우선
+    StickyAgent,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutingConfig {
+    pub strategy: RoutingStrategy,
+    /// 상담원이 응답하지 않으면 다음 후보로 넘기는 시간 (초)
+    pub ring_timeout_secs: u32,
+}
+
+impl Default for RoutingConfig {
+    fn default() -> Self {
+        Self {
+            strategy: RoutingStrategy::default(),
+            ring_timeout_secs: 15,
+        }
+    }
+}
+
+impl RoutingConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.ring_timeout_secs == 0 {
+            return Err("링 타임아웃은 1초 이상이어야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 분배 대기 중인 콜
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// 비워서 넣으면 자동 생성
+    #[serde(default)]
+    pub id: String,
+    /// 지정 시 해당 대기열 스킬을 가진 상담원에게만 분배
+    #[serde(default)]
+    pub queue_id: Option<String>,
+    /// 고객 식별자 (sticky agent 용)
+    #[serde(default)]
+    pub customer_id: Option<String>,
+    #[serde(default)]
+    pub enqueued_at: Option<DateTime<Utc>>,
+}
+
+/// 상담원 한 명에게 콜을 제안한 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutingOffer {
+    pub interaction: Interaction,
+    pub agent_id: String,
+    pub offered_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// 이 콜의 몇 번째 제안인지 (1부터)
+    pub attempt: u32,
+}
+
+/// 후보 상담원별 순위와 근거
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CandidateScore {
+    pub agent_id: String,
+    pub rank: u32,
+    pub idle_secs: i64,
+    pub proficiency: Option<u8>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingDecision {
+    Enqueued,
+    Offered,
+    Accepted,
+    Rejected,
+    TimedOut,
+    /// 모든 후보가 거절/무응답 → 후보 목록 초기화
+    CandidatesExhausted,
+    NoCandidate,
+    Abandoned,
+    /// 상담원 호출 / 수락 처리 실패 → 그 상담원을 빼고 대기열로 복귀
+    Requeued,
+}
+
+/// 라우팅 결정 로그 한 줄
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutingLogEntry {
+    pub seq: u64,
+    pub at: DateTime<Utc>,
+    pub decision: RoutingDecision,
+    pub interaction_id: String,
+    pub queue_id: Option<String>,
+    pub agent_id: Option<String>,
+    pub strategy: RoutingStrategy,
+    pub reason: String,
+    /// 제안 시점의 후보 순위 (Offered 에서만)
+    pub candidates: Vec<CandidateScore>,
+}
+
+/// `tick` 결과. 호출자가 상담원 상태 전이 / 이벤트 발송을 처리한다
+#[derive(Debug, Default, PartialEq)]
+pub struct RoutingOutcome {
+    pub offered: Vec<RoutingOffer>,
+    pub expired: Vec<RoutingOffer>,
+}
+
+/// `get_routing_queue` 응답
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutingQueueSnapshot {
+    pub waiting: Vec<Interaction>,
+    pub offers: Vec<RoutingOffer>,
+}
+
+#[derive(Debug, Clone)]
+struct Pending {
+    interaction: Interaction,
+    /// 이미 거절/무응답한 상담원
+    tried: Vec<String>,
+    attempts: u32,
+    /// NoCandidate 로그를 이미 남겼는지 (매 tick 마다 남기지 않도록)
+    waiting_logged: bool,
+}
+
+/// 대기 콜을 상담원에게 분배하는 엔진. 시각은 모두 인자로 받는다.
+#[derive(Debug, Default)]
+pub struct RoutingEngine {
+    config: RoutingConfig,
+    queue: VecDeque<Pending>,
+    offers: Vec<(RoutingOffer, Pending)>,
+    /// 대기열별 마지막 배정 상담원 (round robin)
+    last_assigned: HashMap<String, String>,
+    /// 고객별 마지막 응대 상담원 (sticky agent)
+    last_agent_for_customer: HashMap<String, String>,
+    log: VecDeque<RoutingLogEntry>,
+    next_seq: u64,
+}
+
+impl RoutingEngine {
+    pub fn new(config: RoutingConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    pub fn config(&self) -> &RoutingConfig {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: RoutingConfig) -> Result<(), String> {
+        config.validate()?;
+        self.config = config;
+        Ok(())
+    }
+
+    /// 최근 로그 (오래된 순). `limit` 지정 시 마지막 N개
+    pub fn log(&self, limit: Option<usize>) -> Vec<RoutingLogEntry> {
+        let skip = limit.map_or(0, |limit| self.log.len().saturating_sub(limit));
+        self.log.iter().skip(skip).cloned().collect()
+    }
+
+    pub fn snapshot(&self) -> RoutingQueueSnapshot {
+        RoutingQueueSnapshot {
+            waiting: self.queue.iter().map(|p| p.interaction.clone()).collect(),
+            offers: self.offers.iter().map(|(o, _)| o.clone()).collect(),
+        }
+    }
+
+    fn record(
+        &mut self,
+        at: DateTime<Utc>,
+        decision: RoutingDecision,
+        interaction: &Interaction,
+        agent_id: Option<&str>,
+        reason: String,
+        candidates: Vec<CandidateScore>,
+    ) {
+        self.next_seq += 1;
+        self.log.push_back(RoutingLogEntry {
+            seq: self.next_seq,
+            at,
+            decision,
+            interaction_id: interaction.id.clone(),
+            queue_id: interaction.queue_id.clone(),
+            agent_id: agent_id.map(str::to_string),
+            strategy: self.config.strategy,
+            reason,
+            candidates,
+        });
+        while self.log.len() > ROUTING_LOG_LIMIT {
+            self.log.pop_front();
+        }
+    }
+
+    fn contains(&self, interaction_id: &str) -> bool {
+        self.queue
+            .iter()
+            .any(|p| p.interaction.id == interaction_id)
+            || self
+                .offers
+                .iter()
+                .any(|(o, _)| o.interaction.id == interaction_id)
+    }
+
+    /// 대기열에 콜 추가 (id 가 비어 있으면 생성)
+    pub fn enqueue(
+        &mut self,
+        mut interaction: Interaction,
+        now: DateTime<Utc>,
+    ) -> Result<Interaction, String> {
+        if interaction.id.trim().is_empty() {
+            interaction.id = format!("interaction-{}-{}", now.timestamp_millis(), self.next_seq);
+        }
+        if self.contains(&interaction.id) {
+            return Err(format!("이미 분배 중인 콜입니다: {}", interaction.id));
+        }
+        interaction.enqueued_at.get_or_insert(now);
+
+        self.record(
+            now,
+            RoutingDecision::Enqueued,
+            &interaction,
+            None,
+            "대기열 진입".to_string(),
+            vec![],
+        );
+        self.requeue(Pending {
+            interaction: interaction.clone(),
+            tried: vec![],
+            attempts: 0,
+            waiting_logged: false,
+        });
+        Ok(interaction)
+    }
+
+    /// 진입 시각 순서를 유지하며 대기열에 다시 넣는다
+    fn requeue(&mut self, pending: Pending) {
+        let at = pending.interaction.enqueued_at;
+        let index = self
+            .queue
+            .iter()
+            .position(|p| p.interaction.enqueued_at > at)
+            .unwrap_or(self.queue.len());
+        self.queue.insert(index, pending);
+    }
+
+    fn take_offer(
+        &mut self,
+        interaction_id: &str,
+        agent_id: &str,
+    ) -> Result<(RoutingOffer, Pending), String> {
+        let index = self
+            .offers
+            .iter()
+            .position(|(o, _)| o.interaction.id == interaction_id && o.agent_id == agent_id)
+            .ok_or_else(|| {
+                format!(
+                    "상담원 {} 에게 제안된 콜 {} 이 없습니다",
+                    agent_id, interaction_id
+                )
+            })?;
+        Ok(self.offers.remove(index))
+    }
+
+    /// 상담원이 제안을 수락
+    pub fn accept(
+        &mut self,
+        interaction_id: &str,
+        agent_id: &str,
+        now: DateTime<Utc>,
+    ) -> Result<RoutingOffer, String> {
+        let (offer, _) = self.take_offer(interaction_id, agent_id)?;
+        let interaction = &offer.interaction;
+
+        self.last_assigned
+            .insert(queue_key(interaction), agent_id.to_string());
+        if let Some(customer) = &interaction.customer_id {
+            self.last_agent_for_customer
+                .insert(customer.clone(), agent_id.to_string());
+        }
+        let waited = interaction
+            .enqueued_at
+            .map_or(0, |at| (now - at).num_seconds());
+        self.record(
+            now,
+            RoutingDecision::Accepted,
+            interaction,
+            Some(agent_id),
+            format!("{}번째 제안 수락 (대기 {}초)", offer.attempt, waited),
+            vec![],
+        );
+        Ok(offer)
+    }
+
+    /// 상담원이 제안을 거절 → 다음 후보로
+    pub fn reject(
+        &mut self,
+        interaction_id: &str,
+        agent_id: &str,
+        now: DateTime<Utc>,
+    ) -> Result<RoutingOffer, String> {
+        let (offer, mut pending) = self.take_offer(interaction_id, agent_id)?;
+        pending.tried.push(agent_id.to_string());
+        self.record(
+            now,
+            RoutingDecision::Rejected,
+            &offer.interaction,
+            Some(agent_id),
+            "상담원 거절, 다음 후보로 재분배".to_string(),
+            vec![],
+        );
+        self.requeue(pending);
+        Ok(offer)
+    }
+
+    /// 제안을 철회하고 콜을 대기열로 되돌린다 (상담원을 호출 상태로 바꾸지 못한 경우)
+    pub fn cancel_offer(
+        &mut self,
+        interaction_id: &str,
+        agent_id: &str,
+        now: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RoutingOffer, String> {
+        let (offer, pending) = self.take_offer(interaction_id, agent_id)?;
+        self.restore(&offer, pending, now, reason);
+        Ok(offer)
+    }
+
+    /// 수락했지만 상담원을 통화 상태로 바꾸지 못한 콜을 대기열로 되돌린다
+    pub fn requeue_accepted(&mut self, offer: &RoutingOffer, now: DateTime<Utc>, reason: String) {
+        let pending = Pending {
+            interaction: offer.interaction.clone(),
+            tried: vec![],
+            attempts: offer.attempt,
+            waiting_logged: false,
+        };
+        self.restore(offer, pending, now, reason);
+    }
+
+    /// 제안받았던 상담원을 후보에서 빼고 진입 시각 자리로 (대기열 앞쪽) 다시 넣는다
+    fn restore(
+        &mut self,
+        offer: &RoutingOffer,
+        mut pending: Pending,
+        now: DateTime<Utc>,
+        reason: String,
+    ) {
+        pending.tried.push(offer.agent_id.clone());
+        self.record(
+            now,
+            RoutingDecision::Requeued,
+            &offer.interaction,
+            Some(&offer.agent_id),
+            reason,
+            vec![],
+        );
+        self.requeue(pending);
+    }
+
+    /// 고객이 끊음. 대기 중이면 제거, 제안 중이면 제안도 철회
+    pub fn abandon(
+        &mut self,
+        interaction_id: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(Interaction, Option<RoutingOffer>), String> {
+        let (interaction, offer) = if let Some(index) = self
+            .queue
+            .iter()
+            .position(|p| p.interaction.id == interaction_id)
+        {
+            let pending = self.queue.remove(index).expect("index from position");
+            (pending.interaction, None)
+        } else if let Some(index) = self
+            .offers
+            .iter()
+            .position(|(o, _)| o.interaction.id == interaction_id)
+        {
+            let (offer, pending) = self.offers.remove(index);
+            (pending.interaction, Some(offer))
+        } else {
+            return Err(format!("분배 중인 콜이 아닙니다: {}", interaction_id));
+        };
+
+        self.record(
+            now,
+            RoutingDecision::Abandoned,
+            &interaction,
+            offer.as_ref().map(|o| o.agent_id.as_str()),
+            "고객 포기".to_string(),
+            vec![],
+        );
+        Ok((interaction, offer))
+    }
+
+    /// 링 타임아웃 처리 후 대기 콜을 순서대로 분배
+    pub fn tick(
+        &mut self,
+        now: DateTime<Utc>,
+        agents: &[AgentStatus],
+        registry: &QueueRegistry,
+    ) -> RoutingOutcome {
+        let mut outcome = RoutingOutcome::default();
+
+        // 1. 링 타임아웃 → 다음 후보로
+        let (expired, active): (Vec<_>, Vec<_>) = std::mem::take(&mut self.offers)
+            .into_iter()
+            .partition(|(o, _)| o.expires_at <= now);
+        self.offers = active;
+        for (offer, mut pending) in expired {
+            pending.tried.push(offer.agent_id.clone());
+            self.record(
+                now,
+                RoutingDecision::TimedOut,
+                &offer.interaction,
+                Some(&offer.agent_id),
+                format!(
+                    "{}초 동안 응답 없음, 다음 후보로 재분배",
+                    self.config.ring_timeout_secs
+                ),
+                vec![],
+            );
+            self.requeue(pending);
+            outcome.expired.push(offer);
+        }
+
+        // 2. 대기 콜 분배 (먼저 들어온 콜부터)
+        let mut reserved: Vec<String> = self
+            .offers
+            .iter()
+            .map(|(o, _)| o.agent_id.clone())
+            .collect();
+        // 타임아웃된 상담원은 아직 ringing 상태이므로 이번 tick 에서는 제외
+        reserved.extend(outcome.expired.iter().map(|o| o.agent_id.clone()));
+
+        let mut still_waiting = VecDeque::new();
+        while let Some(mut pending) = self.queue.pop_front() {
+            let eligible: Vec<&AgentStatus> = agents
+                .iter()
+                .filter(|a| a.status() == AgentState::Available && !a.simulated)
+                .filter(|a| !reserved.contains(&a.id))
+                .filter(|a| {
+                    pending
+                        .interaction
+                        .queue_id
+                        .as_deref()
+                        .is_none_or(|queue_id| registry.serves(&a.id, queue_id))
+                })
+                .collect();
+
+            let mut untried: Vec<&AgentStatus> = eligible
+                .iter()
+                .filter(|a| !pending.tried.contains(&a.id))
+                .copied()
+                .collect();
+            if untried.is_empty() && !eligible.is_empty() {
+                self.record(
+                    now,
+                    RoutingDecision::CandidatesExhausted,
+                    &pending.interaction,
+                    None,
+                    format!(
+                        "후보 {}명 모두 거절/무응답, 처음부터 다시 분배",
+                        pending.tried.len()
+                    ),
+                    vec![],
+                );
+                pending.tried.clear();
+                untried = eligible;
+            }
+
+            let ranked = self.rank(&pending.interaction, &untried, registry, now);
+            let Some(best) = ranked.first().cloned() else {
+                if !pending.waiting_logged {
+                    pending.waiting_logged = true;
+                    self.record(
+                        now,
+                        RoutingDecision::NoCandidate,
+                        &pending.interaction,
+                        None,
+                        "응대 가능한 상담원이 없어 대기".to_string(),
+                        vec![],
+                    );
+                }
+                still_waiting.push_back(pending);
+                continue;
+            };
+
+            pending.attempts += 1;
+            pending.waiting_logged = false;
+            let offer = RoutingOffer {
+                interaction: pending.interaction.clone(),
+                agent_id: best.agent_id.clone(),
+                offered_at: now,
+                expires_at: now + Duration::seconds(self.config.ring_timeout_secs as i64),
+                attempt: pending.attempts,
+            };
+            self.record(
+                now,
+                RoutingDecision::Offered,
+                &pending.interaction,
+                Some(&best.agent_id),
+                format!("{}위 후보: {}", best.rank, best.reason),
+                ranked,
+            );
+            reserved.push(best.agent_id);
+            outcome.offered.push(offer.clone());
+            self.offers.push((offer, pending));
+        }
+        self.queue = still_waiting;
+
+        outcome
+    }
+
+    /// 전략에 따라 후보를 우선순위 순으로 정렬하고 근거를 붙인다
+    fn rank(
+        &self,
+        interaction: &Interaction,
+        candidates: &[&AgentStatus],
+        registry: &QueueRegistry,
+        now: DateTime<Utc>,
+    ) -> Vec<CandidateScore> {
+        let mut scores: Vec<CandidateScore> = candidates
+            .iter()
+            .map(|a| CandidateScore {
+                agent_id: a.id.clone(),
+                rank: 0,
+                idle_secs: a.state.seconds_in_state(now),
+                proficiency: interaction
+                    .queue_id
+                    .as_deref()
+                    .and_then(|queue_id| registry.proficiency(&a.id, queue_id)),
+                reason: String::new(),
+            })
+            .collect();
+
+        // 기본 순서: 오래 대기한 순, 같으면 id 순
+        scores.sort_by(|a, b| {
+            b.idle_secs
+                .cmp(&a.idle_secs)
+                .then_with(|| a.agent_id.cmp(&b.agent_id))
+        });
+
+        match self.config.strategy {
+            RoutingStrategy::LongestIdle => {
+                for s in &mut scores {
+                    s.reason = format!("대기 {}초", s.idle_secs);
+                }
+            }
+            RoutingStrategy::RoundRobin => {
+                scores.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+                let last = self.last_assigned.get(&queue_key(interaction));
+                if let Some(last) = last {
+                    // 마지막 배정 상담원 다음 순번부터
+                    let start = scores.iter().position(|s| s.agent_id > *last).unwrap_or(0);
+                    scores.rotate_left(start);
+                }
+                for s in &mut scores {
+                    s.reason = match last {
+                        Some(last) => format!("순번 (직전 배정: {})", last),
+                        None => "순번 (첫 배정)".to_string(),
+                    };
+                }
+            }
+            RoutingStrategy::SkillBased => {
+                // 안정 정렬이므로 같은 숙련도에서는 대기 시간 순서가 유지된다
+                scores.sort_by_key(|s| std::cmp::Reverse(s.proficiency));
+                for s in &mut scores {
+                    s.reason = match s.proficiency {
+                        Some(p) => format!("숙련도 {} · 대기 {}초", p, s.idle_secs),
+                        None => format!("스킬 정보 없음 · 대기 {}초", s.idle_secs),
+                    };
+                }
+            }
+            RoutingStrategy::StickyAgent => {
+                let previous = interaction
+                    .customer_id
+                    .as_ref()
+                    .and_then(|c| self.last_agent_for_customer.get(c));
+                if let Some(index) =
+                    previous.and_then(|p| scores.iter().position(|s| s.agent_id == *p))
+                {
+                    let preferred = scores.remove(index);
+                    scores.insert(0, preferred);
+                }
+                for s in &mut scores {
+                    s.reason = if previous == Some(&s.agent_id) {
+                        "이전 응대 상담원".to_string()
+                    } else if previous.is_some() {
+                        format!("이전 상담원 부재 · 대기 {}초", s.idle_secs)
+                    } else {
+                        format!("신규 고객 · 대기 {}초", s.idle_secs)
+                    };
+                }
+            }
+        }
+
+        for (index, s) in scores.iter_mut().enumerate() {
+            s.rank = index as u32 + 1;
+        }
+        scores
+    }
+}
+
+fn queue_key(interaction: &Interaction) -> String {
+    interaction.queue_id.clone().unwrap_or_default()
+}
+
+// === Tauri 연동 ===
+
+/// 라우팅 결과에 맞춰 상담원 상태를 바꾼다 (일반 상태 업데이트와 같은 경로)
+fn transition_agent<R: Runtime>(
+    app: &AppHandle<R>,
+    agent_id: &str,
+    status: AgentState,
+    current_call: Option<String>,
+) -> Result<(), String> {
+    let state = app.state::<QueueState>();
+    let name = state
+        .agents
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|a| a.id == agent_id)
+        .map(|a| a.name.clone())
+        .ok_or_else(|| format!("알 수 없는 상담원입니다: {}", agent_id))?;
+
+    queue_state::update_agent_status(
+        app.clone(),
+        state,
+        AgentStatusUpdate {
+            id: agent_id.to_string(),
+            name,
+            status,
+            break_reason: None,
+            current_call,
+            call_duration: None,
+            skills: None,
+            simulated: false,
+        },
+    )
+    .map(|_| ())
+    .map_err(|e| e.to_string())
+}
+
+/// 타임아웃 처리 + 분배 1회 실행 후 상담원 상태 / 이벤트 반영
+fn dispatch<R: Runtime>(app: &AppHandle<R>) {
+    let state = app.state::<QueueState>();
+    let now = Utc::now();
+    let agents = state.agents.lock().unwrap().clone();
+    let outcome = {
+        let queues = state.queues.lock().unwrap();
+        state.routing.lock().unwrap().tick(now, &agents, &queues)
+    };
+
+    for offer in outcome.expired {
+        if let Err(e) = transition_agent(app, &offer.agent_id, AgentState::Available, None) {
+            println!("⚠️ [ROUTING] 상담원 복귀 실패: {}", e);
+        }
+        let _ = app.emit(ROUTING_OFFER_WITHDRAWN_EVENT, &offer);
+    }
+    for offer in outcome.offered {
+        println!(
+            "📞 [ROUTING] {} -> {} ({}번째 제안)",
+            offer.interaction.id, offer.agent_id, offer.attempt
+        );
+        let call = Some(offer.interaction.id.clone());
+        if let Err(e) = transition_agent(app, &offer.agent_id, AgentState::Ringing, call) {
+            // 호출하지 못한 상담원에게 제안을 남겨두지 않고 바로 다음 후보로
+            println!("⚠️ [ROUTING] 상담원 호출 실패: {}", e);
+            let _ = state.routing.lock().unwrap().cancel_offer(
+                &offer.interaction.id,
+                &offer.agent_id,
+                Utc::now(),
+                format!("상담원 호출 실패: {}", e),
+            );
+            continue;
+        }
+        let _ = app.emit(ROUTING_OFFER_EVENT, &offer);
+    }
+}
+
+/// 링 타임아웃 / 상담원 복귀를 반영하기 위해 주기적으로 분배 실행
+pub fn start_routing_engine<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(ROUTING_TICK_SECS)).await;
+            dispatch(&app);
+        }
+    });
+}
+
+// === 라우팅 Tauri Commands ===
+
+/// 콜을 분배 대기열에 넣고 즉시 분배 시도
+#[tauri::command]
+pub fn enqueue_interaction<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    interaction: Interaction,
+) -> Result<Interaction, String> {
+    if let Some(queue_id) = &interaction.queue_id {
+        state.queues.lock().unwrap().definition(queue_id)?;
+    }
+    let now = Utc::now();
+    let queued = state.routing.lock().unwrap().enqueue(interaction, now)?;
+
+    queue_state::record_call_event(
+        app.clone(),
+        state,
+        CallEvent::Enqueued {
+            call_id: queued.id.clone(),
+            at: Some(now),
+        },
+        queued.queue_id.clone(),
+    )?;
+    dispatch(&app);
+    Ok(queued)
+}
+
+#[tauri::command]
+pub fn accept_offer<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    interaction_id: String,
+    agent_id: String,
+) -> Result<RoutingOffer, String> {
+    let now = Utc::now();
+    // 제안을 꺼내기 전에 상담원이 이 콜로 호출 중인지 확인
+    let ringing = state.agents.lock().unwrap().iter().any(|a| {
+        a.id == agent_id
+            && a.status() == AgentState::Ringing
+            && a.current_call.as_deref() == Some(interaction_id.as_str())
+    });
+    if !ringing {
+        return Err(format!(
+            "상담원 {} 은 콜 {} 을 호출받는 중이 아닙니다",
+            agent_id, interaction_id
+        ));
+    }
+    let offer = state
+        .routing
+        .lock()
+        .unwrap()
+        .accept(&interaction_id, &agent_id, now)?;
+
+    if let Err(e) = transition_agent(
+        &app,
+        &agent_id,
+        AgentState::Busy,
+        Some(interaction_id.clone()),
+    ) {
+        // 확인한 뒤 상담원 상태가 바뀐 경우. 콜을 잃지 않도록 대기열로 되돌린다
+        state.routing.lock().unwrap().requeue_accepted(
+            &offer,
+            Utc::now(),
+            format!("통화 연결 실패: {}", e),
+        );
+        let _ = app.emit(ROUTING_OFFER_WITHDRAWN_EVENT, &offer);
+        dispatch(&app);
+        return Err(e);
+    }
+    queue_state::record_call_event(
+        app.clone(),
+        state,
+        CallEvent::Answered {
+            call_id: interaction_id,
+            agent_id,
+            at: Some(now),
+        },
+        offer.interaction.queue_id.clone(),
+    )?;
+    Ok(offer)
+}
+
+#[tauri::command]
+pub fn reject_offer<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    interaction_id: String,
+    agent_id: String,
+) -> Result<(), String> {
+    let offer = state
+        .routing
+        .lock()
+        .unwrap()
+        .reject(&interaction_id, &agent_id, Utc::now())?;
+
+    transition_agent(&app, &agent_id, AgentState::Available, None)?;
+    let _ = app.emit(ROUTING_OFFER_WITHDRAWN_EVENT, &offer);
+    dispatch(&app);
+    Ok(())
+}
+
+/// 고객이 대기 / 호출 중 끊은 경우
+#[tauri::command]
+pub fn abandon_interaction<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, QueueState>,
+    interaction_id: String,
+) -> Result<(), String> {
+    let now = Utc::now();
+    let (interaction, offer) = state
+        .routing
+        .lock()
+        .unwrap()
+        .abandon(&interaction_id, now)?;
+
+    if let Some(offer) = offer {
+        transition_agent(&app, &offer.agent_id, AgentState::Available, None)?;
+        let _ = app.emit(ROUTING_OFFER_WITHDRAWN_EVENT, &offer);
+    }
+    queue_state::record_call_event(
+        app,
+        state,
+        CallEvent::Abandoned {
+            call_id: interaction.id,
+            at: Some(now),
+        },
+        interaction.queue_id,
+    )
+}
+
+#[tauri::command]
+pub fn get_routing_queue(state: tauri::State<'_, QueueState>) -> RoutingQueueSnapshot {
+    state.routing.lock().unwrap().snapshot()
+}
+
+/// 라우팅 결정 로그 (오래된 순). `limit` 지정 시 최근 N개
+#[tauri::command]
+pub fn get_routing_log(
+    state: tauri::State<'_, QueueState>,
+    limit: Option<usize>,
+) -> Vec<RoutingLogEntry> {
+    state.routing.lock().unwrap().log(limit)
+}
+
+#[tauri::command]
+pub fn get_routing_config(state: tauri::State<'_, QueueState>) -> RoutingConfig {
+    state.routing.lock().unwrap().config().clone()
+}
+
+#[tauri::command]
+pub fn set_routing_config(
+    state: tauri::State<'_, QueueState>,
+    config: RoutingConfig,
+) -> Result<(), String> {
+    println!(
+        "🔀 [ROUTING] 전략 변경: {:?} (링 타임아웃 {}초)",
+        config.strategy, config.ring_timeout_secs
+    );
+    state.routing.lock().unwrap().set_config(config)
+}
@@ -0,0 +1,115 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\session_store.rs
+//! 로그인 세션(User + 토큰 + refresh 토큰)을 암호화해서 app data 디렉터리에 보관.
+//! 키는 OS 키 저장소에 있는 설치별 마스터 키에서 유도하므로 파일만 복사해서는 복호화되지 않는다.
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use zeroize::Zeroizing;
+
+use super::auth_state::User;
+use super::fs_util::{wipe_file, write_atomic};
+use super::keystore::{derive_key, install_master_key};
+use super::secret::Secret;
+
+pub const SESSION_FILE_NAME: &str = "session.bin";
+
+/// 파일에 저장하는 세션 내용
+#[derive(Serialize, Deserialize)]
+pub struct PersistedSession {
+    pub user: User,
+    /// `User.token` 은 직렬화 시 가려지므로 실제 값은 여기에 따로 저장한다
+    access_token: String,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    /// 잠긴 상태로 저장됐는지 (재시작해도 잠금 유지)
+    #[serde(default)]
+    pub locked: bool,
+}
+
+impl PersistedSession {
+    pub fn new(user: User, refresh_token: Option<String>, locked: bool) -> Self {
+        Self {
+            access_token: user.token.expose().clone(),
+            user,
+            refresh_token,
+            locked,
+        }
+    }
+}
+
+/// 파일 형식: MAGIC(4) + nonce(12) + 암호문
+const MAGIC: &[u8; 4] = b"NXS1";
+const NONCE_LEN: usize = 12;
+/// 키 유도용 도메인 구분자 (형식이 바뀌면 버전을 올린다)
+const KEY_CONTEXT: &[u8] = b"nexus-call-hub/session/v2";
+
+pub struct SessionStore {
+    path: PathBuf,
+    key: Zeroizing<[u8; 32]>,
+}
+
+impl SessionStore {
+    /// 이 설치의 마스터 키(OS 키 저장소)로 키를 만든다
+    pub fn for_install(path: PathBuf) -> Result<Self, String> {
+        let master_key = install_master_key()?;
+        Ok(Self::new(path, &master_key))
+    }
+
+    pub fn new(path: PathBuf, master_key: &[u8]) -> Self {
+        Self {
+            path,
+            key: derive_key(master_key, KEY_CONTEXT),
+        }
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(self.key.as_slice()))
+    }
+
+    pub fn save(&self, session: &PersistedSession) -> Result<(), String> {
+        let plaintext = Zeroizing::new(serde_json::to_vec(session).map_err(|e| e.to_string())?);
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher()
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| "세션 암호화 실패".to_string())?;
+
+        let mut bytes = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&nonce);
+        bytes.extend_from_slice(&ciphertext);
+        write_atomic(&self.path, &bytes)
+    }
+
+    /// 저장된 세션 복호화. 파일이 없으면 None
+    pub fn load(&self) -> Result<Option<PersistedSession>, String> {
+        let bytes = match fs::read(&self.path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.to_string()),
+        };
+        if bytes.len() < MAGIC.len() + NONCE_LEN || &bytes[..MAGIC.len()] != MAGIC {
+            return Err("세션 파일 형식이 올바르지 않습니다".to_string());
+        }
+
+        let (nonce, ciphertext) = bytes[MAGIC.len()..].split_at(NONCE_LEN);
+        let plaintext = Zeroizing::new(
+            self.cipher()
+                .decrypt(Nonce::from_slice(nonce), ciphertext)
+                .map_err(|_| {
+                    "세션 복호화 실패 (다른 설치에서 만든 파일이거나 손상됨)".to_string()
+                })?,
+        );
+        let mut session: PersistedSession =
+            serde_json::from_slice(&plaintext).map_err(|e| e.to_string())?;
+        session.user.token = Secret::new(std::mem::take(&mut session.access_token));
+        Ok(Some(session))
+    }
+
+    /// 파일 내용을 0 으로 덮어쓰고 디스크에 반영한 뒤 삭제
+    pub fn wipe(&self) -> Result<(), String> {
+        wipe_file(&self.path)
+    }
+}
@@ -0,0 +1,134 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\token_refresh.rs
+//! 백엔드 refresh 엔드포인트 호출 (재시도 + 지수 백오프)
+use serde::{Deserialize, Serialize};
+
+use super::backend::BackendConfig;
+use super::secret::REDACTED;
+
+/// refresh 요청 본문
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RefreshRequest<'a> {
+    refresh_token: &'a str,
+}
+
+/// 백엔드 공통 응답 (`ApiResponse<T>`)
+#[derive(Deserialize)]
+pub(crate) struct ApiResponse<T> {
+    pub(crate) success: bool,
+    #[serde(default)]
+    pub(crate) message: String,
+    pub(crate) data: Option<T>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RefreshResponseData {
+    token: String,
+    /// 회전(rotation) 시 새 refresh 토큰. 없으면 기존 토큰 유지
+    #[serde(default)]
+    refresh_token: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct RefreshedTokens {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+}
+
+/// 토큰 값은 출력하지 않는다
+impl std::fmt::Debug for RefreshedTokens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshedTokens")
+            .field("access_token", &REDACTED)
+            .field(
+                "refresh_token",
+                &self.refresh_token.as_ref().map(|_| REDACTED),
+            )
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RefreshError {
+    /// 백엔드가 거절 (4xx / success=false). 재시도해도 소용없음
+    Rejected(String),
+    /// 네트워크 오류 / 5xx. 재시도 대상
+    Transient(String),
+}
+
+impl std::fmt::Display for RefreshError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RefreshError::Rejected(message) => write!(f, "토큰 갱신 거절: {}", message),
+            RefreshError::Transient(message) => write!(f, "토큰 갱신 실패: {}", message),
+        }
+    }
+}
+
+/// refresh 엔드포인트 1회 호출
+pub async fn request_refresh(
+    client: &reqwest::Client,
+    config: &BackendConfig,
+    refresh_token: &str,
+) -> Result<RefreshedTokens, RefreshError> {
+    let response = client
+        .post(config.refresh_url())
+        .timeout(config.request_timeout())
+        .json(&RefreshRequest { refresh_token })
+        .send()
+        .await
+        .map_err(|e| RefreshError::Transient(e.without_url().to_string()))?;
+
+    let status = response.status();
+    if status.is_server_error() {
+        return Err(RefreshError::Transient(format!("HTTP {}", status.as_u16())));
+    }
+    if !status.is_success() {
+        return Err(RefreshError::Rejected(format!("HTTP {}", status.as_u16())));
+    }
+
+    let body: ApiResponse<RefreshResponseData> = response
+        .json()
+        .await
+        .map_err(|e| RefreshError::Transient(e.without_url().to_string()))?;
+    match body {
+        ApiResponse {
+            success: true,
+            data: Some(data),
+            ..
+        } => Ok(RefreshedTokens {
+            access_token: data.token,
+            refresh_token: data.refresh_token,
+        }),
+        ApiResponse { message, .. } => Err(RefreshError::Rejected(message)),
+    }
+}
+
+/// 일시적 오류는 `config.retry` 정책대로 재시도, 거절되면 바로 실패
+pub async fn refresh_with_backoff(
+    client: &reqwest::Client,
+    config: &BackendConfig,
+    refresh_token: &str,
+) -> Result<RefreshedTokens, RefreshError> {
+    let max_attempts = config.retry.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match request_refresh(client, config, refresh_token).await {
+            Ok(tokens) => return Ok(tokens),
+            Err(RefreshError::Transient(message)) if attempt < max_attempts => {
+                let wait = config.retry.backoff(attempt);
+                println!(
+                    "🔁 [AUTH] 토큰 갱신 재시도 {}/{} ({}ms 후): {}",
+                    attempt,
+                    max_attempts,
+                    wait.as_millis(),
+                    message
+                );
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
@@ -0,0 +1,682 @@
+// C:\pilot-tauri\nexus-call-hub\src-tauri\src\state\totp.rs
+//! 2단계 인증 (RFC 6238 TOTP). 정책에서 `require_second_factor` 인 역할은
+//! 비밀번호 로그인 후 인증 앱 코드 또는 복구 코드를 확인해야 세션이 완전히 인증된다.
+//! 비밀 키와 복구 코드 해시는 설치별 마스터 키(OS 키 저장소)에서 유도한 키로 암호화해서 저장한다.
+use aes_gcm::aead::{Aead, AeadCore, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use chrono::{DateTime, Utc};
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter};
+use zeroize::Zeroizing;
+
+use super::audit_log::AuditEvent;
+use super::auth_state::{end_session, AuthState, LogoutReason, USER_LOGGED_IN_EVENT};
+use super::fs_util::{wipe_file, write_atomic};
+use super::keystore::{derive_key, install_master_key};
+
+pub const TOTP_FILE_NAME: &str = "totp.bin";
+/// 암호화 전에 쓰던 평문 파일. 로드할 때 암호화 파일로 옮기고 지운다
+pub const LEGACY_TOTP_FILE_NAME: &str = "totp.json";
+
+/// 파일 형식: MAGIC(4) + nonce(12) + 암호문
+const MAGIC: &[u8; 4] = b"NXT1";
+const NONCE_LEN: usize = 12;
+/// 키 유도용 도메인 구분자 (형식이 바뀌면 버전을 올린다)
+const KEY_CONTEXT: &[u8] = b"nexus-call-hub/totp/v1";
+
+/// 인증 앱에 표시되는 발급자 이름
+pub const TOTP_ISSUER: &str = "Nexus Call Hub";
+pub const TOTP_DIGITS: u32 = 6;
+pub const TOTP_PERIOD_SECS: i64 = 30;
+/// 앞뒤로 허용하는 시간 구간 수 (시계 오차 ±30초)
+pub const TOTP_SKEW_STEPS: i64 = 1;
+/// 비밀 키 길이 (RFC 4226 권장 160비트)
+pub const TOTP_SECRET_LEN: usize = 20;
+pub const RECOVERY_CODE_COUNT: usize = 10;
+/// 2단계 인증 실패가 이 횟수에 도달하면 로그아웃
+pub const MAX_SECOND_FACTOR_ATTEMPTS: u32 = 5;
+/// 다른 사용자의 인증 앱을 등록해 줄 수 있는 권한 (첫 등록은 관리자가 대신 한다)
+pub const MFA_MANAGE_PERMISSION: &str = "security:mfa:manage";
+
+/// 복구 코드 문자 (헷갈리는 0 / o / 1 / l 제외)
+const RECOVERY_CODE_ALPHABET: &[u8] = b"23456789abcdefghjkmnpqrstuvwxyz";
+
+/// RFC 4226 HOTP
+pub fn hotp(secret: &[u8], counter: u64, digits: u32) -> String {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC 키 길이는 제한이 없다");
+    mac.update(&counter.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        binary % 10u32.pow(digits),
+        width = digits as usize
+    )
+}
+
+/// unix 초가 속한 시간 구간 번호
+pub fn time_step(unix_secs: i64) -> u64 {
+    unix_secs.max(0) as u64 / TOTP_PERIOD_SECS as u64
+}
+
+/// RFC 6238 TOTP (SHA-1, 30초, 6자리)
+pub fn totp_at(secret: &[u8], unix_secs: i64) -> String {
+    hotp(secret, time_step(unix_secs), TOTP_DIGITS)
+}
+
+/// 시계 오차를 허용해서 코드 확인. 맞으면 그 코드의 시간 구간 번호
+pub fn match_step(secret: &[u8], code: &str, unix_secs: i64) -> Option<u64> {
+    let code = code.trim();
+    if code.len() != TOTP_DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let current = time_step(unix_secs) as i64;
+    (-TOTP_SKEW_STEPS..=TOTP_SKEW_STEPS)
+        .map(|offset| current + offset)
+        .filter(|step| *step >= 0)
+        .find(|step| {
+            constant_time_eq(
+                hotp(secret, *step as u64, TOTP_DIGITS).as_bytes(),
+                code.as_bytes(),
+            )
+        })
+        .map(|step| step as u64)
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// 인증 앱 등록용 URI (`otpauth://totp/발급자:계정?...`)
+pub fn otpauth_uri(issuer: &str, account: &str, secret_base32: &str) -> String {
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        percent_encode(issuer),
+        percent_encode(account),
+        secret_base32,
+        percent_encode(issuer),
+        TOTP_DIGITS,
+        TOTP_PERIOD_SECS
+    )
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// URI 를 담은 QR 코드 (SVG 문자열)
+pub fn qr_svg(uri: &str) -> Result<String, String> {
+    let code = qrcode::QrCode::new(uri.as_bytes()).map_err(|e| e.to_string())?;
+    Ok(code
+        .render::<qrcode::render::svg::Color>()
+        .min_dimensions(200, 200)
+        .build())
+}
+
+/// 대소문자 / 구분자 무시
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// 복구 코드는 무작위 40비트 이상이라 느린 해시 없이 SHA-256 으로 저장
+fn hash_recovery_code(code: &str) -> String {
+    Sha256::digest(normalize_recovery_code(code).as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn generate_recovery_code(rng: &mut impl Rng) -> String {
+    let mut chars = (0..10)
+        .map(|_| RECOVERY_CODE_ALPHABET[rng.gen_range(0..RECOVERY_CODE_ALPHABET.len())] as char);
+    let first: String = chars.by_ref().take(5).collect();
+    let second: String = chars.collect();
+    format!("{}-{}", first, second)
+}
+
+/// 사용자 한 명의 등록 정보
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct TotpUser {
+    /// base32 비밀 키
+    secret: String,
+    /// 등록 확인 코드까지 통과했는지
+    pub confirmed: bool,
+    pub created_at: DateTime<Utc>,
+    /// 남은 복구 코드의 SHA-256 해시
+    recovery_codes: Vec<String>,
+    /// 마지막으로 쓴 코드의 시간 구간 (같은 코드 재사용 방지)
+    #[serde(default)]
+    last_used_step: Option<u64>,
+}
+
+/// 저장되는 등록 정보 (사용자 id → 등록)
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TotpSettings {
+    #[serde(default)]
+    pub users: BTreeMap<String, TotpUser>,
+}
+
+/// `begin_totp_enrollment` 응답. 비밀 키와 복구 코드는 이때 한 번만 보여준다
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TotpEnrollment {
+    pub secret: String,
+    pub otpauth_uri: String,
+    /// 인증 앱으로 찍을 QR 코드 (SVG)
+    pub qr_svg: String,
+    pub recovery_codes: Vec<String>,
+}
+
+/// 2단계 인증 확인 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecondFactorOutcome {
+    Verified,
+    /// 틀림. 남은 시도 횟수
+    Rejected {
+        remaining: u32,
+    },
+    /// 시도 횟수 초과
+    Exhausted,
+}
+
+/// 2단계 인증 엔진. 시각은 인자로 받는다
+#[derive(Default)]
+pub struct TotpStore {
+    settings: TotpSettings,
+    path: Option<PathBuf>,
+    key: Zeroizing<[u8; 32]>,
+    /// 사용자별 연속 실패 횟수 (메모리에만 둔다)
+    failed_attempts: BTreeMap<String, u32>,
+}
+
+impl TotpStore {
+    /// 이 설치의 마스터 키(OS 키 저장소)로 키를 만들어 로드
+    pub fn for_install(path: PathBuf) -> Result<Self, String> {
+        let master_key = install_master_key()?;
+        Ok(Self::load(path, &master_key))
+    }
+
+    /// 등록 파일 로드. 없거나 복호화할 수 없으면 등록된 사용자 없음.
+    /// 같은 디렉터리에 평문 `totp.json` 이 남아 있으면 암호화해서 옮긴 뒤 지운다
+    pub fn load(path: PathBuf, master_key: &[u8]) -> Self {
+        let mut store = Self {
+            path: Some(path),
+            key: derive_key(master_key, KEY_CONTEXT),
+            ..Self::default()
+        };
+        match store.read() {
+            Ok(Some(settings)) => store.settings = settings,
+            Ok(None) => store.migrate_legacy(),
+            Err(e) => println!("⚠️ [MFA] 2단계 인증 등록 정보 로드 실패: {}", e),
+        }
+        store
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        // `Mac::new_from_slice` 와 이름이 겹쳐서 KeyInit 은 경로로 부른다
+        <Aes256Gcm as aes_gcm::KeyInit>::new(Key::<Aes256Gcm>::from_slice(self.key.as_slice()))
+    }
+
+    /// 암호화된 등록 파일 복호화. 파일이 없으면 None
+    fn read(&self) -> Result<Option<TotpSettings>, String> {
+        let Some(path) = &self.path else {
+            return Ok(None);
+        };
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.to_string()),
+        };
+        if bytes.len() < MAGIC.len() + NONCE_LEN || &bytes[..MAGIC.len()] != MAGIC {
+            return Err("2단계 인증 파일 형식이 올바르지 않습니다".to_string());
+        }
+
+        let (nonce, ciphertext) = bytes[MAGIC.len()..].split_at(NONCE_LEN);
+        let plaintext = Zeroizing::new(
+            self.cipher()
+                .decrypt(Nonce::from_slice(nonce), ciphertext)
+                .map_err(|_| {
+                    "2단계 인증 파일 복호화 실패 (다른 설치에서 만든 파일이거나 손상됨)".to_string()
+                })?,
+        );
+        serde_json::from_slice(&plaintext)
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+
+    /// 평문 `totp.json` → 암호화 파일. 암호화 파일 저장에 성공한 뒤에만 평문을 지운다
+    fn migrate_legacy(&mut self) {
+        let Some(legacy) = self
+            .path
+            .as_ref()
+            .map(|path| path.with_file_name(LEGACY_TOTP_FILE_NAME))
+        else {
+            return;
+        };
+        let bytes = match fs::read(&legacy) {
+            Ok(bytes) => Zeroizing::new(bytes),
+            Err(_) => return,
+        };
+        match serde_json::from_slice(&bytes) {
+            Ok(settings) => self.settings = settings,
+            Err(e) => {
+                println!("⚠️ [MFA] 평문 2단계 인증 등록 정보 파싱 실패: {}", e);
+                return;
+            }
+        }
+        match self.persist().and_then(|_| wipe_file(&legacy)) {
+            Ok(()) => println!("🔐 [MFA] 평문 2단계 인증 등록 정보를 암호화 파일로 옮겼습니다"),
+            Err(e) => println!("⚠️ [MFA] 평문 2단계 인증 등록 정보 이전 실패: {}", e),
+        }
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let plaintext =
+            Zeroizing::new(serde_json::to_vec(&self.settings).map_err(|e| e.to_string())?);
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher()
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| "2단계 인증 등록 정보 암호화 실패".to_string())?;
+
+        let mut bytes = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&nonce);
+        bytes.extend_from_slice(&ciphertext);
+        write_atomic(path, &bytes)
+    }
+
+    pub fn is_enrolled(&self, user_id: &str) -> bool {
+        self.settings
+            .users
+            .get(user_id)
+            .is_some_and(|user| user.confirmed)
+    }
+
+    pub fn remaining_recovery_codes(&self, user_id: &str) -> usize {
+        self.settings
+            .users
+            .get(user_id)
+            .map_or(0, |user| user.recovery_codes.len())
+    }
+
+    pub fn failed_attempts(&self, user_id: &str) -> u32 {
+        self.failed_attempts.get(user_id).copied().unwrap_or(0)
+    }
+
+    /// 로그인마다 초기화
+    pub fn reset_failures(&mut self, user_id: &str) {
+        self.failed_attempts.remove(user_id);
+    }
+
+    /// 새 비밀 키 / 복구 코드 발급. 확인 코드를 통과하기 전까지는 기존 등록을 바꾸지 않는다
+    pub fn begin_enrollment(
+        &mut self,
+        user_id: &str,
+        account: &str,
+        now: DateTime<Utc>,
+    ) -> Result<TotpEnrollment, String> {
+        let mut rng = rand::rngs::OsRng;
+        let mut secret = [0u8; TOTP_SECRET_LEN];
+        rng.fill_bytes(&mut secret);
+        let secret = BASE32_NOPAD.encode(&secret);
+        let recovery_codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| generate_recovery_code(&mut rng))
+            .collect();
+        let otpauth_uri = otpauth_uri(TOTP_ISSUER, account, &secret);
+        let qr_svg = qr_svg(&otpauth_uri)?;
+
+        let pending = TotpUser {
+            secret: secret.clone(),
+            confirmed: false,
+            created_at: now,
+            recovery_codes: recovery_codes
+                .iter()
+                .map(|c| hash_recovery_code(c))
+                .collect(),
+            last_used_step: None,
+        };
+        // 확인 전 등록은 `~pending` 키로 따로 보관
+        self.settings.users.insert(pending_key(user_id), pending);
+        self.persist()?;
+
+        Ok(TotpEnrollment {
+            secret,
+            otpauth_uri,
+            qr_svg,
+            recovery_codes,
+        })
+    }
+
+    /// 등록 확인. 인증 앱이 만든 코드가 맞으면 등록을 확정하고 true.
+    /// 등록은 이미 인증된 세션에서만 하므로 틀려도 실패 횟수에 넣지 않는다
+    pub fn confirm_enrollment(
+        &mut self,
+        user_id: &str,
+        code: &str,
+        now: DateTime<Utc>,
+    ) -> Result<bool, String> {
+        let key = pending_key(user_id);
+        let Some(pending) = self.settings.users.get(&key) else {
+            return Err("진행 중인 등록이 없습니다".to_string());
+        };
+        let secret = decode_secret(&pending.secret)?;
+        let Some(step) = match_step(&secret, code, now.timestamp()) else {
+            return Ok(false);
+        };
+
+        let mut user = self
+            .settings
+            .users
+            .remove(&key)
+            .expect("위에서 확인한 등록");
+        user.confirmed = true;
+        user.last_used_step = Some(step);
+        self.settings.users.insert(user_id.to_string(), user);
+        self.persist()?;
+        Ok(true)
+    }
+
+    /// 인증 앱 코드 확인. 이미 쓴 구간의 코드는 거부한다
+    pub fn verify_code(
+        &mut self,
+        user_id: &str,
+        code: &str,
+        now: DateTime<Utc>,
+    ) -> Result<SecondFactorOutcome, String> {
+        let Some(user) = self
+            .settings
+            .users
+            .get_mut(user_id)
+            .filter(|user| user.confirmed)
+        else {
+            return Err("2단계 인증이 등록되어 있지 않습니다".to_string());
+        };
+        let secret = decode_secret(&user.secret)?;
+        let step = match_step(&secret, code, now.timestamp())
+            .filter(|step| user.last_used_step.is_none_or(|last| *step > last));
+        let Some(step) = step else {
+            return Ok(self.record_failure(user_id));
+        };
+
+        user.last_used_step = Some(step);
+        self.reset_failures(user_id);
+        self.persist()?;
+        Ok(SecondFactorOutcome::Verified)
+    }
+
+    /// 복구 코드 확인. 맞으면 그 코드는 지운다 (1회용)
+    pub fn verify_recovery_code(
+        &mut self,
+        user_id: &str,
+        code: &str,
+    ) -> Result<SecondFactorOutcome, String> {
+        let Some(user) = self
+            .settings
+            .users
+            .get_mut(user_id)
+            .filter(|user| user.confirmed)
+        else {
+            return Err("2단계 인증이 등록되어 있지 않습니다".to_string());
+        };
+        let hash = hash_recovery_code(code);
+        let Some(index) = user
+            .recovery_codes
+            .iter()
+            .position(|stored| constant_time_eq(stored.as_bytes(), hash.as_bytes()))
+        else {
+            return Ok(self.record_failure(user_id));
+        };
+
+        user.recovery_codes.remove(index);
+        self.reset_failures(user_id);
+        self.persist()?;
+        Ok(SecondFactorOutcome::Verified)
+    }
+
+    fn record_failure(&mut self, user_id: &str) -> SecondFactorOutcome {
+        let failed = self.failed_attempts.entry(user_id.to_string()).or_insert(0);
+        *failed += 1;
+        match MAX_SECOND_FACTOR_ATTEMPTS.saturating_sub(*failed) {
+            0 => SecondFactorOutcome::Exhausted,
+            remaining => SecondFactorOutcome::Rejected { remaining },
+        }
+    }
+}
+
+fn pending_key(user_id: &str) -> String {
+    format!("~pending:{}", user_id)
+}
+
+fn decode_secret(secret: &str) -> Result<Vec<u8>, String> {
+    BASE32_NOPAD
+        .decode(secret.as_bytes())
+        .map_err(|e| format!("저장된 비밀 키가 올바르지 않습니다: {}", e))
+}
+
+// === Tauri 연동 ===
+
+/// `get_second_factor_status` 응답
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecondFactorStatus {
+    /// 역할상 2단계 인증이 필요한지
+    pub required: bool,
+    pub enrolled: bool,
+    /// 로그인 후 2단계 인증을 기다리는 중인지
+    pub pending: bool,
+    pub remaining_recovery_codes: usize,
+    pub failed_attempts: u32,
+}
+
+/// `verify_second_factor` 입력
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SecondFactorCredential {
+    Totp { code: String },
+    RecoveryCode { code: String },
+}
+
+/// 확인 방법 (감사 로그용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecondFactorMethod {
+    Totp,
+    RecoveryCode,
+}
+
+fn current_user_id(state: &AuthState) -> Result<String, String> {
+    state
+        .user
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|u| u.id.clone())
+        .ok_or_else(|| "로그인 상태가 아닙니다".to_string())
+}
+
+/// 확인 결과 처리. 통과하면 세션을 완전히 인증하고, 시도 횟수를 넘기면 로그아웃
+fn apply_outcome(
+    app: &AppHandle,
+    state: &AuthState,
+    window: &str,
+    method: SecondFactorMethod,
+    outcome: SecondFactorOutcome,
+) -> Result<bool, String> {
+    match outcome {
+        SecondFactorOutcome::Verified => {
+            let was_pending = state.complete_second_factor();
+            state.audit(Some(window), AuditEvent::SecondFactorVerified { method });
+            if was_pending {
+                let _ = app.emit(USER_LOGGED_IN_EVENT, state.session_user());
+            }
+            println!("🔐 [MFA] 2단계 인증 통과 ({:?})", method);
+            Ok(true)
+        }
+        SecondFactorOutcome::Rejected { remaining } => {
+            state.audit(Some(window), AuditEvent::SecondFactorFailed { remaining });
+            println!("⚠️ [MFA] 2단계 인증 실패 (남은 시도 {})", remaining);
+            Ok(false)
+        }
+        SecondFactorOutcome::Exhausted => {
+            state.audit(
+                Some(window),
+                AuditEvent::SecondFactorFailed { remaining: 0 },
+            );
+            end_session(app, state, Some(window), LogoutReason::SecondFactorFailed);
+            Err("2단계 인증 실패 횟수를 초과해 로그아웃되었습니다".to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_second_factor_status(state: tauri::State<AuthState>) -> SecondFactorStatus {
+    let user_id = state.user.lock().unwrap().as_ref().map(|u| u.id.clone());
+    let required = state.second_factor_required();
+    let pending = state.is_second_factor_pending();
+    let totp = state.totp.lock().unwrap();
+    SecondFactorStatus {
+        required,
+        enrolled: user_id.as_deref().is_some_and(|id| totp.is_enrolled(id)),
+        pending,
+        remaining_recovery_codes: user_id
+            .as_deref()
+            .map_or(0, |id| totp.remaining_recovery_codes(id)),
+        failed_attempts: user_id.as_deref().map_or(0, |id| totp.failed_attempts(id)),
+    }
+}
+
+/// 등록 대상 사용자. 등록은 2단계 인증까지 마친 온라인 세션에서만 할 수 있다.
+/// 비밀번호만 통과한 대기 세션이 허용되면, 등록 정보가 없는 새 PC 에서 비밀번호만 아는 사람이
+/// 자기 인증 앱을 등록해 2단계 인증을 건너뛸 수 있기 때문이다. 그래서 2단계 인증이 필요한 역할의
+/// 첫 등록은 `MFA_MANAGE_PERMISSION` 을 가진 관리자가 `user_id` 를 지정해 대신 한다
+fn enrollment_target(
+    state: &AuthState,
+    window: &str,
+    user_id: Option<String>,
+) -> Result<String, String> {
+    if !state.is_session_valid(Utc::now()) {
+        return Err("2단계 인증까지 마친 세션에서만 인증 앱을 등록할 수 있습니다".to_string());
+    }
+    if state.is_locked() {
+        return Err("세션이 잠겨 있습니다".to_string());
+    }
+    if state.is_offline() || state.is_impersonating() {
+        return Err("오프라인 / 대리 세션에서는 인증 앱을 등록할 수 없습니다".to_string());
+    }
+    let current_id = current_user_id(state)?;
+    let target = user_id
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty() && *id != current_id);
+    let Some(target) = target else {
+        return Ok(current_id);
+    };
+    if !state.has_permission(MFA_MANAGE_PERMISSION) {
+        state.audit(
+            Some(window),
+            AuditEvent::PermissionDenied {
+                target: "totp_enrollment".to_string(),
+                reason: format!("{} 권한 없음", MFA_MANAGE_PERMISSION),
+            },
+        );
+        return Err("다른 사용자의 인증 앱을 등록할 권한이 없습니다".to_string());
+    }
+    Ok(target)
+}
+
+/// 인증 앱 등록 시작. `user_id` 가 없으면 본인, 있으면 관리자가 대신 등록
+#[tauri::command]
+pub fn begin_totp_enrollment(
+    window: tauri::Window,
+    state: tauri::State<AuthState>,
+    user_id: Option<String>,
+) -> Result<TotpEnrollment, String> {
+    let target = enrollment_target(&state, window.label(), user_id)?;
+    let enrollment = state
+        .totp
+        .lock()
+        .unwrap()
+        .begin_enrollment(&target, &target, Utc::now())?;
+    println!("📲 [MFA] 인증 앱 등록 시작: {}", target);
+    Ok(enrollment)
+}
+
+/// 등록 확인 코드 입력. 통과하면 등록이 확정된다 (틀리면 false)
+#[tauri::command]
+pub fn confirm_totp_enrollment(
+    window: tauri::Window,
+    state: tauri::State<AuthState>,
+    code: String,
+    user_id: Option<String>,
+) -> Result<bool, String> {
+    let target = enrollment_target(&state, window.label(), user_id)?;
+    let confirmed = state
+        .totp
+        .lock()
+        .unwrap()
+        .confirm_enrollment(&target, &code, Utc::now())?;
+    if confirmed {
+        state.audit(
+            Some(window.label()),
+            AuditEvent::TotpEnrolled {
+                target_user_id: target.clone(),
+            },
+        );
+        println!("📲 [MFA] 인증 앱 등록 완료: {}", target);
+    }
+    Ok(confirmed)
+}
+
+/// 로그인 후 2단계 인증. 틀리면 false, `MAX_SECOND_FACTOR_ATTEMPTS` 번 틀리면 로그아웃
+#[tauri::command]
+pub fn verify_second_factor(
+    app: AppHandle,
+    window: tauri::Window,
+    state: tauri::State<AuthState>,
+    credential: SecondFactorCredential,
+) -> Result<bool, String> {
+    let user_id = current_user_id(&state)?;
+    if !state.is_second_factor_pending() {
+        return Ok(true);
+    }
+    let (method, outcome) = {
+        let mut totp = state.totp.lock().unwrap();
+        match credential {
+            SecondFactorCredential::Totp { code } => (
+                SecondFactorMethod::Totp,
+                totp.verify_code(&user_id, &code, Utc::now())?,
+            ),
+            SecondFactorCredential::RecoveryCode { code } => (
+                SecondFactorMethod::RecoveryCode,
+                totp.verify_recovery_code(&user_id, &code)?,
+            ),
+        }
+    };
+    apply_outcome(&app, &state, window.label(), method, outcome)
+}
@@ -0,0 +1,151 @@
+// src-tauri/src/window/access.rs
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::config::WindowRegistry;
+use super::types::{WindowAccess, WindowDefinition, WindowType};
+use crate::state::audit_log::AuditEvent;
+use crate::state::auth_state::AuthState;
+
+/// payload: `WindowAccessDenied`
+pub const WINDOW_ACCESS_DENIED_EVENT: &str = "window-access-denied";
+
+/// 윈도우 명령 오류. 프론트엔드에서는 `kind` 로 구분한다
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WindowError {
+    /// 윈도우 정의에 없는 종류
+    Unknown { window: WindowType },
+    /// 로그인이 안 되어 있거나 세션이 만료됨
+    Unauthenticated { window: WindowType },
+    /// 자리 비움 잠금 중 (잠금 해제 전까지 공개 윈도우만)
+    Locked { window: WindowType },
+    /// 로그인은 되어 있지만 권한 부족
+    Forbidden {
+        window: WindowType,
+        permission: String,
+    },
+    /// 윈도우 생성 / 조작 실패
+    Failed { message: String },
+}
+
+impl std::fmt::Display for WindowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowError::Unknown { window } => write!(f, "알 수 없는 윈도우: {}", window),
+            WindowError::Unauthenticated { window } => {
+                write!(f, "로그인이 필요합니다: {}", window)
+            }
+            WindowError::Locked { window } => {
+                write!(f, "세션이 잠겨 있습니다: {}", window)
+            }
+            WindowError::Forbidden { window, permission } => {
+                write!(f, "권한이 없습니다: {} ({})", window, permission)
+            }
+            WindowError::Failed { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<String> for WindowError {
+    fn from(message: String) -> Self {
+        WindowError::Failed { message }
+    }
+}
+
+impl From<tauri::Error> for WindowError {
+    fn from(e: tauri::Error) -> Self {
+        WindowError::Failed {
+            message: e.to_string(),
+        }
+    }
+}
+
+/// 거부 알림
+#[derive(Debug, Clone, Serialize)]
+pub struct WindowAccessDenied {
+    pub window: WindowType,
+    /// 요청한 윈도우 label
+    pub requested_by: Option<String>,
+    pub user_id: Option<String>,
+    pub error: WindowError,
+    pub at: DateTime<Utc>,
+}
+
+/// `AuthState` 기준으로 윈도우 접근 조건 검사
+pub fn authorize(
+    auth: &AuthState,
+    window_type: &WindowType,
+    definition: &WindowDefinition,
+) -> Result<(), WindowError> {
+    let required = definition.required_access();
+    if *required == WindowAccess::Public {
+        return Ok(());
+    }
+    if !auth.is_session_valid(Utc::now()) {
+        return Err(WindowError::Unauthenticated {
+            window: window_type.clone(),
+        });
+    }
+    if auth.is_locked() {
+        return Err(WindowError::Locked {
+            window: window_type.clone(),
+        });
+    }
+    match required {
+        WindowAccess::Permission(permission) if !auth.has_permission(permission) => {
+            Err(WindowError::Forbidden {
+                window: window_type.clone(),
+                permission: permission.clone(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 정의 조회 + 접근 검사 + 거부 시 감사 기록. `requested_by` 는 요청한 윈도우 label
+pub fn ensure_window_access(
+    app: &AppHandle,
+    window_type: &WindowType,
+    requested_by: Option<&str>,
+) -> Result<WindowDefinition, WindowError> {
+    let definition = app
+        .state::<WindowRegistry>()
+        .get(window_type)
+        .cloned()
+        .ok_or_else(|| WindowError::Unknown {
+            window: window_type.clone(),
+        })?;
+    let auth = app.state::<AuthState>();
+    authorize(&auth, window_type, &definition).inspect_err(|error| {
+        let denied = WindowAccessDenied {
+            window: window_type.clone(),
+            requested_by: requested_by.map(str::to_string),
+            user_id: auth
+                .user
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|user| user.id.clone()),
+            error: error.clone(),
+            at: Utc::now(),
+        };
+        auth.audit_as(
+            denied.user_id.clone(),
+            auth.impersonator_id(),
+            requested_by,
+            AuditEvent::PermissionDenied {
+                target: format!("window:{}", definition.label),
+                reason: error.to_string(),
+            },
+        );
+        println!(
+            "🚫 [AUDIT] 윈도우 접근 거부: {} (사용자: {})",
+            error,
+            denied.user_id.as_deref().unwrap_or("-")
+        );
+        let _ = app.emit(WINDOW_ACCESS_DENIED_EVENT, &denied);
+    })?;
+    Ok(definition)
+}
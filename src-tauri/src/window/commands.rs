@@ -1,22 +1,40 @@
 // src-tauri/src/window/commands.rs
-use super::config::WindowConfigManager;
-use super::types::WindowType;
-use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+use super::access::{ensure_window_access, WindowError};
+use super::config::WindowRegistry;
+use super::geometry::{apply_geometry, saved_geometry, WindowGeometry};
+use super::types::{WindowDefinition, WindowType};
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
 
-/// 윈도우 생성
+/// 윈도우 생성 (윈도우별 접근 조건을 먼저 검사)
 #[tauri::command]
-pub async fn open_window(app_handle: AppHandle, window_type: WindowType) -> Result<String, String> {
-    let config = WindowConfigManager::get_config(window_type);
+pub async fn open_window(
+    app_handle: AppHandle,
+    window: tauri::Window,
+    window_type: WindowType,
+) -> Result<String, WindowError> {
+    let config = ensure_window_access(&app_handle, &window_type, Some(window.label()))?;
 
     // 이미 존재하는 윈도우면 포커스
     if app_handle.get_webview_window(&config.label).is_some() {
-        return focus_window(app_handle, config.label).await;
+        return Ok(focus_window(app_handle, config.label).await?);
     }
 
+    let saved = saved_geometry(&app_handle, &config.label);
+    build_window(&app_handle, &config, saved.as_ref())?;
+    Ok("Window opened successfully".to_string())
+}
+
+/// 정의대로 윈도우 생성 (접근 검사는 호출하는 쪽에서).
+/// `geometry` 가 있으면 숨긴 채 만들고 적용한 뒤 보인다. 없으면 중앙 배치
+pub(crate) fn build_window(
+    app_handle: &AppHandle,
+    config: &WindowDefinition,
+    geometry: Option<&WindowGeometry>,
+) -> Result<WebviewWindow, WindowError> {
     let mut builder = WebviewWindowBuilder::new(
-        &app_handle,
+        app_handle,
         &config.label,
-        WebviewUrl::App(config.url.into()),
+        WebviewUrl::App(config.webview_url().into()),
     )
     .title(&config.title)
     .inner_size(config.width, config.height)
@@ -32,29 +50,35 @@ pub async fn open_window(app_handle: AppHandle, window_type: WindowType) -> Resu
     }
 
     // 최소/최대 크기 설정
-    if let Some(min_width) = config.min_width {
-        if let Some(min_height) = config.min_height {
-            builder = builder.min_inner_size(min_width, min_height);
-        }
+    if let Some((min_width, min_height)) = config.min_size() {
+        builder = builder.min_inner_size(min_width, min_height);
     }
-    if let Some(max_width) = config.max_width {
-        if let Some(max_height) = config.max_height {
-            builder = builder.max_inner_size(max_width, max_height);
-        }
+    if let Some((max_width, max_height)) = config.max_size() {
+        builder = builder.max_inner_size(max_width, max_height);
     }
 
-    // 중앙 배치
-    if config.center {
+    if geometry.is_some() {
+        builder = builder.visible(false);
+    } else if config.center {
         builder = builder.center();
     }
 
-    let _window = builder.build().map_err(|e| e.to_string())?;
+    let new_window = builder.build().map_err(|e| e.to_string())?;
+    if let Some(geometry) = geometry {
+        if let Err(e) = apply_geometry(&new_window, geometry, config.resizable) {
+            println!(
+                "⚠️ [WINDOW] 저장된 배치 적용 실패: {} ({})",
+                config.label, e
+            );
+        }
+        new_window.show()?;
+    }
 
     println!(
         "✅ 윈도우 생성: {} (DevTools: {})",
         config.label, config.devtools
     );
-    Ok("Window opened successfully".to_string())
+    Ok(new_window)
 }
 
 /// 개발자 도구 열기 안내 (Tauri v2)
@@ -150,18 +174,17 @@ pub async fn window_exists(app_handle: AppHandle, label: String) -> Result<bool,
 #[tauri::command]
 pub async fn switch_window(
     app_handle: AppHandle,
+    window: tauri::Window,
     from_label: String,
     to_window_type: WindowType,
-) -> Result<String, String> {
-    let to_label = to_window_type.as_str();
-
-    // 🔥 순서 변경: 새 윈도우 먼저 생성
-    open_window(app_handle.clone(), to_window_type).await?;
+) -> Result<String, WindowError> {
+    // 🔥 순서 변경: 새 윈도우 먼저 생성 (권한이 없으면 기존 윈도우도 그대로 둔다)
+    open_window(app_handle.clone(), window, to_window_type.clone()).await?;
 
     // 그 다음 기존 윈도우 닫기
     close_window(app_handle, from_label.clone()).await?;
 
-    println!("🔄 윈도우 전환: {} → {}", from_label, to_label);
+    println!("🔄 윈도우 전환: {} → {}", from_label, to_window_type);
     Ok("Window switched successfully".to_string())
 }
 
@@ -169,18 +192,22 @@ pub async fn switch_window(
 #[tauri::command]
 pub async fn replace_all_windows(
     app_handle: AppHandle,
+    window: tauri::Window,
     window_type: WindowType,
-) -> Result<String, String> {
-    let new_label = window_type.as_str();
-
-    // 🔥 순서 변경: 새 윈도우 먼저 생성
-    open_window(app_handle.clone(), window_type).await?;
+) -> Result<String, WindowError> {
+    // 🔥 순서 변경: 새 윈도우 먼저 생성 (권한이 없으면 기존 윈도우도 그대로 둔다)
+    open_window(app_handle.clone(), window, window_type.clone()).await?;
+    let new_label = app_handle
+        .state::<WindowRegistry>()
+        .get(&window_type)
+        .map(|definition| definition.label.clone())
+        .unwrap_or_default();
 
     // 기존 윈도우들 목록 가져오기 (새로 생성된 윈도우 제외)
     let windows: Vec<String> = app_handle
         .webview_windows()
         .keys()
-        .filter(|&label| label != new_label) // 새 윈도우는 제외
+        .filter(|&label| *label != new_label) // 새 윈도우는 제외
         .map(|s| s.to_string())
         .collect();
 
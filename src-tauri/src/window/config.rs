@@ -1,321 +1,245 @@
-use super::types::WindowType;
+// src-tauri/src/window/config.rs
+//! 윈도우 정의 레지스트리. 앱에 포함된 `windows.json` 이 기준이고,
+//! 앱 데이터 폴더의 `window_overrides.json` 으로 크기 / 제목 같은 표시 설정만 바꿀 수 있다.
+//! 새 윈도우는 `windows.json` 에 항목을 추가하면 된다. 기본 작업 레이아웃도 같은 파일에 둔다.
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
 
-/// 윈도우 설정 구조체
-#[derive(Debug, Clone)]
-pub struct WindowConfig {
-    pub label: String,
-    pub title: String,
-    pub url: String,
-    pub width: f64,
-    pub height: f64,
+use super::layouts::{normalize_layout_name, WorkspaceLayout};
+use super::types::{WindowAccess, WindowDefinition, WindowType};
+use crate::state::rbac::validate_permission;
+
+pub const WINDOW_OVERRIDES_FILE_NAME: &str = "window_overrides.json";
+
+/// 앱에 포함되는 윈도우 정의
+const BUNDLED_WINDOWS: &str = include_str!("../../windows.json");
+
+/// 사용자가 바꿀 수 있는 표시 설정. label / url / 접근 조건 / devtools 는 바꿀 수 없다
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WindowOverride {
+    pub title: Option<String>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
     pub min_width: Option<f64>,
     pub min_height: Option<f64>,
     pub max_width: Option<f64>,
     pub max_height: Option<f64>,
-    pub resizable: bool,
-    pub center: bool,
-    pub always_on_top: bool,
-    pub decorations: bool,
-    pub transparent: bool,
-    pub shadow: bool,
-    pub devtools: bool,
+    pub resizable: Option<bool>,
+    pub center: Option<bool>,
+    pub always_on_top: Option<bool>,
+    pub decorations: Option<bool>,
 }
 
-impl Default for WindowConfig {
-    fn default() -> Self {
-        Self {
-            label: String::new(),
-            title: String::new(),
-            url: String::new(),
-            width: 800.0,
-            height: 600.0,
-            min_width: None,
-            min_height: None,
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: false,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true, // 기본적으로 개발자 도구 활성화
+impl WindowOverride {
+    fn apply(&self, definition: &mut WindowDefinition) {
+        if let Some(title) = &self.title {
+            definition.title = title.clone();
         }
+        definition.width = self.width.unwrap_or(definition.width);
+        definition.height = self.height.unwrap_or(definition.height);
+        definition.min_width = self.min_width.or(definition.min_width);
+        definition.min_height = self.min_height.or(definition.min_height);
+        definition.max_width = self.max_width.or(definition.max_width);
+        definition.max_height = self.max_height.or(definition.max_height);
+        definition.resizable = self.resizable.unwrap_or(definition.resizable);
+        definition.center = self.center.unwrap_or(definition.center);
+        definition.always_on_top = self.always_on_top.unwrap_or(definition.always_on_top);
+        definition.decorations = self.decorations.unwrap_or(definition.decorations);
     }
 }
 
-/// 윈도우 설정 관리자
-pub struct WindowConfigManager;
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WindowOverridesFile {
+    #[serde(default)]
+    windows: BTreeMap<WindowType, WindowOverride>,
+}
+
+/// 윈도우 id → 정의
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WindowRegistry {
+    windows: BTreeMap<WindowType, WindowDefinition>,
+    /// 기본 작업 레이아웃 (이름 → 레이아웃)
+    #[serde(default)]
+    layouts: BTreeMap<String, WorkspaceLayout>,
+}
 
-impl WindowConfigManager {
-    /// 윈도우 타입에 따른 설정 반환
-    pub fn get_config(window_type: WindowType) -> WindowConfig {
-        match window_type {
-            WindowType::Launcher => Self::launcher_config(),
-            WindowType::Login => Self::login_config(),
-            WindowType::CallOutbound => Self::call_outbound_config(),
-            WindowType::CallInbound => Self::call_inbound_config(),
-            WindowType::CallBot => Self::call_bot_config(),
-            WindowType::ChatBot => Self::chat_bot_config(),
-            WindowType::QueueMonitor => Self::queue_monitor_config(),
-            WindowType::Statistics => Self::statistics_config(),
-            WindowType::Settings => Self::settings_config(),
-            WindowType::ShareTaskInfo => Self::share_task_info_config(),
-            WindowType::CompanyChat => Self::company_chat_config(),
-        }
+impl WindowRegistry {
+    /// 앱에 포함된 정의. 잘못되어 있으면 어떤 항목이 왜 잘못됐는지 모두 담은 오류
+    pub fn bundled() -> Result<Self, String> {
+        Self::parse(BUNDLED_WINDOWS).map_err(|e| format!("windows.json 윈도우 정의 오류: {}", e))
     }
 
-    /// 개발/프로덕션 환경에 따른 URL 생성
-    fn get_url(path: &str) -> String {
-        if cfg!(dev) {
-            format!("http://localhost:1420{}", path)
-        } else {
-            if path == "/" {
-                "index.html".to_string()
-            } else {
-                format!("index.html#{}", path)
-            }
-        }
+    /// JSON 파싱 + 검증
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let registry: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        registry.validate()?;
+        Ok(registry)
     }
 
-    /// 런처 윈도우 설정
-    fn launcher_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::Launcher.as_str().to_string(),
-            title: WindowType::Launcher.title().to_string(),
-            url: WindowType::Launcher.url(),
-            width: 400.0,
-            height: 600.0,
-            min_width: Some(380.0),
-            min_height: Some(500.0),
-            max_width: Some(450.0),
-            max_height: Some(700.0),
-            resizable: true,
-            center: true,
-            always_on_top: false,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
+    /// 앱 시작 시 1회. 포함된 정의가 잘못되어 있으면 앱을 시작하지 않는다.
+    /// 사용자 설정 파일이 잘못되어 있으면 무시하고 포함된 정의만 사용
+    pub fn load(overrides_path: PathBuf) -> Result<Self, String> {
+        let bundled = Self::bundled()?;
+        let Ok(bytes) = fs::read(&overrides_path) else {
+            println!("🪟 [WINDOW] 윈도우 정의 로드: {}개", bundled.windows.len());
+            return Ok(bundled);
+        };
+        let merged = serde_json::from_slice::<WindowOverridesFile>(&bytes)
+            .map_err(|e| e.to_string())
+            .and_then(|file| bundled.with_overrides(&file.windows));
+        match merged {
+            Ok(registry) => {
+                println!(
+                    "🪟 [WINDOW] 윈도우 정의 로드: {}개 (사용자 설정 적용)",
+                    registry.windows.len()
+                );
+                Ok(registry)
+            }
+            Err(e) => {
+                println!("⚠️ [WINDOW] 윈도우 사용자 설정 오류, 무시: {}", e);
+                Ok(bundled)
+            }
         }
     }
 
-    /// 로그인 윈도우 설정
-    fn login_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::Login.as_str().to_string(),
-            title: WindowType::Login.title().to_string(),
-            url: WindowType::Login.url(),
-            width: 450.0,
-            height: 500.0,
-            min_width: Some(400.0),
-            min_height: Some(450.0),
-            max_width: None,
-            max_height: None,
-            resizable: false,
-            center: true,
-            always_on_top: true,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
+    /// 사용자 설정을 적용한 새 레지스트리 (적용 결과도 검증)
+    pub fn with_overrides(
+        &self,
+        overrides: &BTreeMap<WindowType, WindowOverride>,
+    ) -> Result<Self, String> {
+        let mut merged = self.clone();
+        for (id, window_override) in overrides {
+            let definition = merged
+                .windows
+                .get_mut(id)
+                .ok_or_else(|| format!("없는 윈도우 '{}' 의 설정입니다", id))?;
+            window_override.apply(definition);
         }
+        merged.validate()?;
+        Ok(merged)
     }
 
-    /// 발신 통화 윈도우 설정
-    fn call_outbound_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::CallOutbound.as_str().to_string(),
-            title: WindowType::CallOutbound.title().to_string(),
-            url: WindowType::CallOutbound.url(),
-            width: 350.0,
-            height: 500.0,
-            min_width: Some(320.0),
-            min_height: Some(450.0),
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: true,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
+    /// 모든 항목의 오류를 한 번에 보여준다
+    pub fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+        let mut labels = BTreeSet::new();
+        if self.windows.is_empty() {
+            errors.push("윈도우가 하나도 없습니다".to_string());
+        }
+        for (id, definition) in &self.windows {
+            if !id.as_str().chars().all(|c| c.is_ascii_alphanumeric()) || id.as_str().is_empty() {
+                errors.push(format!("'{}': id 는 영문 / 숫자만 쓸 수 있습니다", id));
+            }
+            for problem in definition_problems(definition) {
+                errors.push(format!("'{}': {}", id, problem));
+            }
+            if !labels.insert(definition.label.as_str()) {
+                errors.push(format!(
+                    "'{}': label '{}' 이 다른 윈도우와 겹칩니다",
+                    id, definition.label
+                ));
+            }
+        }
+        for (name, layout) in &self.layouts {
+            let problem = normalize_layout_name(name)
+                .and_then(|normalized| match normalized == *name {
+                    true => Ok(()),
+                    false => Err("이름 앞뒤에 공백이 있습니다".to_string()),
+                })
+                .and_then(|_| layout.validate(self));
+            if let Err(e) = problem {
+                errors.push(format!("레이아웃 '{}': {}", name, e));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
         }
     }
 
-    /// 수신 통화 윈도우 설정
-    fn call_inbound_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::CallInbound.as_str().to_string(),
-            title: WindowType::CallInbound.title().to_string(),
-            url: WindowType::CallInbound.url(),
-            width: 350.0,
-            height: 400.0,
-            min_width: Some(320.0),
-            min_height: Some(350.0),
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: true,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
-        }
+    pub fn get(&self, window_type: &WindowType) -> Option<&WindowDefinition> {
+        self.windows.get(window_type)
     }
 
-    /// 통계 윈도우 설정
-    fn statistics_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::Statistics.as_str().to_string(),
-            title: WindowType::Statistics.title().to_string(),
-            url: WindowType::Statistics.url(),
-            width: 1000.0,
-            height: 700.0,
-            min_width: Some(800.0),
-            min_height: Some(600.0),
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: false,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
-        }
+    /// label 로 윈도우 정의 찾기
+    pub fn find_by_label(&self, label: &str) -> Option<(&WindowType, &WindowDefinition)> {
+        self.windows
+            .iter()
+            .find(|(_, definition)| definition.label == label)
     }
 
-    /// 설정 윈도우 설정
-    fn settings_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::Settings.as_str().to_string(),
-            title: WindowType::Settings.title().to_string(),
-            url: WindowType::Settings.url(),
-            width: 600.0,
-            height: 500.0,
-            min_width: Some(500.0),
-            min_height: Some(400.0),
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: false,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
-        }
+    pub fn layouts(&self) -> impl Iterator<Item = (&String, &WorkspaceLayout)> {
+        self.layouts.iter()
     }
+}
 
-    /// 콜봇 윈도우 설정
-    fn call_bot_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::CallBot.as_str().to_string(),
-            title: WindowType::CallBot.title().to_string(),
-            url: WindowType::CallBot.url(),
-            width: 1100.0,
-            height: 800.0,
-            min_width: Some(900.0),
-            min_height: Some(600.0),
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: false,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
-        }
+fn definition_problems(definition: &WindowDefinition) -> Vec<String> {
+    let mut problems = Vec::new();
+    let label_ok = !definition.label.is_empty()
+        && definition
+            .label
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'));
+    if !label_ok {
+        problems.push(format!(
+            "label '{}' 은 영문 / 숫자 / '_' / '-' 만 쓸 수 있습니다",
+            definition.label
+        ));
+    }
+    if definition.title.trim().is_empty() {
+        problems.push("title 이 비어 있습니다".to_string());
+    }
+    if !definition.url.ends_with(".html")
+        || definition.url.contains("..")
+        || definition.url.contains("://")
+    {
+        problems.push(format!(
+            "url '{}' 은 앱에 포함된 .html 파일이어야 합니다",
+            definition.url
+        ));
     }
 
-    /// 챗봇 윈도우 설정
-    fn chat_bot_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::ChatBot.as_str().to_string(),
-            title: WindowType::ChatBot.title().to_string(),
-            url: WindowType::ChatBot.url(),
-            width: 1200.0,
-            height: 900.0,
-            min_width: Some(1000.0),
-            min_height: Some(700.0),
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: false,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
+    let size = (definition.width, definition.height);
+    if !(size.0 > 0.0 && size.1 > 0.0) {
+        problems.push(format!("크기 {}x{} 가 올바르지 않습니다", size.0, size.1));
+    }
+    let pairs = [
+        ("min", definition.min_width, definition.min_height),
+        ("max", definition.max_width, definition.max_height),
+    ];
+    for (name, width, height) in pairs {
+        if width.is_some() != height.is_some() {
+            problems.push(format!(
+                "{name}_width 와 {name}_height 는 함께 지정해야 합니다"
+            ));
         }
     }
-    /// 큐 모니터 윈도우 설정
-    fn queue_monitor_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::QueueMonitor.as_str().to_string(),
-            title: WindowType::QueueMonitor.title().to_string(),
-            url: WindowType::QueueMonitor.url(),
-            width: 1000.0,
-            height: 700.0,
-            min_width: Some(800.0),
-            min_height: Some(500.0),
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: false,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
+    if let Some((min_width, min_height)) = definition.min_size() {
+        if min_width > size.0 || min_height > size.1 {
+            problems.push(format!(
+                "최소 크기 {}x{} 가 기본 크기 {}x{} 보다 큽니다",
+                min_width, min_height, size.0, size.1
+            ));
         }
     }
-
-    /// 공유 작업 정보 윈도우 설정
-    fn share_task_info_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::ShareTaskInfo.as_str().to_string(),
-            title: WindowType::ShareTaskInfo.title().to_string(),
-            url: WindowType::ShareTaskInfo.url(),
-            width: 1920.0,
-            height: 1080.0,
-            min_width: Some(1280.0),
-            min_height: Some(800.0),
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: false,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
+    if let Some((max_width, max_height)) = definition.max_size() {
+        if max_width < size.0 || max_height < size.1 {
+            problems.push(format!(
+                "최대 크기 {}x{} 가 기본 크기 {}x{} 보다 작습니다",
+                max_width, max_height, size.0, size.1
+            ));
         }
     }
-
-    /// Company Chat 윈도우 설정
-    fn company_chat_config() -> WindowConfig {
-        WindowConfig {
-            label: WindowType::CompanyChat.as_str().to_string(),
-            title: WindowType::CompanyChat.title().to_string(),
-            url: WindowType::CompanyChat.url(),
-            width: 1200.0,
-            height: 800.0,
-            min_width: Some(900.0),
-            min_height: Some(600.0),
-            max_width: None,
-            max_height: None,
-            resizable: true,
-            center: true,
-            always_on_top: false,
-            decorations: true,
-            transparent: false,
-            shadow: true,
-            devtools: true,
+    if let WindowAccess::Permission(permission) = &definition.access {
+        if let Err(e) = validate_permission(permission) {
+            problems.push(format!("권한 '{}': {}", permission, e));
         }
     }
+    problems
 }
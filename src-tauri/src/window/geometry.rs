@@ -0,0 +1,278 @@
+// src-tauri/src/window/geometry.rs
+//! 윈도우 위치 / 크기 기억. 사용자별 + 윈도우 label 별로 이동 / 리사이즈 / 닫기 때 기록하고
+//! `open_window` 에서 되살린다. 저장된 모니터가 없어졌으면 보이는 모니터 안으로 옮긴다.
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, WebviewWindow, WindowEvent};
+
+use super::WindowState;
+use crate::state::auth_state::AuthState;
+use crate::state::fs_util::write_atomic;
+
+pub const WINDOW_GEOMETRY_FILE_NAME: &str = "window_geometry.json";
+
+/// 물리 픽셀 사각형
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    fn intersection_area(&self, other: &Rect) -> u64 {
+        let left = self.x.max(other.x) as i64;
+        let top = self.y.max(other.y) as i64;
+        let right = (self.x as i64 + self.width as i64).min(other.x as i64 + other.width as i64);
+        let bottom = (self.y as i64 + self.height as i64).min(other.y as i64 + other.height as i64);
+        ((right - left).max(0) * (bottom - top).max(0)) as u64
+    }
+
+    /// `area` 안에 완전히 들어오도록 크기를 줄이고 위치를 옮긴다
+    fn clamp_into(&self, area: &Rect) -> Rect {
+        let width = self.width.min(area.width);
+        let height = self.height.min(area.height);
+        Rect {
+            x: self.x.clamp(area.x, area.x + (area.width - width) as i32),
+            y: self.y.clamp(area.y, area.y + (area.height - height) as i32),
+            width,
+            height,
+        }
+    }
+}
+
+/// 윈도우 하나의 배치
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    /// 최대화 전 위치 / 크기 (바깥 위치 + 안쪽 크기, 물리 픽셀)
+    pub bounds: Rect,
+    #[serde(default)]
+    pub maximized: bool,
+    /// 마지막으로 있던 모니터 이름
+    #[serde(default)]
+    pub monitor: Option<String>,
+}
+
+/// 모니터 작업 영역 (작업 표시줄 제외)
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorArea {
+    pub name: Option<String>,
+    pub work_area: Rect,
+}
+
+/// 보이는 위치로 보정. 저장된 모니터가 있으면 그 모니터, 없으면 가장 많이 겹치는 모니터,
+/// 하나도 안 겹치면 첫 번째(주) 모니터 안으로 옮긴다. 모니터 정보가 없으면 그대로
+pub fn clamp_to_monitors(geometry: &WindowGeometry, monitors: &[MonitorArea]) -> WindowGeometry {
+    let by_name = geometry.monitor.as_ref().and_then(|name| {
+        monitors
+            .iter()
+            .find(|monitor| monitor.name.as_ref() == Some(name))
+    });
+    let by_overlap = || {
+        monitors
+            .iter()
+            .map(|monitor| {
+                (
+                    geometry.bounds.intersection_area(&monitor.work_area),
+                    monitor,
+                )
+            })
+            .filter(|(area, _)| *area > 0)
+            .max_by_key(|(area, _)| *area)
+            .map(|(_, monitor)| monitor)
+    };
+    let Some(target) = by_name.or_else(by_overlap).or(monitors.first()) else {
+        return geometry.clone();
+    };
+
+    WindowGeometry {
+        bounds: geometry.bounds.clamp_into(&target.work_area),
+        maximized: geometry.maximized,
+        monitor: target.name.clone(),
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct GeometryFile {
+    /// 사용자 id → 윈도우 label → 배치
+    #[serde(default)]
+    users: BTreeMap<String, BTreeMap<String, WindowGeometry>>,
+}
+
+/// 윈도우 배치 저장소. 이동 / 리사이즈는 메모리에만 기록하고 닫을 때 파일에 쓴다
+#[derive(Debug, Default)]
+pub struct GeometryStore {
+    file: GeometryFile,
+    path: Option<PathBuf>,
+    dirty: bool,
+}
+
+impl GeometryStore {
+    /// 파일 로드. 없거나 깨졌으면 빈 저장소
+    pub fn load(path: PathBuf) -> Self {
+        let file = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                println!("⚠️ [WINDOW] 윈도우 배치 파일 파싱 실패: {}", e);
+                GeometryFile::default()
+            }),
+            Err(_) => GeometryFile::default(),
+        };
+        Self {
+            file,
+            path: Some(path),
+            dirty: false,
+        }
+    }
+
+    pub fn get(&self, user_id: &str, label: &str) -> Option<&WindowGeometry> {
+        self.file.users.get(user_id)?.get(label)
+    }
+
+    /// 관측한 배치 기록. 최대화 상태에서는 최대화 전 위치 / 크기를 유지한다
+    pub fn record(&mut self, user_id: &str, label: &str, observed: WindowGeometry) {
+        let windows = self.file.users.entry(user_id.to_string()).or_default();
+        match windows.get_mut(label) {
+            Some(previous) if observed.maximized => {
+                previous.maximized = true;
+                previous.monitor = observed.monitor;
+            }
+            Some(previous) if *previous == observed => return,
+            _ => {
+                windows.insert(label.to_string(), observed);
+            }
+        }
+        self.dirty = true;
+    }
+
+    /// 바뀐 내용이 있으면 파일에 저장
+    pub fn flush(&mut self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if !self.dirty {
+            return Ok(());
+        }
+        let bytes = serde_json::to_vec_pretty(&self.file).map_err(|e| e.to_string())?;
+        write_atomic(path, &bytes)?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+// === Tauri 연동 ===
+
+/// 현재 윈도우 배치. 최소화 중이면 위치가 의미 없으므로 None
+pub fn observe(window: &tauri::Window) -> Option<WindowGeometry> {
+    if window.is_minimized().unwrap_or(false) {
+        return None;
+    }
+    let position = window.outer_position().ok()?;
+    let size = window.inner_size().ok()?;
+    Some(WindowGeometry {
+        bounds: Rect {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+        },
+        maximized: window.is_maximized().unwrap_or(false),
+        monitor: window
+            .current_monitor()
+            .ok()
+            .flatten()
+            .and_then(|monitor| monitor.name().cloned()),
+    })
+}
+
+/// 연결된 모니터 작업 영역 (주 모니터가 먼저)
+pub fn monitor_areas(app: &AppHandle) -> Vec<MonitorArea> {
+    let primary = app.primary_monitor().ok().flatten();
+    let mut monitors = app.available_monitors().unwrap_or_default();
+    if let Some(primary) = &primary {
+        monitors.sort_by_key(|monitor| monitor.name() != primary.name());
+    }
+    monitors
+        .iter()
+        .map(|monitor| {
+            let area = monitor.work_area();
+            MonitorArea {
+                name: monitor.name().cloned(),
+                work_area: Rect {
+                    x: area.position.x,
+                    y: area.position.y,
+                    width: area.size.width,
+                    height: area.size.height,
+                },
+            }
+        })
+        .collect()
+}
+
+/// 현재 사용자가 이 윈도우에 저장해 둔 배치 (보이는 위치로 보정됨)
+pub fn saved_geometry(app: &AppHandle, label: &str) -> Option<WindowGeometry> {
+    let user_id = app.state::<AuthState>().actor_id()?;
+    let saved = app
+        .state::<WindowState>()
+        .geometry
+        .lock()
+        .unwrap()
+        .get(&user_id, label)
+        .cloned()?;
+    Some(clamp_to_monitors(&saved, &monitor_areas(app)))
+}
+
+/// 저장된 배치 적용. 크기 고정 윈도우는 위치만 되살린다
+pub fn apply_geometry(
+    window: &WebviewWindow,
+    geometry: &WindowGeometry,
+    resizable: bool,
+) -> Result<(), String> {
+    let bounds = geometry.bounds;
+    // 이미 열려 있던 윈도우면 최대화를 풀어야 위치 / 크기가 적용된다
+    if !geometry.maximized && window.is_maximized().unwrap_or(false) {
+        window.unmaximize().map_err(|e| e.to_string())?;
+    }
+    if resizable {
+        window
+            .set_size(tauri::PhysicalSize::new(bounds.width, bounds.height))
+            .map_err(|e| e.to_string())?;
+    }
+    window
+        .set_position(tauri::PhysicalPosition::new(bounds.x, bounds.y))
+        .map_err(|e| e.to_string())?;
+    if geometry.maximized && resizable {
+        window.maximize().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// 모든 윈도우 이벤트 (`Builder::on_window_event`). 이동 / 리사이즈는 기록, 닫기는 기록 후 저장
+pub fn handle_window_event(window: &tauri::Window, event: &WindowEvent) {
+    let (record, flush) = match event {
+        WindowEvent::Moved(_) | WindowEvent::Resized(_) => (true, false),
+        WindowEvent::CloseRequested { .. } => (true, true),
+        WindowEvent::Destroyed => (false, true),
+        _ => return,
+    };
+
+    let app = window.app_handle();
+    let windows = app.state::<WindowState>();
+    if record {
+        let user_id = app.state::<AuthState>().actor_id();
+        if let Some((user_id, observed)) = user_id.zip(observe(window)) {
+            windows
+                .geometry
+                .lock()
+                .unwrap()
+                .record(&user_id, window.label(), observed);
+        }
+    }
+    if flush {
+        if let Err(e) = windows.geometry.lock().unwrap().flush() {
+            println!("⚠️ [WINDOW] 윈도우 배치 저장 실패: {}", e);
+        }
+    }
+}
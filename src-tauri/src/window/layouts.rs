@@ -0,0 +1,347 @@
+// src-tauri/src/window/layouts.rs
+//! 작업 레이아웃. 여러 윈도우를 정해진 배치로 한 번에 연다.
+//! 기본 레이아웃은 `windows.json` 의 `layouts`, 사용자가 저장한 레이아웃은 사용자별로 `window_layouts.json` 에 둔다.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+use super::access::{authorize, ensure_window_access, WindowError};
+use super::commands::build_window;
+use super::config::WindowRegistry;
+use super::geometry::WindowGeometry;
+use super::geometry::{apply_geometry, clamp_to_monitors, monitor_areas, observe, saved_geometry};
+use super::types::{WindowAccess, WindowType};
+use super::WindowState;
+use crate::state::auth_state::AuthState;
+use crate::state::fs_util::write_atomic;
+
+pub const WINDOW_LAYOUTS_FILE_NAME: &str = "window_layouts.json";
+
+/// 레이아웃 이름 최대 길이 (글자 수)
+pub const MAX_LAYOUT_NAME_CHARS: usize = 40;
+/// 사용자 한 명이 저장할 수 있는 레이아웃 수
+pub const MAX_LAYOUTS_PER_USER: usize = 20;
+
+/// 레이아웃 안의 윈도우 하나
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LayoutWindow {
+    pub window: WindowType,
+    /// 없으면 사용자가 마지막으로 둔 위치 (그것도 없으면 중앙)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<WindowGeometry>,
+    #[serde(default)]
+    pub fullscreen: bool,
+}
+
+/// 이름 붙인 윈도우 묶음
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceLayout {
+    pub windows: Vec<LayoutWindow>,
+    /// 사용자가 저장한 시각 (기본 레이아웃은 없음)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saved_at: Option<DateTime<Utc>>,
+}
+
+impl WorkspaceLayout {
+    /// 윈도우가 있고, 모두 정의에 있고, 겹치지 않는지
+    pub fn validate(&self, registry: &WindowRegistry) -> Result<(), String> {
+        if self.windows.is_empty() {
+            return Err("윈도우가 하나도 없습니다".to_string());
+        }
+        let mut seen = BTreeSet::new();
+        for entry in &self.windows {
+            if registry.get(&entry.window).is_none() {
+                return Err(format!("없는 윈도우 '{}' 가 있습니다", entry.window));
+            }
+            if !seen.insert(&entry.window) {
+                return Err(format!("윈도우 '{}' 가 두 번 들어 있습니다", entry.window));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 레이아웃 이름 정리 + 검사
+pub fn normalize_layout_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("레이아웃 이름을 입력해주세요".to_string());
+    }
+    if name.chars().count() > MAX_LAYOUT_NAME_CHARS {
+        return Err(format!(
+            "레이아웃 이름은 {}자 이하여야 합니다",
+            MAX_LAYOUT_NAME_CHARS
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// 사용자 레이아웃 이름 검사. 이름 규칙에 더해 기본 레이아웃 이름과 겹치면 거부한다
+pub fn validate_saved_layout_name(name: &str, registry: &WindowRegistry) -> Result<String, String> {
+    let name = normalize_layout_name(name)?;
+    if registry.layouts().any(|(builtin, _)| *builtin == name) {
+        return Err(format!("'{}' 은 기본 레이아웃 이름입니다", name));
+    }
+    Ok(name)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct LayoutFile {
+    /// 사용자 id → 이름 → 레이아웃
+    #[serde(default)]
+    users: BTreeMap<String, BTreeMap<String, WorkspaceLayout>>,
+}
+
+/// 사용자가 저장한 레이아웃
+#[derive(Debug, Default)]
+pub struct LayoutStore {
+    file: LayoutFile,
+    path: Option<PathBuf>,
+}
+
+impl LayoutStore {
+    /// 파일 로드. 없거나 깨졌으면 빈 저장소.
+    /// 윈도우 정의가 바뀌었을 수 있으므로 지금 정의로 다시 검사해서 맞지 않는 레이아웃은 뺀다
+    pub fn load(path: PathBuf, registry: &WindowRegistry) -> Self {
+        let mut file: LayoutFile = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                println!("⚠️ [WINDOW] 레이아웃 파일 파싱 실패: {}", e);
+                LayoutFile::default()
+            }),
+            Err(_) => LayoutFile::default(),
+        };
+        for (user_id, layouts) in &mut file.users {
+            layouts.retain(|name, layout| {
+                let checked = validate_saved_layout_name(name, registry)
+                    .and_then(|_| layout.validate(registry));
+                if let Err(e) = &checked {
+                    println!("⚠️ [WINDOW] 레이아웃 제외: {} / {} ({})", user_id, name, e);
+                }
+                checked.is_ok()
+            });
+        }
+        file.users.retain(|_, layouts| !layouts.is_empty());
+        Self {
+            file,
+            path: Some(path),
+        }
+    }
+
+    pub fn get(&self, user_id: &str, name: &str) -> Option<&WorkspaceLayout> {
+        self.file.users.get(user_id)?.get(name)
+    }
+
+    pub fn user_layouts(&self, user_id: &str) -> impl Iterator<Item = (&String, &WorkspaceLayout)> {
+        self.file.users.get(user_id).into_iter().flatten()
+    }
+
+    /// 저장 (같은 이름이면 덮어쓴다)
+    pub fn save(
+        &mut self,
+        user_id: &str,
+        name: &str,
+        layout: WorkspaceLayout,
+    ) -> Result<(), String> {
+        let layouts = self.file.users.entry(user_id.to_string()).or_default();
+        if !layouts.contains_key(name) && layouts.len() >= MAX_LAYOUTS_PER_USER {
+            return Err(format!(
+                "레이아웃은 {}개까지 저장할 수 있습니다",
+                MAX_LAYOUTS_PER_USER
+            ));
+        }
+        layouts.insert(name.to_string(), layout);
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let bytes = serde_json::to_vec_pretty(&self.file).map_err(|e| e.to_string())?;
+        write_atomic(path, &bytes)
+    }
+}
+
+// === Tauri 연동 ===
+
+/// `list_layouts` 항목
+#[derive(Debug, Clone, Serialize)]
+pub struct LayoutSummary {
+    pub name: String,
+    /// `windows.json` 의 기본 레이아웃
+    pub builtin: bool,
+    pub windows: Vec<WindowType>,
+    /// 현재 사용자가 열 수 없는 윈도우 (하나라도 있으면 적용할 수 없다)
+    pub denied: Vec<WindowType>,
+    pub saved_at: Option<DateTime<Utc>>,
+}
+
+fn summarize(
+    auth: &AuthState,
+    registry: &WindowRegistry,
+    name: &str,
+    builtin: bool,
+    layout: &WorkspaceLayout,
+) -> LayoutSummary {
+    let denied = layout
+        .windows
+        .iter()
+        .filter(|entry| {
+            registry
+                .get(&entry.window)
+                .is_none_or(|definition| authorize(auth, &entry.window, definition).is_err())
+        })
+        .map(|entry| entry.window.clone())
+        .collect();
+    LayoutSummary {
+        name: name.to_string(),
+        builtin,
+        windows: layout
+            .windows
+            .iter()
+            .map(|entry| entry.window.clone())
+            .collect(),
+        denied,
+        saved_at: layout.saved_at,
+    }
+}
+
+fn current_user_id(auth: &AuthState) -> Result<String, String> {
+    if !auth.is_session_valid(Utc::now()) {
+        return Err("로그인이 필요합니다".to_string());
+    }
+    auth.actor_id()
+        .ok_or_else(|| "로그인이 필요합니다".to_string())
+}
+
+/// 기본 레이아웃 + 내 레이아웃. 윈도우마다 권한을 확인해서 열 수 없는 윈도우를 `denied` 로 알려준다
+#[tauri::command]
+pub fn list_layouts(app_handle: AppHandle) -> Result<Vec<LayoutSummary>, String> {
+    let auth = app_handle.state::<AuthState>();
+    let user_id = current_user_id(&auth)?;
+    let registry = app_handle.state::<WindowRegistry>();
+    let windows = app_handle.state::<WindowState>();
+    let store = windows.layouts.lock().unwrap();
+
+    let builtin = registry
+        .layouts()
+        .map(|(name, layout)| summarize(&auth, &registry, name, true, layout));
+    let saved = store
+        .user_layouts(&user_id)
+        .map(|(name, layout)| summarize(&auth, &registry, name, false, layout));
+    Ok(builtin.chain(saved).collect())
+}
+
+/// 지금 열려 있는 윈도우 배치를 내 레이아웃으로 저장. 로그인 화면 같은 공개 윈도우는 빠진다
+#[tauri::command]
+pub fn save_layout(app_handle: AppHandle, name: String) -> Result<LayoutSummary, String> {
+    let auth = app_handle.state::<AuthState>();
+    let user_id = current_user_id(&auth)?;
+    let registry = app_handle.state::<WindowRegistry>();
+    let name = validate_saved_layout_name(&name, &registry)?;
+
+    let mut windows: Vec<LayoutWindow> = app_handle
+        .webview_windows()
+        .into_iter()
+        .filter_map(|(label, webview_window)| {
+            let (window_type, definition) = registry.find_by_label(&label)?;
+            if *definition.required_access() == WindowAccess::Public {
+                return None;
+            }
+            let window = webview_window.as_ref().window();
+            Some(LayoutWindow {
+                window: window_type.clone(),
+                geometry: observe(&window),
+                fullscreen: window.is_fullscreen().unwrap_or(false),
+            })
+        })
+        .collect();
+    windows.sort_by(|a, b| a.window.cmp(&b.window));
+    let layout = WorkspaceLayout {
+        windows,
+        saved_at: Some(Utc::now()),
+    };
+    layout
+        .validate(&registry)
+        .map_err(|_| "저장할 윈도우가 없습니다".to_string())?;
+
+    let summary = summarize(&auth, &registry, &name, false, &layout);
+    app_handle
+        .state::<WindowState>()
+        .layouts
+        .lock()
+        .unwrap()
+        .save(&user_id, &name, layout)?;
+    println!(
+        "💾 [WINDOW] 레이아웃 저장: {} ({}개 윈도우)",
+        name,
+        summary.windows.len()
+    );
+    Ok(summary)
+}
+
+/// 레이아웃 적용. 윈도우 하나라도 권한이 없으면 아무것도 열지 않는다.
+/// 이미 열려 있는 윈도우는 레이아웃 위치로 옮기고, 레이아웃에 없는 윈도우는 그대로 둔다
+#[tauri::command]
+pub async fn apply_layout(
+    app_handle: AppHandle,
+    window: tauri::Window,
+    name: String,
+) -> Result<Vec<String>, WindowError> {
+    let user_id = current_user_id(&app_handle.state::<AuthState>())?;
+    let layout = {
+        let registry = app_handle.state::<WindowRegistry>();
+        let builtin = registry.layouts().find(|(builtin, _)| **builtin == name);
+        match builtin {
+            Some((_, layout)) => layout.clone(),
+            None => app_handle
+                .state::<WindowState>()
+                .layouts
+                .lock()
+                .unwrap()
+                .get(&user_id, &name)
+                .cloned()
+                .ok_or_else(|| format!("레이아웃 '{}' 이 없습니다", name))?,
+        }
+    };
+
+    // 먼저 전부 권한 확인 (거부되면 감사 기록 후 중단)
+    let mut definitions = Vec::with_capacity(layout.windows.len());
+    for entry in &layout.windows {
+        definitions.push(ensure_window_access(
+            &app_handle,
+            &entry.window,
+            Some(window.label()),
+        )?);
+    }
+
+    let monitors = monitor_areas(&app_handle);
+    let mut opened = Vec::with_capacity(definitions.len());
+    for (entry, definition) in layout.windows.iter().zip(definitions) {
+        let geometry = entry
+            .geometry
+            .as_ref()
+            .map(|geometry| clamp_to_monitors(geometry, &monitors))
+            .or_else(|| saved_geometry(&app_handle, &definition.label));
+        let target = match app_handle.get_webview_window(&definition.label) {
+            Some(existing) => {
+                if let Some(geometry) = &geometry {
+                    apply_geometry(&existing, geometry, definition.resizable)?;
+                }
+                existing.show()?;
+                existing
+            }
+            None => build_window(&app_handle, &definition, geometry.as_ref())?,
+        };
+        target.set_fullscreen(entry.fullscreen)?;
+        opened.push(definition.label);
+    }
+
+    println!("🧩 [WINDOW] 레이아웃 적용: {} → {:?}", name, opened);
+    Ok(opened)
+}
@@ -1,82 +1,34 @@
 // src-tauri/src/window/mod.rs
+pub mod access;
 pub mod commands;
 pub mod config;
+pub mod geometry;
+pub mod layouts;
 pub mod types;
 
-use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum WindowType {
-    Login,
-    Launcher,
-    CallInbound,
-    CallOutbound,
-    CallBot,
-    ChatBot,
-    QueueMonitor,
-    Statistics,
-    Settings,
-}
-
-impl WindowType {
-    pub fn to_label(&self) -> &'static str {
-        match self {
-            WindowType::Login => "login",
-            WindowType::Launcher => "launcher",
-            WindowType::CallInbound => "call_inbound",
-            WindowType::CallOutbound => "call_outbound",
-            WindowType::CallBot => "call_bot",
-            WindowType::ChatBot => "chat_bot",
-            WindowType::QueueMonitor => "queue_monitor",
-            WindowType::Statistics => "statistics",
-            WindowType::Settings => "settings",
-        }
-    }
+use config::WindowRegistry;
+use geometry::GeometryStore;
+use layouts::LayoutStore;
 
-    pub fn title(&self) -> &'static str {
-        match self {
-            WindowType::Login => "Nexus Call Hub - 로그인",
-            WindowType::Launcher => "Nexus Call Hub - 런처",
-            WindowType::CallInbound => "Nexus Call Hub - 인바운드 상담",
-            WindowType::CallOutbound => "Nexus Call Hub - 아웃바운드 영업",
-            WindowType::CallBot => "Nexus Call Hub - AI 콜봇",
-            WindowType::ChatBot => "Nexus Call Hub - 챗봇 테스트",
-            WindowType::QueueMonitor => "Nexus Call Hub - 대기열 모니터",
-            WindowType::Statistics => "Nexus Call Hub - 통계",
-            WindowType::Settings => "Nexus Call Hub - 설정",
-        }
-    }
+/// 윈도우 관련 상태 (`app.manage` 로 등록)
+#[derive(Default)]
+pub struct WindowState {
+    /// 사용자별 윈도우 위치 / 크기
+    pub geometry: Mutex<GeometryStore>,
+    /// 사용자가 저장한 작업 레이아웃
+    pub layouts: Mutex<LayoutStore>,
+}
 
-    pub fn url(&self) -> &'static str {
-        match self {
-            WindowType::Login => "login.html",
-            WindowType::Launcher => "launcher.html",
-            WindowType::CallInbound => "call_inbound.html",
-            WindowType::CallOutbound => "call_outbound.html",
-            WindowType::CallBot => "call_bot.html",
-            WindowType::ChatBot => "chat_bot.html",
-            WindowType::QueueMonitor => "queue_monitor.html",
-            WindowType::Statistics => "statistics.html",
-            WindowType::Settings => "settings.html",
-        }
+impl WindowState {
+    /// 윈도우 배치 로드 (앱 시작 시 1회)
+    pub fn load_geometry(&self, path: std::path::PathBuf) {
+        *self.geometry.lock().unwrap() = GeometryStore::load(path);
     }
 
-    pub fn default_size(&self) -> (f64, f64) {
-        match self {
-            WindowType::Login => (400.0, 600.0),
-            WindowType::Launcher => (800.0, 600.0),
-            WindowType::CallInbound => (1200.0, 800.0),
-            WindowType::CallOutbound => (1200.0, 800.0),
-            WindowType::CallBot => (1000.0, 700.0),
-            WindowType::ChatBot => (1400.0, 900.0),
-            WindowType::QueueMonitor => (1000.0, 600.0),
-            WindowType::Statistics => (1200.0, 800.0),
-            WindowType::Settings => (800.0, 600.0),
-        }
+    /// 저장된 작업 레이아웃 로드 (앱 시작 시 1회, 윈도우 정의 로드 후)
+    pub fn load_layouts(&self, path: std::path::PathBuf, registry: &WindowRegistry) {
+        *self.layouts.lock().unwrap() = LayoutStore::load(path, registry);
     }
-
-    // 🔥 사용되지 않는 함수들 제거
-    // pub fn min_size(&self) -> Option<(f64, f64)> { ... }
-    // pub fn is_resizable(&self) -> bool { ... }
-    // pub fn default_position_offset(&self) -> Option<(i32, i32)> { ... }
 }
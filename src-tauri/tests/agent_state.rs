@@ -0,0 +1,68 @@
+// 상담원 상태 전이표: 허용 전이 전체 목록, 호출 / 통화 중 오프라인 전환
+mod common;
+
+use common::now;
+use nexus_call_hub_lib::state::agent_state::{AgentState, AgentStateMachine, AgentTransitionError};
+
+#[test]
+fn transition_table() {
+    use AgentState::*;
+    let allowed = [
+        (Offline, Available),
+        (Available, Ringing),
+        (Available, Break),
+        (Available, Offline),
+        (Ringing, Busy),
+        (Ringing, Available),
+        (Ringing, Offline),
+        (Busy, WrapUp),
+        (Busy, Offline),
+        (WrapUp, Available),
+        (WrapUp, Break),
+        (WrapUp, Offline),
+        (Break, Available),
+        (Break, Offline),
+    ];
+    for from in AgentState::ALL {
+        for to in AgentState::ALL {
+            assert_eq!(
+                from.can_transition_to(to),
+                allowed.contains(&(from, to)),
+                "{} → {}",
+                from,
+                to
+            );
+        }
+    }
+}
+
+#[test]
+fn ringing_or_busy_agents_can_go_offline() {
+    for path in [
+        [AgentState::Available, AgentState::Ringing].as_slice(),
+        [AgentState::Available, AgentState::Ringing, AgentState::Busy].as_slice(),
+    ] {
+        let mut machine = AgentStateMachine::new(now());
+        for &state in path {
+            assert_eq!(machine.transition("1", state, None, now()), Ok(true));
+        }
+        let from = machine.status;
+        assert_eq!(
+            machine.transition("1", AgentState::Offline, None, now()),
+            Ok(true)
+        );
+        assert_eq!(machine.status, AgentState::Offline);
+        assert_eq!(machine.previous_status, Some(from));
+    }
+
+    // 오프라인에서 바로 통화로 돌아올 수는 없다
+    let mut machine = AgentStateMachine::new(now());
+    assert_eq!(
+        machine.transition("1", AgentState::Busy, None, now()),
+        Err(AgentTransitionError::IllegalTransition {
+            agent_id: "1".to_string(),
+            from: AgentState::Offline,
+            to: AgentState::Busy,
+        })
+    );
+}
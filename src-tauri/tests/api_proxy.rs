@@ -0,0 +1,191 @@
+// api_request 프록시를 로컬 mock 서버로 검증 + 토큰 발급 주소 차단 / 백엔드 주소 https 강제
+mod common;
+
+use common::MockServer;
+use nexus_call_hub_lib::state::api_proxy::{send, ApiRequest, ApiResponse};
+use nexus_call_hub_lib::state::backend::BackendConfig;
+use std::collections::BTreeMap;
+
+const ME: &str = r#"{"success":true,"message":"ok","data":{"username":"agent1"}}"#;
+
+fn config(server: &MockServer) -> BackendConfig {
+    BackendConfig {
+        base_url: server.base_url.clone(),
+        ..BackendConfig::default()
+    }
+}
+
+fn call(
+    config: &BackendConfig,
+    token: Option<&str>,
+    request: ApiRequest,
+) -> Result<ApiResponse, String> {
+    let client = reqwest::Client::new();
+    tauri::async_runtime::block_on(send(&client, config, token, request))
+}
+
+fn get(url: &str) -> ApiRequest {
+    ApiRequest {
+        url: url.to_string(),
+        ..ApiRequest::default()
+    }
+}
+
+#[test]
+fn attaches_bearer_token_and_returns_status_headers_body() {
+    let server = MockServer::start(vec![(200, ME)]);
+    let response = call(&config(&server), Some("access-1"), get("/auth/me")).unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, ME);
+    assert_eq!(
+        response.headers.get("content-type").map(String::as_str),
+        Some("application/json")
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/auth/me");
+    assert_eq!(requests[0].header("authorization"), Some("Bearer access-1"));
+}
+
+#[test]
+fn sends_without_token_when_logged_out() {
+    let server = MockServer::start(vec![(200, ME)]);
+    call(&config(&server), None, get("/auth/signup")).unwrap();
+    assert_eq!(server.requests()[0].header("authorization"), None);
+}
+
+#[test]
+fn forwards_method_headers_body_and_query() {
+    let server = MockServer::start(vec![(201, "{}")]);
+    let mut headers = BTreeMap::new();
+    headers.insert("Content-Type".to_string(), "application/json".to_string());
+    let request = ApiRequest {
+        method: Some("post".to_string()),
+        url: format!("{}/api/chatting/rooms?page=2", server.base_url),
+        headers,
+        body: Some(r#"{"name":"room"}"#.to_string()),
+    };
+
+    let response = call(&config(&server), Some("access-1"), request).unwrap();
+    assert_eq!(response.status, 201);
+
+    let recorded = &server.requests()[0];
+    assert_eq!(recorded.method, "POST");
+    assert_eq!(recorded.path, "/api/chatting/rooms?page=2");
+    assert_eq!(recorded.header("content-type"), Some("application/json"));
+    assert_eq!(recorded.body, r#"{"name":"room"}"#);
+}
+
+#[test]
+fn http_error_status_is_returned_not_raised() {
+    let server = MockServer::start(vec![(401, r#"{"success":false}"#)]);
+    let response = call(&config(&server), Some("expired"), get("/auth/me")).unwrap();
+    assert_eq!(response.status, 401);
+    assert_eq!(response.body, r#"{"success":false}"#);
+}
+
+#[test]
+fn rejects_urls_outside_configured_bases_without_sending() {
+    let server = MockServer::start(vec![(200, ME)]);
+    let other = MockServer::start(vec![(200, ME)]);
+
+    for url in [
+        other.base_url.clone(),
+        format!("{}/auth/me", other.base_url),
+        "http://example.com/auth/me".to_string(),
+        format!(
+            "http://user@{}/auth/me",
+            server.base_url.trim_start_matches("http://")
+        ),
+    ] {
+        let result = call(&config(&server), Some("access-1"), get(&url));
+        assert!(result.is_err(), "{} should be rejected", url);
+    }
+    assert!(server.requests().is_empty());
+    assert!(other.requests().is_empty());
+}
+
+#[test]
+fn allowed_base_urls_extend_the_allow_list_by_path_prefix() {
+    let server = MockServer::start(vec![(200, ME)]);
+    let extra = MockServer::start(vec![(200, ME)]);
+    let config = BackendConfig {
+        allowed_base_urls: vec![format!("{}/reports/", extra.base_url)],
+        ..config(&server)
+    };
+
+    call(
+        &config,
+        None,
+        get(&format!("{}/reports/daily", extra.base_url)),
+    )
+    .unwrap();
+    assert!(call(&config, None, get(&format!("{}/reportsx", extra.base_url))).is_err());
+    assert!(call(&config, None, get(&format!("{}/admin", extra.base_url))).is_err());
+    assert_eq!(extra.requests().len(), 1);
+}
+
+#[test]
+fn caller_cannot_override_authorization_header() {
+    let server = MockServer::start(vec![(200, ME)]);
+    let mut headers = BTreeMap::new();
+    headers.insert("Authorization".to_string(), "Bearer forged".to_string());
+    let request = ApiRequest {
+        headers,
+        ..get("/auth/me")
+    };
+
+    assert!(call(&config(&server), Some("access-1"), request).is_err());
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn token_endpoints_cannot_be_called_through_the_proxy() {
+    let server = MockServer::start(vec![(200, ME)]);
+    let config = config(&server);
+    for url in [
+        "/auth/login".to_string(),
+        "/auth/refresh/".to_string(),
+        "/auth/impersonate?user=kim".to_string(),
+        format!("{}/auth/login", server.base_url),
+    ] {
+        let request = ApiRequest {
+            method: Some("POST".to_string()),
+            ..get(&url)
+        };
+        assert!(
+            call(&config, None, request).is_err(),
+            "{} should be rejected",
+            url
+        );
+    }
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn non_local_backends_must_use_https() {
+    let with_base = |base_url: &str| BackendConfig {
+        base_url: base_url.to_string(),
+        ..BackendConfig::default()
+    };
+    for ok in [
+        "https://api.example.com",
+        "http://localhost:8080",
+        "http://127.0.0.1:8080",
+        "http://[::1]:8080",
+    ] {
+        assert!(with_base(ok).validate().is_ok(), "{}", ok);
+    }
+    for bad in ["http://43.200.234.52:8080", "ftp://localhost", "not a url"] {
+        assert!(with_base(bad).validate().is_err(), "{}", bad);
+    }
+    let extra_http = BackendConfig {
+        allowed_base_urls: vec!["http://reports.example.com".to_string()],
+        ..with_base("https://api.example.com")
+    };
+    assert!(extra_http.validate().is_err());
+    assert!(BackendConfig::default().validate().is_ok());
+}
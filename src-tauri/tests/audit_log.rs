@@ -0,0 +1,223 @@
+// 감사 로그 HMAC 해시 체인: 레코드 수정 / 중간 삭제 / 순서 변경 위치 보고, 키 없는 재계산·다른 키 체인 거부, 끝부분 잘림은 파일만으로 알 수 없음, 고정 디렉터리 내보내기(덮어쓰기 거부)
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use chrono::Duration;
+
+use common::now;
+use nexus_call_hub_lib::state::audit_log::{
+    audit_key, verify_file, AuditEvent, AuditLog, AuditRecord, AuditVerification,
+    AUDIT_EXPORT_DIR_NAME, GENESIS_HASH,
+};
+
+const MASTER_KEY: &[u8] = &[7u8; 32];
+
+fn verify(path: &Path) -> AuditVerification {
+    verify_file(path, audit_key(MASTER_KEY).as_slice()).unwrap()
+}
+
+fn log_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("audit-log-test-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir.join("audit_log.jsonl")
+}
+
+/// 레코드 5개를 기록한 로그 파일의 줄 목록
+fn written(name: &str) -> (PathBuf, Vec<String>) {
+    let path = log_path(name);
+    let mut log = AuditLog::open(path.clone(), MASTER_KEY);
+    let events = [
+        AuditEvent::Login,
+        AuditEvent::TokenRefreshed,
+        AuditEvent::SessionUnlocked,
+        AuditEvent::UnlockFailed { remaining: 2 },
+        AuditEvent::TokenRefreshed,
+    ];
+    for (minute, event) in events.into_iter().enumerate() {
+        log.append(
+            event,
+            Some("kim".to_string()),
+            None,
+            Some("main".to_string()),
+            now() + Duration::minutes(minute as i64),
+        )
+        .unwrap();
+    }
+    let lines = std::fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    (path, lines)
+}
+
+fn rewrite(path: &PathBuf, lines: &[String]) {
+    std::fs::write(path, lines.join("\n") + "\n").unwrap();
+}
+
+#[test]
+fn intact_log_verifies() {
+    let (path, _) = written("intact");
+    let verification = verify(&path);
+    assert!(verification.valid);
+    assert_eq!(verification.records, 5);
+    assert_eq!(verification.broken_at_line, None);
+
+    // 다시 열면 마지막 레코드에 이어서 기록한다
+    let mut log = AuditLog::open(path.clone(), MASTER_KEY);
+    let record = log
+        .append(AuditEvent::Login, None, None, None, now())
+        .unwrap();
+    assert_eq!(record.seq, 6);
+    assert_eq!(record.prev_hash, verification.head_hash);
+    assert!(verify(&path).valid);
+}
+
+#[test]
+fn missing_file_is_an_empty_valid_chain() {
+    let verification = verify(&log_path("missing"));
+    assert!(verification.valid);
+    assert_eq!(verification.records, 0);
+    assert_eq!(verification.head_hash, GENESIS_HASH);
+}
+
+#[test]
+fn edited_record_is_reported_at_its_line() {
+    let (path, mut lines) = written("edited");
+    lines[2] = lines[2].replace("session_unlocked", "login");
+    rewrite(&path, &lines);
+
+    let verification = verify(&path);
+    assert!(!verification.valid);
+    assert_eq!(verification.broken_at_line, Some(3));
+    assert_eq!(verification.records, 2);
+}
+
+#[test]
+fn edited_record_with_recomputed_hash_breaks_the_next_line() {
+    let (path, mut lines) = written("rehashed");
+    let mut record: serde_json::Value = serde_json::from_str(&lines[1]).unwrap();
+    record["user_id"] = serde_json::json!("lee");
+    let mut parsed: AuditRecord = serde_json::from_value(record).unwrap();
+    parsed.hash = parsed.compute_hash(audit_key(MASTER_KEY).as_slice());
+    lines[1] = serde_json::to_string(&parsed).unwrap();
+    rewrite(&path, &lines);
+
+    let verification = verify(&path);
+    assert!(!verification.valid);
+    assert_eq!(verification.broken_at_line, Some(3));
+}
+
+#[test]
+fn record_rehashed_without_the_key_is_reported_at_its_line() {
+    use sha2::{Digest, Sha256};
+
+    let (path, mut lines) = written("unkeyed");
+    let mut record: serde_json::Value = serde_json::from_str(&lines[1]).unwrap();
+    record["user_id"] = serde_json::json!("lee");
+    // 키를 모르는 쪽은 평문 SHA-256 이나 다른 키로만 다시 계산할 수 있다
+    let mut parsed: AuditRecord = serde_json::from_value(record).unwrap();
+    let mut fields = serde_json::to_value(&parsed).unwrap();
+    fields.as_object_mut().unwrap().remove("hash");
+    parsed.hash = format!("{:x}", Sha256::digest(fields.to_string()));
+    lines[1] = serde_json::to_string(&parsed).unwrap();
+    rewrite(&path, &lines);
+
+    let verification = verify(&path);
+    assert!(!verification.valid);
+    assert_eq!(verification.broken_at_line, Some(2));
+
+    parsed.hash = parsed.compute_hash(audit_key(&[9u8; 32]).as_slice());
+    lines[1] = serde_json::to_string(&parsed).unwrap();
+    rewrite(&path, &lines);
+    assert_eq!(verify(&path).broken_at_line, Some(2));
+}
+
+#[test]
+fn chain_written_with_another_key_fails_at_the_first_line() {
+    let path = log_path("foreign");
+    let mut foreign = AuditLog::open(path.clone(), &[9u8; 32]);
+    foreign
+        .append(AuditEvent::Login, None, None, None, now())
+        .unwrap();
+
+    let verification = verify(&path);
+    assert!(!verification.valid);
+    assert_eq!(verification.broken_at_line, Some(1));
+
+    // 이 설치의 키로 열면 이전 파일은 보관하고 새 체인을 시작한다
+    let mut log = AuditLog::open(path.clone(), MASTER_KEY);
+    let record = log
+        .append(AuditEvent::Login, None, None, None, now())
+        .unwrap();
+    assert_eq!(record.seq, 1);
+    assert_eq!(record.prev_hash, GENESIS_HASH);
+    assert!(verify(&path).valid);
+    let archived = std::fs::read_dir(path.parent().unwrap())
+        .unwrap()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().ends_with(".old"))
+        .count();
+    assert_eq!(archived, 1);
+}
+
+#[test]
+fn export_goes_to_the_fixed_directory_and_never_overwrites() {
+    let (path, _) = written("export");
+    let log = AuditLog::open(path.clone(), MASTER_KEY);
+
+    let (exported, verification) = log.export(now()).unwrap();
+    assert!(verification.valid);
+    assert_eq!(
+        exported.parent().unwrap(),
+        path.parent().unwrap().join(AUDIT_EXPORT_DIR_NAME)
+    );
+    assert_eq!(
+        std::fs::read(&exported).unwrap(),
+        std::fs::read(&path).unwrap()
+    );
+
+    // 같은 시각에 다시 내보내면 기존 파일을 덮어쓰지 않고 실패한다
+    std::fs::write(&exported, "kept").unwrap();
+    assert!(log.export(now()).is_err());
+    assert_eq!(std::fs::read_to_string(&exported).unwrap(), "kept");
+    assert!(log.export(now() + Duration::seconds(1)).is_ok());
+}
+
+#[test]
+fn deleted_middle_record_is_reported() {
+    let (path, mut lines) = written("deleted");
+    lines.remove(2);
+    rewrite(&path, &lines);
+
+    let verification = verify(&path);
+    assert!(!verification.valid);
+    assert_eq!(verification.broken_at_line, Some(3));
+    assert_eq!(verification.records, 2);
+}
+
+#[test]
+fn reordered_records_are_reported() {
+    let (path, mut lines) = written("reordered");
+    lines.swap(1, 3);
+    rewrite(&path, &lines);
+
+    let verification = verify(&path);
+    assert!(!verification.valid);
+    assert_eq!(verification.broken_at_line, Some(2));
+    assert_eq!(verification.records, 1);
+}
+
+#[test]
+fn truncated_tail_is_not_detectable_from_the_file_alone() {
+    let (path, lines) = written("truncated");
+    let full = verify(&path);
+    rewrite(&path, &lines[..3]);
+
+    // 남은 체인은 온전하므로 유효하게 나온다. 잘림은 보관해 둔 head_hash 와 비교해야 드러난다
+    let verification = verify(&path);
+    assert!(verification.valid);
+    assert_eq!(verification.records, 3);
+    assert_ne!(verification.head_hash, full.head_hash);
+}
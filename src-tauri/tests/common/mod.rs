@@ -0,0 +1,155 @@
+// 통합 테스트 공통: 고정 시각 / 로그인 세션 fixture, 로컬 HTTP 서버 (미리 정한 응답을 순서대로 돌려준다)
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use chrono::{DateTime, TimeZone, Utc};
+use nexus_call_hub_lib::state::auth_state::{AuthState, User};
+use nexus_call_hub_lib::state::jwt::TokenClaims;
+use nexus_call_hub_lib::state::secret::Secret;
+
+/// 테스트 공통 기준 시각 (2025-01-06 월요일 09:00 UTC)
+pub fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap()
+}
+
+/// 서명은 검사하지 않으므로 토큰은 형식만 맞춘다
+pub fn user(id: &str, role: &str) -> User {
+    User {
+        id: id.to_string(),
+        name: id.to_string(),
+        email: None,
+        department: "고객상담팀".to_string(),
+        role: role.to_string(),
+        token: Secret::new(format!("{}.payload.signature", id)),
+        permissions: None,
+    }
+}
+
+/// `exp` 가 None 이면 만료 없는 토큰
+pub fn claims(sub: &str, exp: Option<DateTime<Utc>>) -> TokenClaims {
+    TokenClaims {
+        sub: Some(sub.to_string()),
+        exp: exp.map(|at| at.timestamp()),
+        iat: Some(now().timestamp()),
+        roles: vec![],
+    }
+}
+
+/// `begin_online_session` 으로 시작한 세션
+pub fn signed_in(user: User, claims: TokenClaims, refresh_token: Option<&str>) -> AuthState {
+    let auth = AuthState::default();
+    auth.begin_online_session(user, claims, refresh_token.map(str::to_string));
+    auth
+}
+
+/// `kim` 이 `role` 로 로그인한 상태
+pub fn logged_in(role: &str, exp: Option<DateTime<Utc>>) -> AuthState {
+    signed_in(user("kim", role), claims("kim", exp), None)
+}
+
+/// 서버가 받은 요청
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct MockServer {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockServer {
+    /// `responses` 를 (상태 코드, JSON 본문) 순서대로 응답. 다 쓰면 마지막 응답 반복
+    pub fn start(responses: Vec<(u16, &str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect();
+
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for (index, stream) in listener.incoming().enumerate() {
+                let Ok(stream) = stream else { break };
+                let (status, body) = responses
+                    .get(index)
+                    .or_else(|| responses.last())
+                    .cloned()
+                    .unwrap_or((404, String::new()));
+                if let Some(request) = serve(stream, status, &body) {
+                    recorded.lock().unwrap().push(request);
+                }
+            }
+        });
+
+        Self { base_url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn serve(stream: TcpStream, status: u16, body: &str) -> Option<RecordedRequest> {
+    let mut reader = BufReader::new(stream.try_clone().ok()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            headers.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    let length = headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut request_body = vec![0u8; length];
+    reader.read_exact(&mut request_body).ok()?;
+
+    let response = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let mut stream = stream;
+    stream.write_all(response.as_bytes()).ok()?;
+    stream.flush().ok()?;
+
+    Some(RecordedRequest {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&request_body).into_owned(),
+    })
+}
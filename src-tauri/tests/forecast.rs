@@ -0,0 +1,82 @@
+// 인력 산정: Erlang C 대기 확률 / 필요 인원 최소성, Erlang A 는 트래픽 강도보다 적은 인원도 허용
+use nexus_call_hub_lib::forecast::{erlang_b, erlang_c, StaffingModel, StaffingParams};
+
+/// 시간당 120콜 × 평균 처리 300초 = 10 Erlang, 20초 내 80% 응답
+fn params(patience_secs: Option<f64>) -> StaffingParams {
+    StaffingParams {
+        arrival_rate_per_hour: 120.0,
+        aht_secs: 300.0,
+        target_service_level: 0.8,
+        target_answer_secs: 20.0,
+        patience_secs,
+        max_occupancy: None,
+    }
+}
+
+#[test]
+fn erlang_c_matches_the_published_table() {
+    assert!((erlang_c(11, 10.0) - 0.682).abs() < 0.001);
+    assert!((erlang_b(11, 10.0) - 0.163).abs() < 0.001);
+    // 트래픽 강도 이하 인원이면 모두 대기
+    assert_eq!(erlang_c(10, 10.0), 1.0);
+}
+
+#[test]
+fn erlang_c_requirement_is_the_smallest_count_meeting_the_target() {
+    let params = params(None);
+    assert_eq!(params.model(), StaffingModel::ErlangC);
+    assert!((params.traffic_erlangs() - 10.0).abs() < 1e-9);
+
+    let required = params.required_agents().unwrap();
+    assert!(required > 10);
+    assert!(params.metrics(required).service_level >= 0.8);
+    assert!(params.metrics(required - 1).service_level < 0.8);
+}
+
+#[test]
+fn erlang_a_can_need_fewer_agents_than_the_offered_load() {
+    // 인내 시간 30초면 대기 콜 상당수가 포기하므로 낮은 목표는 10명 미만으로 달성
+    let params = StaffingParams {
+        target_service_level: 0.5,
+        ..params(Some(30.0))
+    };
+    assert_eq!(params.model(), StaffingModel::ErlangA);
+
+    let required = params.required_agents().unwrap();
+    assert!(required < 10, "required {}", required);
+    assert!(params.metrics(required).service_level >= 0.5);
+    assert!(params.metrics(required - 1).service_level < 0.5);
+    assert!(params.metrics(required).abandonment_probability.unwrap() > 0.0);
+}
+
+#[test]
+fn patient_callers_make_erlang_a_approach_erlang_c() {
+    let erlang_c = params(None);
+    let erlang_a = params(Some(1.0e7));
+    assert_eq!(
+        erlang_a.required_agents().unwrap(),
+        erlang_c.required_agents().unwrap()
+    );
+}
+
+#[test]
+fn occupancy_cap_raises_the_requirement() {
+    let uncapped = params(None).required_agents().unwrap();
+    let capped = StaffingParams {
+        max_occupancy: Some(0.7),
+        ..params(None)
+    };
+    let required = capped.required_agents().unwrap();
+    assert!(required >= uncapped);
+    assert!(capped.metrics(required).occupancy <= 0.7);
+    assert_eq!(required, 15);
+}
+
+#[test]
+fn no_arrivals_need_no_agents() {
+    let params = StaffingParams {
+        arrival_rate_per_hour: 0.0,
+        ..params(Some(30.0))
+    };
+    assert_eq!(params.required_agents().unwrap(), 0);
+}
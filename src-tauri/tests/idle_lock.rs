@@ -0,0 +1,182 @@
+// 자리 비움 잠금: 유휴 판정(통화 중 제외) / 잠금 해제 시도 제한 / PIN / 잠긴 동안 윈도우 접근 거부
+mod common;
+
+use chrono::{Duration, Utc};
+
+use common::{logged_in, now};
+use nexus_call_hub_lib::state::idle_lock::{
+    IdleLock, LockReason, UnlockOutcome, MAX_UNLOCK_ATTEMPTS,
+};
+use nexus_call_hub_lib::window::access::{authorize, WindowError};
+use nexus_call_hub_lib::window::types::{WindowDefinition, WindowType};
+
+/// 유휴 5분 (기본값) 으로 방금 로그인한 상태
+fn fresh_lock() -> IdleLock {
+    let mut lock = IdleLock::default();
+    lock.set_idle_timeout(true, 300).unwrap();
+    lock.reset(now());
+    lock
+}
+
+#[test]
+fn locks_once_the_idle_timeout_passes() {
+    let mut lock = fresh_lock();
+    assert!(lock
+        .check_idle(now() + Duration::seconds(299), false)
+        .is_none());
+
+    let locked = lock
+        .check_idle(now() + Duration::seconds(300), false)
+        .unwrap();
+    assert_eq!(locked.reason, LockReason::Idle);
+    assert_eq!(locked.locked_at, now() + Duration::seconds(300));
+    // 이미 잠겨 있으면 다시 잠그지 않는다
+    assert!(lock
+        .check_idle(now() + Duration::seconds(600), false)
+        .is_none());
+    assert!(lock.locked().is_some());
+}
+
+#[test]
+fn activity_postpones_the_lock() {
+    let mut lock = fresh_lock();
+    lock.record_activity(now() + Duration::seconds(200));
+    assert!(lock
+        .check_idle(now() + Duration::seconds(450), false)
+        .is_none());
+    assert!(lock
+        .check_idle(now() + Duration::seconds(500), false)
+        .is_some());
+}
+
+#[test]
+fn time_on_a_call_counts_as_activity() {
+    let mut lock = fresh_lock();
+    // 10분 통화하는 동안은 입력이 없어도 잠그지 않는다
+    for minute in 1..=10 {
+        assert!(lock
+            .check_idle(now() + Duration::minutes(minute), true)
+            .is_none());
+    }
+    // 통화가 끝난 시점부터 다시 5분
+    let call_ended = now() + Duration::minutes(10);
+    assert!(lock
+        .check_idle(call_ended + Duration::seconds(299), false)
+        .is_none());
+    assert!(lock
+        .check_idle(call_ended + Duration::seconds(300), false)
+        .is_some());
+}
+
+#[test]
+fn disabled_lock_never_triggers() {
+    let mut lock = fresh_lock();
+    lock.set_idle_timeout(false, 300).unwrap();
+    assert!(lock.check_idle(now() + Duration::hours(8), false).is_none());
+    assert!(lock.set_idle_timeout(true, 10).is_err());
+}
+
+#[test]
+fn unlock_attempts_are_limited() {
+    let mut lock = fresh_lock();
+    lock.lock(now(), LockReason::Manual).unwrap();
+
+    for attempt in 1..MAX_UNLOCK_ATTEMPTS {
+        assert_eq!(
+            lock.complete_unlock(false, now()),
+            UnlockOutcome::Rejected {
+                remaining: MAX_UNLOCK_ATTEMPTS - attempt
+            }
+        );
+        assert!(lock.locked().is_some());
+    }
+    assert_eq!(lock.complete_unlock(false, now()), UnlockOutcome::Exhausted);
+}
+
+#[test]
+fn successful_unlock_clears_failures_and_restarts_idle_timer() {
+    let mut lock = fresh_lock();
+    lock.lock(now(), LockReason::Manual).unwrap();
+    lock.complete_unlock(false, now());
+    lock.complete_unlock(false, now());
+
+    let unlocked_at = now() + Duration::minutes(1);
+    assert_eq!(
+        lock.complete_unlock(true, unlocked_at),
+        UnlockOutcome::Unlocked
+    );
+    assert!(lock.locked().is_none());
+    assert_eq!(lock.status(None, unlocked_at).failed_attempts, 0);
+    assert!(lock
+        .check_idle(unlocked_at + Duration::seconds(299), false)
+        .is_none());
+
+    // 다시 잠그면 시도 횟수도 처음부터
+    lock.lock(unlocked_at, LockReason::Manual).unwrap();
+    assert_eq!(
+        lock.complete_unlock(false, unlocked_at),
+        UnlockOutcome::Rejected {
+            remaining: MAX_UNLOCK_ATTEMPTS - 1
+        }
+    );
+}
+
+#[test]
+fn pin_is_hashed_and_verified_per_user() {
+    let mut lock = IdleLock::default();
+    assert!(lock.set_pin("kim", "12").is_err());
+    assert!(lock.set_pin("kim", "12ab").is_err());
+    assert!(!lock.has_pin("kim"));
+
+    lock.set_pin("kim", "4821").unwrap();
+    assert!(lock.has_pin("kim"));
+    let stored = &lock.settings().pins["kim"];
+    assert!(stored.starts_with("$argon2"));
+    assert!(!stored.contains("4821"));
+
+    assert!(lock.verify_pin("kim", "4821"));
+    assert!(!lock.verify_pin("kim", "4822"));
+    assert!(!lock.verify_pin("lee", "4821"));
+    assert!(lock.status(Some("kim"), now()).has_pin);
+    assert!(!lock.status(Some("lee"), now()).has_pin);
+}
+
+fn window(access: serde_json::Value) -> (WindowType, WindowDefinition) {
+    let definition = serde_json::from_value(serde_json::json!({
+        "label": "queue_monitor",
+        "title": "대기열 모니터",
+        "url": "queue_monitor.html",
+        "width": 800,
+        "height": 600,
+        "access": access
+    }))
+    .unwrap();
+    (
+        serde_json::from_value(serde_json::json!("QueueMonitor")).unwrap(),
+        definition,
+    )
+}
+
+#[test]
+fn locked_session_can_only_open_public_windows() {
+    let auth = logged_in("agent", Some(Utc::now() + Duration::hours(1)));
+    let (window_type, private) = window(serde_json::json!({ "type": "authenticated" }));
+    let (_, public) = window(serde_json::json!({ "type": "public" }));
+    assert_eq!(authorize(&auth, &window_type, &private), Ok(()));
+
+    auth.idle
+        .lock()
+        .unwrap()
+        .lock(Utc::now(), LockReason::Manual)
+        .unwrap();
+    assert_eq!(
+        authorize(&auth, &window_type, &private),
+        Err(WindowError::Locked {
+            window: window_type.clone()
+        })
+    );
+    assert_eq!(authorize(&auth, &window_type, &public), Ok(()));
+
+    auth.idle.lock().unwrap().complete_unlock(true, Utc::now());
+    assert_eq!(authorize(&auth, &window_type, &private), Ok(()));
+}
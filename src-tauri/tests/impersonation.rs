@@ -0,0 +1,152 @@
+// 대리 세션: 종료 시 원래 세션(사용자 / claims / refresh 토큰) 그대로 복원, 중첩 세션, 권한 상승 거부
+mod common;
+
+use std::collections::BTreeSet;
+
+use chrono::Duration;
+
+use common::{claims, now, signed_in, user};
+use nexus_call_hub_lib::state::auth_state::AuthState;
+use nexus_call_hub_lib::state::impersonation::ensure_no_escalation;
+
+fn set(items: &[&str]) -> BTreeSet<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+/// 슈퍼바이저로 로그인한 상태
+fn supervisor_session() -> AuthState {
+    signed_in(
+        user("park", "supervisor"),
+        claims("park", Some(now() + Duration::hours(8))),
+        Some("park-refresh"),
+    )
+}
+
+fn current_user_id(auth: &AuthState) -> Option<String> {
+    auth.user.lock().unwrap().as_ref().map(|u| u.id.clone())
+}
+
+#[test]
+fn ending_impersonation_restores_the_original_session() {
+    let auth = supervisor_session();
+    let original_permissions = auth.effective_permissions();
+    let generation = *auth.session_generation.lock().unwrap();
+
+    auth.push_session(
+        user("kim", "agent"),
+        claims("kim", Some(now() + Duration::hours(1))),
+        Some("kim-refresh".to_string()),
+        now(),
+    )
+    .unwrap();
+    assert!(auth.is_impersonating());
+    assert_eq!(current_user_id(&auth).as_deref(), Some("kim"));
+    assert_eq!(auth.impersonator_id().as_deref(), Some("park"));
+    assert_eq!(auth.actor_id().as_deref(), Some("park"));
+    assert_eq!(
+        auth.refresh_token.lock().unwrap().as_deref(),
+        Some("kim-refresh")
+    );
+    assert_ne!(auth.effective_permissions(), original_permissions);
+
+    let (ended, restored) = auth.pop_session().unwrap();
+    assert_eq!(ended.id, "kim");
+    assert_eq!(
+        (restored.id.as_str(), restored.role.as_str()),
+        ("park", "supervisor")
+    );
+    assert!(!auth.is_impersonating());
+    assert_eq!(auth.impersonator_id(), None);
+    let current = auth.user.lock().unwrap().clone().unwrap();
+    assert_eq!(current.id, "park");
+    assert_eq!(current.token.expose(), "park.payload.signature");
+    assert_eq!(
+        *auth.claims.lock().unwrap(),
+        Some(claims("park", Some(now() + Duration::hours(8))))
+    );
+    assert_eq!(
+        auth.refresh_token.lock().unwrap().as_deref(),
+        Some("park-refresh")
+    );
+    assert!(*auth.is_authenticated.lock().unwrap());
+    assert_eq!(auth.effective_permissions(), original_permissions);
+    // 대리 세션 동안 예약된 만료 타이머가 무효가 되도록 세대가 바뀐다
+    assert!(*auth.session_generation.lock().unwrap() > generation);
+
+    assert!(auth.pop_session().is_none());
+}
+
+#[test]
+fn nested_sessions_unwind_one_at_a_time() {
+    let auth = supervisor_session();
+    auth.push_session(
+        user("lee", "팀장"),
+        claims("lee", Some(now() + Duration::hours(2))),
+        None,
+        now(),
+    )
+    .unwrap();
+    auth.push_session(
+        user("kim", "agent"),
+        claims("kim", Some(now() + Duration::hours(1))),
+        None,
+        now(),
+    )
+    .unwrap();
+    // 감사 기록의 actor 는 항상 처음 로그인한 사용자
+    assert_eq!(auth.impersonator_id().as_deref(), Some("park"));
+
+    let (ended, restored) = auth.pop_session().unwrap();
+    assert_eq!((ended.id.as_str(), restored.id.as_str()), ("kim", "lee"));
+    assert_eq!(
+        *auth.claims.lock().unwrap(),
+        Some(claims("lee", Some(now() + Duration::hours(2))))
+    );
+    assert_eq!(*auth.refresh_token.lock().unwrap(), None);
+    assert!(auth.is_impersonating());
+
+    let (ended, restored) = auth.pop_session().unwrap();
+    assert_eq!((ended.id.as_str(), restored.id.as_str()), ("lee", "park"));
+    assert_eq!(
+        auth.refresh_token.lock().unwrap().as_deref(),
+        Some("park-refresh")
+    );
+    assert!(!auth.is_impersonating());
+}
+
+#[test]
+fn impersonation_needs_a_logged_in_session() {
+    let auth = AuthState::default();
+    assert!(auth
+        .push_session(
+            user("kim", "agent"),
+            claims("kim", Some(now() + Duration::hours(1))),
+            None,
+            now()
+        )
+        .is_err());
+    assert!(!auth.is_impersonating());
+    assert_eq!(current_user_id(&auth), None);
+}
+
+#[test]
+fn targets_with_permissions_the_actor_lacks_are_rejected() {
+    let actor = set(&["calls:*", "queues:view", "agents:impersonate"]);
+    assert!(ensure_no_escalation(&actor, &set(&["calls:inbound:answer", "queues:view"])).is_ok());
+    assert!(ensure_no_escalation(&actor, &BTreeSet::new()).is_ok());
+
+    let error =
+        ensure_no_escalation(&actor, &set(&["queues:view", "statistics:view"])).unwrap_err();
+    assert!(error.contains("statistics:view"));
+    // 대상의 와일드카드는 요청자에게 같은 와일드카드가 있어야 한다
+    assert!(ensure_no_escalation(&set(&["calls:inbound:answer"]), &set(&["calls:*"])).is_err());
+}
+
+#[test]
+fn agents_cannot_impersonate_upwards_under_the_default_policy() {
+    let auth = AuthState::default();
+    let agent = auth.permissions_of(&user("kim", "agent"));
+    let supervisor = auth.permissions_of(&user("park", "supervisor"));
+    assert!(ensure_no_escalation(&supervisor, &agent).is_ok());
+    assert!(ensure_no_escalation(&agent, &supervisor).is_err());
+}
@@ -0,0 +1,111 @@
+// JWT claims: payload 디코딩 / 잘못된 형식 거부 / 만료 판정 / 토큰 roles 는 권한에 반영하지 않음
+mod common;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::Duration;
+
+use common::{claims, logged_in, now, signed_in, user};
+use nexus_call_hub_lib::state::jwt::{decode_claims, TokenClaims};
+use nexus_call_hub_lib::state::rbac::is_granted;
+
+/// 서명 부분은 검사하지 않으므로 아무 값이나 붙인다
+fn token(payload: serde_json::Value) -> String {
+    format!(
+        "{}.{}.signature",
+        URL_SAFE_NO_PAD.encode(br#"{"alg":"HS256","typ":"JWT"}"#),
+        URL_SAFE_NO_PAD.encode(payload.to_string())
+    )
+}
+
+#[test]
+fn payload_claims_are_decoded() {
+    let claims = decode_claims(&token(serde_json::json!({
+        "sub": "kim",
+        "exp": now().timestamp() + 3600,
+        "iat": now().timestamp(),
+        "roles": ["agent", "팀장"],
+        "extra": "무시"
+    })))
+    .unwrap();
+    assert_eq!(claims.sub.as_deref(), Some("kim"));
+    assert_eq!(claims.expires_at(), Some(now() + Duration::hours(1)));
+    assert_eq!(claims.issued_at(), Some(now()));
+    assert_eq!(claims.roles, vec!["agent", "팀장"]);
+
+    // roles 는 콤마 구분 문자열도 허용, 없으면 빈 목록
+    let joined = decode_claims(&token(
+        serde_json::json!({ "roles": "agent, supervisor ," }),
+    ))
+    .unwrap();
+    assert_eq!(joined.roles, vec!["agent", "supervisor"]);
+    assert_eq!(
+        decode_claims(&token(serde_json::json!({}))).unwrap(),
+        TokenClaims::default()
+    );
+}
+
+#[test]
+fn malformed_tokens_are_rejected() {
+    let payload = URL_SAFE_NO_PAD.encode(br#"{"sub":"kim"}"#);
+    for bad in [
+        String::new(),
+        "opaque-session-token".to_string(),
+        format!("header.{}", payload),
+        format!("header.{}.signature.extra", payload),
+        "header.!!!.signature".to_string(),
+        format!("header.{}.signature", URL_SAFE_NO_PAD.encode("not json")),
+        format!(
+            "header.{}.signature",
+            URL_SAFE_NO_PAD.encode(r#"{"exp":"soon"}"#)
+        ),
+    ] {
+        assert!(decode_claims(&bad).is_err(), "{:?}", bad);
+    }
+}
+
+#[test]
+fn tokens_expire_at_exp_and_never_without_it() {
+    let claims = TokenClaims {
+        exp: Some(now().timestamp()),
+        ..TokenClaims::default()
+    };
+    assert!(!claims.is_expired(now() - Duration::seconds(1)));
+    assert!(claims.is_expired(now()));
+    assert!(!TokenClaims::default().is_expired(now() + Duration::days(3650)));
+}
+
+#[test]
+fn session_is_invalid_once_the_token_expires() {
+    let auth = logged_in("agent", Some(now() + Duration::minutes(5)));
+    assert_eq!(auth.expires_at(), Some(now() + Duration::minutes(5)));
+    assert!(auth.is_session_valid(now()));
+    assert!(auth.bearer_token(now()).is_some());
+    assert!(!auth.is_session_valid(now() + Duration::minutes(5)));
+    assert!(auth.bearer_token(now() + Duration::minutes(5)).is_none());
+}
+
+#[test]
+fn unverified_token_roles_grant_nothing() {
+    let plain = logged_in("agent", None);
+    let forged = signed_in(
+        user("kim", "agent"),
+        TokenClaims {
+            roles: vec!["admin".to_string(), "supervisor".to_string()],
+            ..claims("kim", None)
+        },
+        None,
+    );
+    assert_eq!(
+        forged.effective_permissions(),
+        plain.effective_permissions()
+    );
+    assert!(!is_granted(
+        &forged.effective_permissions(),
+        "statistics:view"
+    ));
+    assert_eq!(
+        forged.second_factor_required(),
+        plain.second_factor_required()
+    );
+}
@@ -0,0 +1,212 @@
+// 오프라인 자격 증명 저장 / 확인 / 실패 잠금 / 만료 검증, 로그인 응답의 거부 / 연결 실패 구분, 오프라인 로그인 허용 조건
+mod common;
+
+use chrono::Duration;
+
+use common::{now, MockServer};
+use nexus_call_hub_lib::state::auth_state::{AuthState, User};
+use nexus_call_hub_lib::state::backend::BackendConfig;
+use nexus_call_hub_lib::state::offline_login::{
+    request_login, LoginError, OfflineCredentials, OfflineVerification, MAX_OFFLINE_ATTEMPTS,
+    OFFLINE_CREDENTIAL_MAX_AGE_DAYS, OFFLINE_FALLBACK_SECS,
+};
+use nexus_call_hub_lib::state::secret::Secret;
+
+fn user() -> User {
+    User {
+        id: "kim".to_string(),
+        name: "김상담".to_string(),
+        email: None,
+        department: "고객상담팀".to_string(),
+        role: "상담원".to_string(),
+        token: Secret::new("header.payload.signature".to_string()),
+        permissions: None,
+    }
+}
+
+fn remembered() -> OfflineCredentials {
+    let mut store = OfflineCredentials::default();
+    store
+        .remember("kim", "correct horse", &user(), now())
+        .unwrap();
+    store
+}
+
+#[test]
+fn remembered_password_verifies_without_storing_plaintext() {
+    let mut store = remembered();
+    let cached = store.cached().unwrap();
+    assert!(cached.verifier.starts_with("$argon2"));
+    assert!(!cached.verifier.contains("correct horse"));
+
+    match store.verify(" kim ", "correct horse", now()).unwrap() {
+        OfflineVerification::Verified(profile) => {
+            assert_eq!(profile.id, "kim");
+            assert_eq!(profile.role, "상담원");
+        }
+        other => panic!("확인되어야 함: {:?}", other),
+    }
+}
+
+#[test]
+fn wrong_password_or_username_is_rejected() {
+    let mut store = remembered();
+    assert_eq!(
+        store.verify("kim", "wrong", now()).unwrap(),
+        OfflineVerification::Rejected {
+            remaining: MAX_OFFLINE_ATTEMPTS - 1
+        }
+    );
+    assert_eq!(
+        store.verify("lee", "correct horse", now()).unwrap(),
+        OfflineVerification::Rejected {
+            remaining: MAX_OFFLINE_ATTEMPTS - 2
+        }
+    );
+}
+
+#[test]
+fn success_resets_the_failure_count() {
+    let mut store = remembered();
+    store.verify("kim", "wrong", now()).unwrap();
+    store.verify("kim", "correct horse", now()).unwrap();
+    assert_eq!(store.cached().unwrap().failed_attempts, 0);
+}
+
+#[test]
+fn credentials_are_deleted_after_max_attempts() {
+    let mut store = remembered();
+    for attempt in 1..MAX_OFFLINE_ATTEMPTS {
+        assert_eq!(
+            store.verify("kim", "wrong", now()).unwrap(),
+            OfflineVerification::Rejected {
+                remaining: MAX_OFFLINE_ATTEMPTS - attempt
+            }
+        );
+    }
+    assert_eq!(
+        store.verify("kim", "wrong", now()).unwrap(),
+        OfflineVerification::Rejected { remaining: 0 }
+    );
+    assert!(store.cached().is_none());
+    // 맞는 비밀번호도 더는 통하지 않는다
+    assert!(matches!(
+        store.verify("kim", "correct horse", now()).unwrap(),
+        OfflineVerification::Unavailable(_)
+    ));
+}
+
+#[test]
+fn failure_count_survives_a_restart() {
+    let dir = std::env::temp_dir().join(format!("offline-login-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("offline_credentials.json");
+
+    let mut store = OfflineCredentials::load(path.clone());
+    store
+        .remember("kim", "correct horse", &user(), now())
+        .unwrap();
+    store.verify("kim", "wrong", now()).unwrap();
+    store.verify("kim", "wrong", now()).unwrap();
+
+    let mut reloaded = OfflineCredentials::load(path.clone());
+    assert_eq!(reloaded.cached().unwrap().failed_attempts, 2);
+    assert_eq!(
+        reloaded.verify("kim", "wrong", now()).unwrap(),
+        OfflineVerification::Rejected {
+            remaining: MAX_OFFLINE_ATTEMPTS - 3
+        }
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn credentials_expire_after_max_age() {
+    let mut store = remembered();
+    let last_valid = now() + Duration::days(OFFLINE_CREDENTIAL_MAX_AGE_DAYS);
+    assert!(matches!(
+        store.verify("kim", "correct horse", last_valid).unwrap(),
+        OfflineVerification::Verified(_)
+    ));
+    assert!(matches!(
+        store
+            .verify("kim", "correct horse", last_valid + Duration::seconds(1))
+            .unwrap(),
+        OfflineVerification::Unavailable(_)
+    ));
+}
+
+#[test]
+fn empty_credentials_are_not_remembered() {
+    let mut store = OfflineCredentials::default();
+    assert!(store.remember("kim", "", &user(), now()).is_err());
+    assert!(store.remember("  ", "pw", &user(), now()).is_err());
+    assert!(store.cached().is_none());
+}
+
+fn login_against(status: u16, body: &str) -> Result<String, LoginError> {
+    let server = MockServer::start(vec![(status, body)]);
+    let config = BackendConfig {
+        base_url: server.base_url.clone(),
+        ..BackendConfig::default()
+    };
+    let client = reqwest::Client::new();
+    tauri::async_runtime::block_on(request_login(&client, &config, "kim", "correct horse"))
+        .map(|tokens| tokens.access_token)
+}
+
+#[test]
+fn only_401_403_or_an_explicit_failure_body_reject_the_login() {
+    let ok = r#"{"success":true,"message":"ok","data":{"token":"access-1"}}"#;
+    assert_eq!(login_against(200, ok).unwrap(), "access-1");
+
+    let refused = r#"{"success":false,"message":"비밀번호 오류"}"#;
+    for (status, body) in [(401, ""), (403, "{}"), (200, refused), (400, refused)] {
+        assert!(
+            matches!(login_against(status, body), Err(LoginError::Rejected(_))),
+            "HTTP {} {}",
+            status,
+            body
+        );
+    }
+    // 게이트웨이 / 프록시 오류나 읽을 수 없는 응답은 서버에 닿지 않은 것으로 본다
+    for (status, body) in [
+        (404, "<html>Not Found</html>"),
+        (429, ""),
+        (502, "Bad Gateway"),
+        (200, "<html>captive portal</html>"),
+        (200, r#"{"success":true,"message":"ok"}"#),
+    ] {
+        assert!(
+            matches!(login_against(status, body), Err(LoginError::Unreachable(_))),
+            "HTTP {} {}",
+            status,
+            body
+        );
+    }
+}
+
+#[test]
+fn offline_login_is_allowed_once_right_after_an_unreachable_online_login() {
+    let auth = AuthState::default();
+    assert!(!auth.take_offline_fallback("kim", now()));
+
+    auth.record_online_login("kim", true, now());
+    assert!(!auth.take_offline_fallback("lee", now()));
+    // 다른 아이디로 확인해도 기록은 소비된다
+    assert!(!auth.take_offline_fallback("kim", now()));
+
+    auth.record_online_login("kim", true, now());
+    assert!(auth.take_offline_fallback(" kim ", now() + Duration::seconds(5)));
+    assert!(!auth.take_offline_fallback("kim", now() + Duration::seconds(6)));
+
+    auth.record_online_login("kim", true, now());
+    assert!(
+        !auth.take_offline_fallback("kim", now() + Duration::seconds(OFFLINE_FALLBACK_SECS + 1))
+    );
+
+    // 서버가 응답했으면 (성공 / 거부) 오프라인 로그인 불가
+    auth.record_online_login("kim", true, now());
+    auth.record_online_login("kim", false, now());
+    assert!(!auth.take_offline_fallback("kim", now()));
+}
@@ -0,0 +1,185 @@
+// 알림 규칙: 유지 시간(hold) / 재알림 간격(cooldown) 상태 전이, 대기열 지정 규칙 검증, 초기화
+mod common;
+
+use std::collections::BTreeMap;
+
+use chrono::Duration;
+
+use common::now;
+use nexus_call_hub_lib::state::queue_alerts::{
+    AlertCondition, AlertEngine, AlertRule, AlertSeverity, AlertStatus, QueueAlert,
+};
+use nexus_call_hub_lib::state::queue_registry::{QueueDefinition, QueueRegistry, QueueView};
+use nexus_call_hub_lib::state::queue_state::QueueStatus;
+
+fn rule(hold_secs: u32, cooldown_secs: u32) -> AlertRule {
+    AlertRule {
+        id: "waiting".to_string(),
+        name: "대기 콜 과다".to_string(),
+        enabled: true,
+        condition: AlertCondition::InboundWaitingAbove { limit: 5 },
+        hold_secs,
+        cooldown_secs,
+        severity: AlertSeverity::Warning,
+        queue_id: None,
+    }
+}
+
+fn engine(rule: AlertRule) -> AlertEngine {
+    let mut engine = AlertEngine::default();
+    engine.upsert_rule(rule, &QueueRegistry::default()).unwrap();
+    engine
+}
+
+fn waiting(count: u32) -> QueueStatus {
+    QueueStatus {
+        inbound_waiting: count,
+        ..QueueStatus::default()
+    }
+}
+
+/// 전체 대기열 상태가 `count` 건일 때 `secs` 초 시점의 평가 결과
+fn evaluate(engine: &mut AlertEngine, count: u32, secs: i64) -> Vec<QueueAlert> {
+    engine.evaluate(
+        &waiting(count),
+        &[],
+        &BTreeMap::new(),
+        now() + Duration::seconds(secs),
+    )
+}
+
+fn statuses(alerts: &[QueueAlert]) -> Vec<AlertStatus> {
+    alerts.iter().map(|a| a.status).collect()
+}
+
+#[test]
+fn alert_is_raised_only_after_the_hold_time() {
+    let mut engine = engine(rule(30, 300));
+    assert!(evaluate(&mut engine, 8, 0).is_empty());
+    assert!(evaluate(&mut engine, 8, 29).is_empty());
+
+    let raised = evaluate(&mut engine, 8, 30);
+    assert_eq!(statuses(&raised), vec![AlertStatus::Active]);
+    assert_eq!(raised[0].raised_at, now() + Duration::seconds(30));
+    assert_eq!(raised[0].subject, None);
+
+    // 활성 상태가 유지되는 동안은 다시 보내지 않는다
+    assert!(evaluate(&mut engine, 9, 60).is_empty());
+    assert_eq!(engine.active().len(), 1);
+}
+
+#[test]
+fn condition_clearing_during_hold_restarts_the_timer() {
+    let mut engine = engine(rule(30, 0));
+    assert!(evaluate(&mut engine, 8, 0).is_empty());
+    assert!(evaluate(&mut engine, 2, 20).is_empty());
+    assert!(evaluate(&mut engine, 8, 40).is_empty());
+    assert!(evaluate(&mut engine, 8, 69).is_empty());
+    assert_eq!(
+        statuses(&evaluate(&mut engine, 8, 70)),
+        vec![AlertStatus::Active]
+    );
+}
+
+#[test]
+fn resolved_alert_is_not_raised_again_within_the_cooldown() {
+    let mut engine = engine(rule(0, 300));
+    assert_eq!(
+        statuses(&evaluate(&mut engine, 8, 0)),
+        vec![AlertStatus::Active]
+    );
+
+    let resolved = evaluate(&mut engine, 2, 10);
+    assert_eq!(statuses(&resolved), vec![AlertStatus::Resolved]);
+    assert_eq!(resolved[0].resolved_at, Some(now() + Duration::seconds(10)));
+    assert_eq!(engine.resolved().len(), 1);
+    assert!(engine.active().is_empty());
+
+    // 마지막 발생 후 300초가 지나기 전에는 조건이 다시 참이어도 조용하다
+    assert!(evaluate(&mut engine, 8, 20).is_empty());
+    assert!(evaluate(&mut engine, 8, 299).is_empty());
+    assert_eq!(
+        statuses(&evaluate(&mut engine, 8, 300)),
+        vec![AlertStatus::Active]
+    );
+}
+
+#[test]
+fn disabling_a_rule_resolves_its_alert() {
+    let mut engine = engine(rule(0, 0));
+    evaluate(&mut engine, 8, 0);
+
+    let disabled = AlertRule {
+        enabled: false,
+        ..rule(0, 0)
+    };
+    engine
+        .upsert_rule(disabled, &QueueRegistry::default())
+        .unwrap();
+    assert_eq!(
+        statuses(&evaluate(&mut engine, 8, 1)),
+        vec![AlertStatus::Resolved]
+    );
+    assert!(evaluate(&mut engine, 8, 2).is_empty());
+}
+
+#[test]
+fn queue_rule_must_name_a_registered_queue() {
+    let mut registry = QueueRegistry::default();
+    let billing_rule = AlertRule {
+        queue_id: Some("billing".to_string()),
+        ..rule(0, 0)
+    };
+    let mut engine = AlertEngine::default();
+    assert!(engine.upsert_rule(billing_rule.clone(), &registry).is_err());
+    assert!(engine.rules().is_empty());
+
+    registry
+        .upsert_queue(QueueDefinition {
+            id: "billing".to_string(),
+            name: "요금 문의".to_string(),
+            skill: None,
+        })
+        .unwrap();
+    engine.upsert_rule(billing_rule, &registry).unwrap();
+
+    // 대기열 지정 규칙은 전체 상태가 아니라 그 대기열 상태로 평가한다
+    let views = BTreeMap::from([(
+        "billing".to_string(),
+        QueueView {
+            status: waiting(8),
+            agents: vec![],
+        },
+    )]);
+    let raised = engine.evaluate(&waiting(0), &[], &views, now());
+    assert_eq!(statuses(&raised), vec![AlertStatus::Active]);
+    assert_eq!(raised[0].queue_id.as_deref(), Some("billing"));
+    assert!(raised[0].message.starts_with("[billing]"));
+}
+
+#[test]
+fn blank_rule_name_is_rejected() {
+    let blank = AlertRule {
+        name: "  ".to_string(),
+        ..rule(0, 0)
+    };
+    assert!(blank.validate(&QueueRegistry::default()).is_err());
+}
+
+#[test]
+fn reset_resolves_active_alerts_and_forgets_the_cooldown() {
+    let mut engine = engine(rule(0, 300));
+    evaluate(&mut engine, 8, 0);
+
+    let cleared = engine.reset(now() + Duration::seconds(5));
+    assert_eq!(statuses(&cleared), vec![AlertStatus::Resolved]);
+    assert_eq!(cleared[0].resolved_at, Some(now() + Duration::seconds(5)));
+    assert!(engine.active().is_empty());
+    assert_eq!(engine.resolved().len(), 1);
+
+    // cooldown 기록도 지워지므로 조건이 참이면 바로 다시 발생한다
+    assert_eq!(
+        statuses(&evaluate(&mut engine, 8, 10)),
+        vec![AlertStatus::Active]
+    );
+}
@@ -0,0 +1,110 @@
+// delta 로그 재동기화: 정확히 같은 seq / 보관 범위 경계 / 밀려난 seq / 미래 seq
+mod common;
+
+use common::now;
+use nexus_call_hub_lib::state::queue_delta::{
+    QueueChange, QueueDeltaLog, QueueResync, QUEUE_DELTA_LOG_LIMIT,
+};
+use nexus_call_hub_lib::state::queue_state::QueueStatus;
+
+/// 대기 콜 수를 1..=count 로 바꿔 가며 delta 를 `count` 개 쌓은 로그
+fn log_with(count: u32) -> QueueDeltaLog {
+    let mut log = QueueDeltaLog::default();
+    for waiting in 1..=count {
+        let status = QueueStatus {
+            inbound_waiting: waiting,
+            ..QueueStatus::default()
+        };
+        log.record_status(None, &status, now()).unwrap();
+    }
+    log
+}
+
+fn delta_seqs(resync: QueueResync) -> Vec<u64> {
+    match resync {
+        QueueResync::Deltas { deltas, .. } => deltas.iter().map(|d| d.seq).collect(),
+        QueueResync::Snapshot(snapshot) => panic!("snapshot at seq {}", snapshot.seq),
+    }
+}
+
+#[test]
+fn unchanged_status_does_not_consume_a_seq() {
+    let mut log = log_with(1);
+    let status = QueueStatus {
+        inbound_waiting: 1,
+        ..QueueStatus::default()
+    };
+    assert!(log.record_status(None, &status, now()).is_none());
+    assert_eq!(log.seq(), 1);
+}
+
+#[test]
+fn caught_up_window_gets_no_deltas() {
+    let log = log_with(3);
+    assert_eq!(
+        log.since(Some(3)),
+        QueueResync::Deltas {
+            seq: 3,
+            deltas: vec![]
+        }
+    );
+}
+
+#[test]
+fn missing_deltas_are_returned_in_order() {
+    let log = log_with(5);
+    assert_eq!(delta_seqs(log.since(Some(2))), vec![3, 4, 5]);
+    assert_eq!(delta_seqs(log.since(Some(0))), vec![1, 2, 3, 4, 5]);
+
+    let QueueResync::Deltas { deltas, .. } = log.since(Some(4)) else {
+        panic!("expected deltas");
+    };
+    let QueueChange::QueueStatus { queue_id, patch } = &deltas[0].change else {
+        panic!("expected a status delta");
+    };
+    assert_eq!(*queue_id, None);
+    assert_eq!(patch.inbound_waiting, Some(5));
+    assert_eq!(patch.inbound_agents_total, None);
+}
+
+#[test]
+fn oldest_retained_seq_is_the_last_one_served_as_deltas() {
+    let total = QUEUE_DELTA_LOG_LIMIT as u32 + 10;
+    let log = log_with(total);
+    let oldest = total as u64 - QUEUE_DELTA_LOG_LIMIT as u64 + 1;
+
+    // 바로 앞 seq 까지 본 윈도우는 보관된 delta 전부로 따라잡는다
+    let seqs = delta_seqs(log.since(Some(oldest - 1)));
+    assert_eq!(seqs.len(), QUEUE_DELTA_LOG_LIMIT);
+    assert_eq!(seqs[0], oldest);
+
+    // 그보다 뒤처지면 밀려난 delta 가 있으므로 전체 스냅샷
+    let QueueResync::Snapshot(snapshot) = log.since(Some(oldest - 2)) else {
+        panic!("expected a snapshot");
+    };
+    assert_eq!(snapshot.seq, total as u64);
+    assert_eq!(snapshot.status.inbound_waiting, total);
+}
+
+#[test]
+fn seq_from_the_future_or_missing_forces_a_snapshot() {
+    let log = log_with(3);
+    for since in [Some(4), Some(100), None] {
+        let QueueResync::Snapshot(snapshot) = log.since(since) else {
+            panic!("expected a snapshot for {:?}", since);
+        };
+        assert_eq!(snapshot.seq, 3);
+    }
+}
+
+#[test]
+fn definition_changes_are_sequenced_with_status() {
+    let mut log = log_with(1);
+    log.record_queue_definitions(now());
+
+    let QueueResync::Deltas { seq, deltas } = log.since(Some(1)) else {
+        panic!("expected deltas");
+    };
+    assert_eq!(seq, 2);
+    assert_eq!(deltas[0].change, QueueChange::QueueDefinitions);
+}
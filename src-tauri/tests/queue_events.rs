@@ -0,0 +1,461 @@
+// 대기열 변경분(delta) / KPI 이벤트가 모든 윈도우에 전달되는지, 거부된 상태 변경(잘못된 전이 / 스킬)은 아무것도 바꾸지 않는지 Tauri mock runtime 으로 검증
+use std::sync::{Arc, Mutex};
+
+use nexus_call_hub_lib::state::agent_state::{AgentState, AgentTransitionError, BreakReason};
+use nexus_call_hub_lib::state::queue_alerts::{AlertCondition, AlertRule, AlertSeverity};
+use nexus_call_hub_lib::state::queue_kpi::{CallEvent, QueueKpiReport};
+use nexus_call_hub_lib::state::queue_registry::{AgentSkill, QueueDefinition, QueueRegistry};
+use nexus_call_hub_lib::state::queue_state::{
+    self, AgentStatusUpdate, QueueState, QueueStatus, QUEUE_ALERT_EVENT, QUEUE_DELTA_EVENT,
+    QUEUE_KPIS_UPDATED_EVENT,
+};
+use tauri::test::{get_ipc_response, mock_builder, mock_context, noop_assets, MockRuntime};
+use tauri::{App, Listener, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+
+const WINDOW_LABELS: [&str; 3] = ["launcher", "queue_monitor", "statistics"];
+
+type Received = Arc<Mutex<Vec<(String, String)>>>;
+
+fn create_app() -> App<MockRuntime> {
+    mock_builder()
+        .manage(QueueState::default())
+        .invoke_handler(tauri::generate_handler![
+            nexus_call_hub_lib::state::queue_state::update_queue_status,
+            nexus_call_hub_lib::state::queue_state::get_queue_status,
+            nexus_call_hub_lib::state::queue_state::update_agent_status,
+            nexus_call_hub_lib::state::queue_state::get_all_agents,
+        ])
+        .build(mock_context(noop_assets()))
+        .expect("failed to build mock app")
+}
+
+fn open_windows(app: &App<MockRuntime>) -> Vec<WebviewWindow<MockRuntime>> {
+    WINDOW_LABELS
+        .iter()
+        .map(|label| {
+            WebviewWindowBuilder::new(app, *label, WebviewUrl::default())
+                .build()
+                .expect("failed to build mock window")
+        })
+        .collect()
+}
+
+/// 각 윈도우에 리스너를 달고 (윈도우 label, payload) 를 수집
+fn listen_all(windows: &[WebviewWindow<MockRuntime>], event: &str) -> Received {
+    let received: Received = Arc::new(Mutex::new(Vec::new()));
+    for window in windows {
+        let label = window.label().to_string();
+        let received = received.clone();
+        window.listen(event, move |e| {
+            received
+                .lock()
+                .unwrap()
+                .push((label.clone(), e.payload().to_string()));
+        });
+    }
+    received
+}
+
+fn sample_status() -> QueueStatus {
+    QueueStatus {
+        inbound_waiting: 8,
+        inbound_agents_available: 2,
+        inbound_agents_total: 6,
+        outbound_active_campaigns: 3,
+        outbound_calls_in_progress: 12,
+        outbound_calls_today: 247,
+    }
+}
+
+fn agent_update(status: AgentState) -> AgentStatusUpdate {
+    AgentStatusUpdate {
+        id: "1".to_string(),
+        name: "김상담".to_string(),
+        status,
+        break_reason: None,
+        current_call: None,
+        call_duration: None,
+        skills: None,
+        simulated: false,
+    }
+}
+
+fn apply(
+    app: &App<MockRuntime>,
+    update: AgentStatusUpdate,
+) -> Result<queue_state::AgentStatus, AgentTransitionError> {
+    queue_state::update_agent_status(app.handle().clone(), app.state::<QueueState>(), update)
+}
+
+fn invoke_request(cmd: &str, body: serde_json::Value) -> tauri::webview::InvokeRequest {
+    tauri::webview::InvokeRequest {
+        cmd: cmd.into(),
+        callback: tauri::ipc::CallbackFn(0),
+        error: tauri::ipc::CallbackFn(1),
+        url: "http://tauri.localhost".parse().unwrap(),
+        body: tauri::ipc::InvokeBody::Json(body),
+        headers: Default::default(),
+        invoke_key: tauri::test::INVOKE_KEY.to_string(),
+    }
+}
+
+/// 기록 시각(`at`)은 실제 시각이라 비교에서 뺀다
+fn assert_every_window_received(received: &Received, expected: &serde_json::Value) {
+    let received = received.lock().unwrap();
+    for label in WINDOW_LABELS {
+        let payloads: Vec<serde_json::Value> = received
+            .iter()
+            .filter(|(l, _)| l == label)
+            .map(|(_, p)| {
+                let mut payload: serde_json::Value = serde_json::from_str(p).unwrap();
+                assert!(payload["at"].is_string(), "window `{label}`: {payload}");
+                payload.as_object_mut().unwrap().remove("at");
+                payload
+            })
+            .collect();
+        assert_eq!(payloads, vec![expected.clone()], "window `{label}`");
+    }
+}
+
+/// 0 에서 `sample_status` 로 바뀐 전체 상태 delta
+fn sample_status_delta(seq: u64) -> serde_json::Value {
+    serde_json::json!({
+        "seq": seq,
+        "type": "queue_status",
+        "queue_id": null,
+        "patch": sample_status()
+    })
+}
+
+#[test]
+fn queue_status_update_reaches_every_window() {
+    let app = create_app();
+    let windows = open_windows(&app);
+    let received = listen_all(&windows, QUEUE_DELTA_EVENT);
+
+    let status = sample_status();
+    queue_state::update_queue_status(
+        app.handle().clone(),
+        app.state::<QueueState>(),
+        status.clone(),
+        None,
+    )
+    .unwrap();
+
+    assert_every_window_received(&received, &sample_status_delta(1));
+    assert_eq!(
+        *app.state::<QueueState>().queue_status.lock().unwrap(),
+        status
+    );
+    assert!(app
+        .state::<QueueState>()
+        .last_updated
+        .lock()
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn agent_status_update_reaches_every_window() {
+    let app = create_app();
+    let windows = open_windows(&app);
+    let received = listen_all(&windows, QUEUE_DELTA_EVENT);
+
+    let agent = apply(&app, agent_update(AgentState::Available)).unwrap();
+
+    // 프론트엔드 AgentInfo 와 같은 camelCase 필드명이어야 한다
+    let expected = serde_json::json!({
+        "seq": 1,
+        "type": "agent",
+        "agent": {
+            "id": "1",
+            "name": "김상담",
+            "status": "available",
+            "breakReason": null,
+            "statusSince": agent.state.status_since,
+            "previousStatus": "offline",
+            "currentCall": null,
+            "callDuration": null,
+            "simulated": false
+        }
+    });
+    assert_every_window_received(&received, &expected);
+}
+
+#[test]
+fn invoke_from_one_window_broadcasts_to_all() {
+    let app = create_app();
+    let windows = open_windows(&app);
+    let received = listen_all(&windows, QUEUE_DELTA_EVENT);
+
+    let status = sample_status();
+    get_ipc_response(
+        &windows[1],
+        invoke_request(
+            "update_queue_status",
+            serde_json::json!({ "status": status }),
+        ),
+    )
+    .expect("update_queue_status failed");
+
+    assert_every_window_received(&received, &sample_status_delta(1));
+
+    let current = get_ipc_response(
+        &windows[2],
+        invoke_request("get_queue_status", serde_json::json!({})),
+    )
+    .expect("get_queue_status failed")
+    .deserialize::<QueueStatus>()
+    .unwrap();
+    assert_eq!(current, status);
+}
+
+#[test]
+fn agent_updates_replace_existing_entry() {
+    let app = create_app();
+    let _windows = open_windows(&app);
+
+    for status in [
+        AgentState::Available,
+        AgentState::Ringing,
+        AgentState::Busy,
+        AgentState::WrapUp,
+        AgentState::Available,
+    ] {
+        apply(&app, agent_update(status)).unwrap();
+    }
+
+    let agents = queue_state::get_all_agents(app.state::<QueueState>(), None).unwrap();
+    assert_eq!(agents.len(), 1);
+    assert_eq!(agents[0].status(), AgentState::Available);
+    assert_eq!(agents[0].state.previous_status, Some(AgentState::WrapUp));
+}
+
+#[test]
+fn illegal_transition_is_rejected_without_event() {
+    let app = create_app();
+    let windows = open_windows(&app);
+    apply(&app, agent_update(AgentState::Available)).unwrap();
+    let received = listen_all(&windows, QUEUE_DELTA_EVENT);
+
+    let error = apply(&app, agent_update(AgentState::Busy)).unwrap_err();
+    assert_eq!(
+        error,
+        AgentTransitionError::IllegalTransition {
+            agent_id: "1".to_string(),
+            from: AgentState::Available,
+            to: AgentState::Busy,
+        }
+    );
+    assert_eq!(
+        serde_json::to_value(&error).unwrap()["kind"],
+        "illegal_transition"
+    );
+
+    let error = apply(&app, agent_update(AgentState::Break)).unwrap_err();
+    assert!(matches!(
+        error,
+        AgentTransitionError::MissingBreakReason { .. }
+    ));
+
+    assert!(received.lock().unwrap().is_empty());
+    let agents = queue_state::get_all_agents(app.state::<QueueState>(), None).unwrap();
+    assert_eq!(agents[0].status(), AgentState::Available);
+
+    let mut on_break = agent_update(AgentState::Break);
+    on_break.break_reason = Some(BreakReason::Lunch);
+    let agent = apply(&app, on_break).unwrap();
+    assert_eq!(agent.state.break_reason, Some(BreakReason::Lunch));
+}
+
+#[test]
+fn invalid_skills_reject_the_whole_update() {
+    let app = create_app();
+    let windows = open_windows(&app);
+    apply(&app, agent_update(AgentState::Available)).unwrap();
+    let received = listen_all(&windows, QUEUE_DELTA_EVENT);
+
+    let mut update = agent_update(AgentState::Break);
+    update.break_reason = Some(BreakReason::Lunch);
+    update.skills = Some(vec![AgentSkill {
+        skill: "billing".to_string(),
+        proficiency: 0,
+    }]);
+    let error = apply(&app, update).unwrap_err();
+    assert!(matches!(
+        &error,
+        AgentTransitionError::InvalidSkills { agent_id, .. } if agent_id == "1"
+    ));
+    assert_eq!(
+        serde_json::to_value(&error).unwrap()["kind"],
+        "invalid_skills"
+    );
+
+    // 상태도 스킬도 바뀌지 않고 이벤트도 없다
+    assert!(received.lock().unwrap().is_empty());
+    let agents = queue_state::get_all_agents(app.state::<QueueState>(), None).unwrap();
+    assert_eq!(agents[0].status(), AgentState::Available);
+    assert!(app
+        .state::<QueueState>()
+        .queues
+        .lock()
+        .unwrap()
+        .agent_skills("1")
+        .is_empty());
+}
+
+#[test]
+fn unknown_agent_cannot_start_mid_call() {
+    let app = create_app();
+    assert!(apply(&app, agent_update(AgentState::Busy)).is_err());
+    assert!(queue_state::get_all_agents(app.state::<QueueState>(), None)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn global_status_cannot_be_overwritten_once_queues_exist() {
+    let app = create_app();
+    let state = app.state::<QueueState>();
+    state
+        .queues
+        .lock()
+        .unwrap()
+        .upsert_queue(QueueDefinition {
+            id: "billing".to_string(),
+            name: "요금 문의".to_string(),
+            skill: None,
+        })
+        .unwrap();
+
+    assert!(queue_state::update_queue_status(
+        app.handle().clone(),
+        app.state::<QueueState>(),
+        sample_status(),
+        None,
+    )
+    .is_err());
+    assert_eq!(*state.queue_status.lock().unwrap(), QueueStatus::default());
+
+    // 대기열별로 갱신하면 전체 상태는 대기열 합계
+    queue_state::update_queue_status(
+        app.handle().clone(),
+        app.state::<QueueState>(),
+        sample_status(),
+        Some("billing".to_string()),
+    )
+    .unwrap();
+    assert_eq!(*state.queue_status.lock().unwrap(), sample_status());
+}
+
+#[test]
+fn alert_is_emitted_without_the_notification_plugin() {
+    let app = create_app();
+    let windows = open_windows(&app);
+    let received = listen_all(&windows, QUEUE_ALERT_EVENT);
+    app.state::<QueueState>()
+        .alerts
+        .lock()
+        .unwrap()
+        .upsert_rule(
+            AlertRule {
+                id: "waiting".to_string(),
+                name: "대기 콜 과다".to_string(),
+                enabled: true,
+                condition: AlertCondition::InboundWaitingAbove { limit: 5 },
+                hold_secs: 0,
+                cooldown_secs: 0,
+                severity: AlertSeverity::Warning,
+                queue_id: None,
+            },
+            &QueueRegistry::default(),
+        )
+        .unwrap();
+
+    // mock 앱에는 알림 플러그인이 없으므로 데스크톱 알림은 건너뛰고 이벤트만 보낸다
+    queue_state::update_queue_status(
+        app.handle().clone(),
+        app.state::<QueueState>(),
+        sample_status(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(received.lock().unwrap().len(), WINDOW_LABELS.len());
+}
+
+#[test]
+fn call_events_push_the_kpi_report_to_every_window() {
+    let app = create_app();
+    let windows = open_windows(&app);
+    let received = listen_all(&windows, QUEUE_KPIS_UPDATED_EVENT);
+
+    queue_state::record_call_event(
+        app.handle().clone(),
+        app.state::<QueueState>(),
+        CallEvent::Enqueued {
+            call_id: "c1".to_string(),
+            at: None,
+        },
+        None,
+    )
+    .unwrap();
+
+    let received = received.lock().unwrap();
+    for label in WINDOW_LABELS {
+        let reports: Vec<QueueKpiReport> = received
+            .iter()
+            .filter(|(l, _)| l == label)
+            .map(|(_, p)| serde_json::from_str(p).unwrap())
+            .collect();
+        assert_eq!(reports.len(), 1, "window `{label}`");
+        assert_eq!(reports[0].calls_waiting, 1, "window `{label}`");
+    }
+}
+
+#[test]
+fn ending_a_simulation_removes_its_agents_and_resets_kpis() {
+    let app = create_app();
+    let windows = open_windows(&app);
+    let state = app.state::<QueueState>();
+    apply(&app, agent_update(AgentState::Available)).unwrap();
+
+    // 실제 시각보다 앞서간 시뮬레이션 시계에서 시뮬레이션 상담원 / 콜 기록
+    let simulated_at = chrono::Utc::now() + chrono::Duration::hours(10);
+    state.set_simulated_now(Some(simulated_at));
+    let simulated = AgentStatusUpdate {
+        id: "sim-1".to_string(),
+        name: "시뮬레이션 상담원 1".to_string(),
+        simulated: true,
+        ..agent_update(AgentState::Available)
+    };
+    queue_state::update_agent_status_at(app.handle(), &state, simulated, simulated_at).unwrap();
+    queue_state::record_call_event(
+        app.handle().clone(),
+        app.state::<QueueState>(),
+        CallEvent::Enqueued {
+            call_id: "sim-call-1".to_string(),
+            at: None,
+        },
+        None,
+    )
+    .unwrap();
+    assert_eq!(state.kpi_report().calls_waiting, 1);
+
+    let received = listen_all(&windows, QUEUE_DELTA_EVENT);
+    queue_state::end_simulation(app.handle(), &state);
+
+    assert_every_window_received(
+        &received,
+        &serde_json::json!({ "seq": 3, "type": "agent_removed", "agent_id": "sim-1" }),
+    );
+    let agents = queue_state::get_all_agents(app.state::<QueueState>(), None).unwrap();
+    assert_eq!(
+        agents.iter().map(|a| a.id.as_str()).collect::<Vec<_>>(),
+        vec!["1"]
+    );
+    // 실제 시각으로 돌아오고, 시뮬레이션 시각에 쌓인 기록은 남지 않는다
+    assert!(state.now() < simulated_at);
+    let report = state.kpi_report();
+    assert_eq!(report.calls_waiting, 0);
+    assert!(report.generated_at < simulated_at);
+}
@@ -0,0 +1,192 @@
+// 대기열 히스토리: 링 버퍼 용량 / 보관 기간, 같은 구간 덮어쓰기, 다운샘플 평균, 저장 주기
+mod common;
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
+
+use common::now;
+use nexus_call_hub_lib::state::queue_history::{
+    AgentCounts, HistoryConfig, HistorySample, QueueHistory, QueueSample,
+};
+use nexus_call_hub_lib::state::queue_state::QueueStatus;
+
+fn at(secs: i64) -> DateTime<Utc> {
+    now() + Duration::seconds(secs)
+}
+
+fn sample(secs: i64, waiting: u32) -> HistorySample {
+    HistorySample {
+        at: at(secs),
+        queue: QueueStatus {
+            inbound_waiting: waiting,
+            ..QueueStatus::default()
+        },
+        agents: AgentCounts {
+            available: waiting % 3,
+            ..AgentCounts::default()
+        },
+        queues: BTreeMap::new(),
+    }
+}
+
+fn history(resolution_secs: u32, retention_hours: u32) -> QueueHistory {
+    let mut history = QueueHistory::default();
+    history
+        .set_config(
+            HistoryConfig {
+                resolution_secs,
+                retention_hours,
+                persist_every_secs: 60,
+            },
+            now(),
+        )
+        .unwrap();
+    history
+}
+
+fn history_with(secs: &[i64], resolution_secs: u32, retention_hours: u32) -> QueueHistory {
+    let mut history = history(resolution_secs, retention_hours);
+    for &s in secs {
+        history.record(sample(s, s as u32));
+    }
+    history
+}
+
+fn waiting_series(history: &QueueHistory) -> Vec<f64> {
+    history
+        .query(at(-86_400), at(86_400), None, None)
+        .points
+        .iter()
+        .map(|p| p.inbound_waiting)
+        .collect()
+}
+
+#[test]
+fn samples_in_the_same_bucket_overwrite_each_other() {
+    let mut history = history(10, 1);
+    history.record(sample(0, 1));
+    history.record(sample(9, 4));
+    assert_eq!(history.len(), 1);
+    assert_eq!(waiting_series(&history), vec![4.0]);
+
+    history.record(sample(10, 7));
+    assert_eq!(history.len(), 2);
+    // 마지막 샘플보다 이른 샘플은 버린다
+    history.record(sample(5, 100));
+    assert_eq!(waiting_series(&history), vec![4.0, 7.0]);
+}
+
+#[test]
+fn ring_buffer_drops_the_oldest_sample_at_capacity() {
+    // 1시간 / 30분 간격 = 2개
+    let mut history = history(1800, 1);
+    assert_eq!(history.config().capacity(), 2);
+    for (index, secs) in [0, 1800, 3600].into_iter().enumerate() {
+        history.record(sample(secs, index as u32));
+    }
+    assert_eq!(history.len(), 2);
+    assert_eq!(waiting_series(&history), vec![1.0, 2.0]);
+}
+
+#[test]
+fn samples_older_than_retention_are_dropped() {
+    let mut history = history(600, 1);
+    history.record(sample(0, 1));
+    history.record(sample(600, 2));
+    history.record(sample(7200, 3));
+    assert_eq!(history.len(), 1);
+
+    // 보관 기간을 줄이면 지금 기준으로 바로 정리
+    let mut history = history_with(&[0, 1200, 2400, 3600], 600, 24);
+    history
+        .set_config(
+            HistoryConfig {
+                resolution_secs: 600,
+                retention_hours: 1,
+                persist_every_secs: 60,
+            },
+            at(5000),
+        )
+        .unwrap();
+    assert_eq!(waiting_series(&history), vec![2400.0, 3600.0]);
+}
+
+#[test]
+fn query_downsamples_by_averaging_each_bucket() {
+    let mut history = history(10, 1);
+    for (secs, waiting) in [(0, 2), (10, 4), (20, 9), (60, 1), (70, 3)] {
+        history.record(sample(secs, waiting));
+    }
+
+    let series = history.query(at(0), at(120), Some(60), None);
+    assert_eq!(series.resolution_secs, 60);
+    assert_eq!(series.points.len(), 2);
+    let first = &series.points[0];
+    assert_eq!(first.at, at(0));
+    assert_eq!(first.samples, 3);
+    assert!((first.inbound_waiting - 5.0).abs() < 1e-9);
+    assert_eq!(first.inbound_waiting_max, 9);
+    // available = waiting % 3 → 2, 1, 0
+    assert!((first.agents_available - 1.0).abs() < 1e-9);
+    assert_eq!(series.points[1].at, at(60));
+    assert!((series.points[1].inbound_waiting - 2.0).abs() < 1e-9);
+
+    // 샘플 간격보다 잘게는 나누지 않는다
+    assert_eq!(
+        history.query(at(0), at(120), Some(1), None).resolution_secs,
+        10
+    );
+    // 구간 밖 샘플은 제외
+    assert_eq!(
+        history.query(at(10), at(20), Some(60), None).points[0].samples,
+        2
+    );
+}
+
+#[test]
+fn queue_query_only_uses_samples_that_include_the_queue() {
+    let mut history = history(10, 1);
+    history.record(sample(0, 5));
+    let mut with_queue = sample(10, 5);
+    with_queue.queues.insert(
+        "billing".to_string(),
+        QueueSample {
+            queue: QueueStatus {
+                inbound_waiting: 2,
+                ..QueueStatus::default()
+            },
+            agents: AgentCounts::default(),
+        },
+    );
+    history.record(with_queue);
+
+    let series = history.query(at(0), at(60), Some(60), Some("billing"));
+    assert_eq!(series.points.len(), 1);
+    assert_eq!(series.points[0].samples, 1);
+    assert!((series.points[0].inbound_waiting - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn snapshot_is_due_once_per_persist_interval_and_reloads() {
+    let dir = std::env::temp_dir().join(format!("queue-history-test-{}", std::process::id()));
+    let path = dir.join("queue_history.json");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let mut history = QueueHistory::load(path.clone(), now());
+    history.record(sample(0, 3));
+    history.record(sample(10, 6));
+
+    let snapshot = history.snapshot_if_due(at(10)).expect("first save is due");
+    assert!(history.snapshot_if_due(at(69)).is_none());
+    assert!(history.snapshot_if_due(at(70)).is_some());
+    snapshot.write().unwrap();
+
+    let restored = QueueHistory::load(path, at(20));
+    assert_eq!(restored.len(), 2);
+    assert_eq!(waiting_series(&restored), vec![3.0, 6.0]);
+    let _ = std::fs::remove_dir_all(&dir);
+
+    // 파일 경로가 없는 메모리 전용 히스토리는 저장하지 않는다
+    assert!(QueueHistory::default().snapshot(at(0)).is_none());
+}
@@ -0,0 +1,191 @@
+// 대기열 KPI: 서비스 레벨 / ASA / 포기율 / AHT / 점유율 공식과 집계 구간 경계, 오늘 이전 대기 콜 정리
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+
+use common::now;
+use nexus_call_hub_lib::state::agent_state::AgentState;
+use nexus_call_hub_lib::state::queue_kpi::{CallEvent, KpiEngine, KpiWindow};
+
+fn ago(secs: i64) -> Option<DateTime<Utc>> {
+    Some(now() - Duration::seconds(secs))
+}
+
+fn enqueue(engine: &mut KpiEngine, call_id: &str, at: i64) {
+    engine.record_call_event(
+        &CallEvent::Enqueued {
+            call_id: call_id.to_string(),
+            at: ago(at),
+        },
+        now(),
+    );
+}
+
+fn answer(engine: &mut KpiEngine, call_id: &str, at: i64) {
+    engine.record_call_event(
+        &CallEvent::Answered {
+            call_id: call_id.to_string(),
+            agent_id: "kim".to_string(),
+            at: ago(at),
+        },
+        now(),
+    );
+}
+
+fn complete(engine: &mut KpiEngine, call_id: &str, at: i64) {
+    engine.record_call_event(
+        &CallEvent::Completed {
+            call_id: call_id.to_string(),
+            at: ago(at),
+        },
+        now(),
+    );
+}
+
+fn abandon(engine: &mut KpiEngine, call_id: &str, at: i64) {
+    engine.record_call_event(
+        &CallEvent::Abandoned {
+            call_id: call_id.to_string(),
+            at: ago(at),
+        },
+        now(),
+    );
+}
+
+/// 10분 전에 들어온 콜 3건: 10초 만에 응답(180초 처리), 30초 만에 응답(300초 처리), 40초 뒤 포기
+fn sample_engine() -> KpiEngine {
+    let mut engine = KpiEngine::default();
+    for call_id in ["a", "b", "c"] {
+        enqueue(&mut engine, call_id, 600);
+    }
+    answer(&mut engine, "a", 590);
+    complete(&mut engine, "a", 410);
+    answer(&mut engine, "b", 570);
+    complete(&mut engine, "b", 270);
+    abandon(&mut engine, "c", 560);
+    engine
+}
+
+fn close(actual: Option<f64>, expected: f64) -> bool {
+    actual.is_some_and(|v| (v - expected).abs() < 1e-9)
+}
+
+#[test]
+fn kpi_formulas() {
+    let kpis = sample_engine().compute(KpiWindow::FifteenMinutes, now());
+    assert_eq!(kpis.calls_offered, 3);
+    assert_eq!(kpis.calls_answered, 2);
+    assert_eq!(kpis.calls_abandoned, 1);
+    assert_eq!(kpis.calls_completed, 2);
+    // 기준 20초 안에 응답한 콜 / 제공 콜 (포기 포함)
+    assert!(close(kpis.service_level, 1.0 / 3.0));
+    assert!(close(kpis.average_speed_of_answer_secs, 20.0));
+    assert!(close(kpis.abandonment_rate, 1.0 / 3.0));
+    assert!(close(kpis.average_handle_time_secs, 240.0));
+}
+
+#[test]
+fn service_level_follows_the_threshold() {
+    let mut engine = sample_engine();
+    engine.service_level_threshold_secs = 30;
+    let report = engine.report(now());
+    assert_eq!(report.service_level_threshold_secs, 30);
+    assert!(close(report.fifteen_minutes.service_level, 2.0 / 3.0));
+}
+
+#[test]
+fn empty_window_has_no_ratios() {
+    let kpis = KpiEngine::default().compute(KpiWindow::OneHour, now());
+    assert_eq!(kpis.calls_offered, 0);
+    assert_eq!(kpis.service_level, None);
+    assert_eq!(kpis.average_speed_of_answer_secs, None);
+    assert_eq!(kpis.abandonment_rate, None);
+    assert_eq!(kpis.average_handle_time_secs, None);
+    assert_eq!(kpis.occupancy, None);
+}
+
+#[test]
+fn calls_leave_the_window_by_their_event_time() {
+    let mut engine = KpiEngine::default();
+    // 15분 경계에 정확히 걸친 응답은 포함, 그보다 1초 이른 응답은 1시간 구간에만
+    enqueue(&mut engine, "edge", 15 * 60 + 5);
+    answer(&mut engine, "edge", 15 * 60);
+    enqueue(&mut engine, "old", 15 * 60 + 6);
+    answer(&mut engine, "old", 15 * 60 + 1);
+    enqueue(&mut engine, "late", 90);
+    answer(&mut engine, "late", 60);
+    // 포기 콜도 포기 시각 기준
+    enqueue(&mut engine, "gone", 61 * 60);
+    abandon(&mut engine, "gone", 60 * 60 + 1);
+
+    let fifteen = engine.compute(KpiWindow::FifteenMinutes, now());
+    assert_eq!(fifteen.window_start, now() - Duration::minutes(15));
+    assert_eq!(fifteen.calls_answered, 2);
+    assert_eq!(fifteen.calls_abandoned, 0);
+
+    let hour = engine.compute(KpiWindow::OneHour, now());
+    assert_eq!(hour.calls_answered, 3);
+    assert_eq!(hour.calls_abandoned, 0);
+
+    // 집계 시점 이후의 이벤트는 넣지 않고, 구간이 뒤로 가면 밀려났던 포기 콜이 다시 들어온다
+    let earlier = engine.compute(KpiWindow::OneHour, now() - Duration::minutes(10));
+    assert_eq!(earlier.calls_answered, 2);
+    assert_eq!(earlier.calls_abandoned, 1);
+}
+
+#[test]
+fn waiting_calls_are_reported_until_answered_or_abandoned() {
+    let mut engine = sample_engine();
+    enqueue(&mut engine, "d", 45);
+    enqueue(&mut engine, "e", 15);
+    let report = engine.report(now());
+    assert_eq!(report.calls_waiting, 2);
+    assert!(close(report.longest_waiting_secs, 45.0));
+
+    answer(&mut engine, "d", 0);
+    abandon(&mut engine, "e", 0);
+    let report = engine.report(now());
+    assert_eq!(report.calls_waiting, 0);
+    assert_eq!(report.longest_waiting_secs, None);
+}
+
+#[test]
+fn waiting_calls_from_before_today_are_pruned() {
+    let mut engine = KpiEngine::default();
+    let today = KpiWindow::Today.start(now());
+    // 어제 들어와서 응답 / 포기 이벤트가 오지 않은 콜
+    engine.record_call_event(
+        &CallEvent::Enqueued {
+            call_id: "stale".to_string(),
+            at: Some(today - Duration::minutes(10)),
+        },
+        today - Duration::minutes(5),
+    );
+    assert_eq!(engine.report(today - Duration::minutes(5)).calls_waiting, 1);
+
+    enqueue(&mut engine, "fresh", 30);
+    let report = engine.report(now());
+    assert_eq!(report.calls_waiting, 1);
+    assert!(close(report.longest_waiting_secs, 30.0));
+}
+
+#[test]
+fn occupancy_is_clipped_to_the_window() {
+    let mut engine = KpiEngine::default();
+    let at = |secs: i64| now() - Duration::seconds(secs);
+    // 30분 전부터 통화, 20분 전 대기, 10분 전 다시 통화, 중간 휴식은 근무 시간에서 제외
+    engine.record_agent_state("kim", AgentState::Busy, at(30 * 60));
+    engine.record_agent_state("kim", AgentState::Available, at(20 * 60));
+    engine.record_agent_state("kim", AgentState::Break, at(12 * 60));
+    engine.record_agent_state("kim", AgentState::Busy, at(10 * 60));
+    // 같은 상태를 다시 보고해도 구간이 나뉘지 않는다
+    engine.record_agent_state("kim", AgentState::Busy, at(5 * 60));
+
+    // 15분 구간: 대기 3분, 휴식 2분, 통화 10분 → 10 / 13
+    let fifteen = engine.compute(KpiWindow::FifteenMinutes, now());
+    assert!(close(fifteen.occupancy, 10.0 / 13.0));
+
+    // 1시간 구간: 통화 10 + 10분, 대기 8분
+    let hour = engine.compute(KpiWindow::OneHour, now());
+    assert!(close(hour.occupancy, 20.0 / 28.0));
+}
@@ -0,0 +1,117 @@
+// 스킬 배정 변경: 새로 맡은 대기열 점유율에 현재 상태 반영, 빠진 대기열은 그 시점에 닫음
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+
+use common::now;
+use nexus_call_hub_lib::state::agent_state::AgentState;
+use nexus_call_hub_lib::state::queue_kpi::KpiWindow;
+use nexus_call_hub_lib::state::queue_registry::{AgentSkill, QueueDefinition, QueueRegistry};
+
+fn registry() -> QueueRegistry {
+    let mut registry = QueueRegistry::default();
+    for (id, name) in [("billing", "요금 문의"), ("tech", "기술 지원")] {
+        registry
+            .upsert_queue(QueueDefinition {
+                id: id.to_string(),
+                name: name.to_string(),
+                skill: None,
+            })
+            .unwrap();
+    }
+    registry
+}
+
+fn skill(name: &str) -> AgentSkill {
+    AgentSkill {
+        skill: name.to_string(),
+        proficiency: 3,
+    }
+}
+
+fn occupancy(registry: &QueueRegistry, queue_id: &str, at: DateTime<Utc>) -> Option<f64> {
+    registry
+        .kpis(queue_id)
+        .unwrap()
+        .compute(KpiWindow::FifteenMinutes, at)
+        .occupancy
+}
+
+#[test]
+fn busy_agent_is_seeded_into_a_newly_assigned_queue() {
+    let mut registry = registry();
+    registry
+        .set_agent_skills(
+            "kim",
+            vec![skill("billing")],
+            Some(AgentState::Available),
+            now(),
+        )
+        .unwrap();
+    registry.record_agent_state("kim", AgentState::Busy, now());
+
+    // 통화 도중 tech 스킬을 추가하면 tech 점유율도 그 시점부터 통화 중으로 잡힌다
+    let assigned = now() + Duration::minutes(1);
+    registry
+        .set_agent_skills(
+            "kim",
+            vec![skill("billing"), skill("tech")],
+            Some(AgentState::Busy),
+            assigned,
+        )
+        .unwrap();
+    let later = assigned + Duration::minutes(2);
+    assert_eq!(occupancy(&registry, "tech", later), Some(1.0));
+    assert_eq!(occupancy(&registry, "billing", later), Some(1.0));
+
+    // 이미 맡고 있던 대기열은 기존 구간을 유지한다
+    registry.record_agent_state("kim", AgentState::Available, later);
+    let end = later + Duration::minutes(3);
+    assert_eq!(occupancy(&registry, "billing", end), Some(0.5));
+    assert_eq!(occupancy(&registry, "tech", end), Some(0.4));
+}
+
+#[test]
+fn removed_skill_closes_the_agent_in_that_queue() {
+    let mut registry = registry();
+    registry
+        .set_agent_skills(
+            "kim",
+            vec![skill("billing"), skill("tech")],
+            Some(AgentState::Busy),
+            now(),
+        )
+        .unwrap();
+
+    let removed = now() + Duration::minutes(1);
+    registry
+        .set_agent_skills(
+            "kim",
+            vec![skill("billing")],
+            Some(AgentState::Busy),
+            removed,
+        )
+        .unwrap();
+    registry.record_agent_state("kim", AgentState::Available, removed);
+
+    // tech 는 스킬이 빠진 뒤로 쌓이지 않고, billing 만 대기 시간이 더해진다
+    let later = removed + Duration::minutes(1);
+    assert_eq!(occupancy(&registry, "tech", later), Some(1.0));
+    assert_eq!(occupancy(&registry, "billing", later), Some(0.5));
+    assert_eq!(
+        registry.queues_for_agent("kim"),
+        vec!["billing".to_string()]
+    );
+}
+
+#[test]
+fn unknown_agent_state_seeds_nothing() {
+    let mut registry = registry();
+    registry
+        .set_agent_skills("kim", vec![skill("billing")], None, now())
+        .unwrap();
+    assert_eq!(
+        occupancy(&registry, "billing", now() + Duration::minutes(1)),
+        None
+    );
+}
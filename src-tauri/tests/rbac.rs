@@ -0,0 +1,155 @@
+// 역할 기반 권한: 와일드카드 일치 / 상속 / 오프라인 제한, 순환 상속과 없는 상위 역할 정책 거부
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use nexus_call_hub_lib::state::rbac::{is_granted, permission_matches, RbacPolicy};
+
+fn set(items: &[&str]) -> BTreeSet<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+fn policy_path(name: &str, json: serde_json::Value) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rbac-test-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("rbac_policy.json");
+    std::fs::write(&path, serde_json::to_vec(&json).unwrap()).unwrap();
+    path
+}
+
+#[test]
+fn wildcards_match_whole_segments() {
+    assert!(permission_matches("queue:view", "queue:view"));
+    assert!(!permission_matches("queue:view", "queue:view:all"));
+    assert!(!permission_matches("queue:view:all", "queue:view"));
+
+    // 마지막 `*` 는 나머지 모든 하위 구간
+    assert!(permission_matches("queue:*", "queue:view"));
+    assert!(permission_matches("queue:*", "queue:agents:assign"));
+    assert!(!permission_matches("queue:*", "queue"));
+    assert!(!permission_matches("queue:*", "statistics:view"));
+    assert!(permission_matches("*", "audit:view"));
+
+    // 중간 `*` 는 정확히 한 구간
+    assert!(permission_matches("calls:*:answer", "calls:inbound:answer"));
+    assert!(!permission_matches("calls:*:answer", "calls:inbound:dial"));
+    assert!(!permission_matches("calls:*:answer", "calls:inbound"));
+    assert!(!permission_matches(
+        "calls:*:answer",
+        "calls:inbound:answer:now"
+    ));
+}
+
+#[test]
+fn is_granted_checks_every_permission() {
+    let permissions = set(&["tasks:shared:view", "calls:*"]);
+    assert!(is_granted(&permissions, "calls:outbound:dial"));
+    assert!(is_granted(&permissions, "tasks:shared:view"));
+    assert!(!is_granted(&permissions, "tasks:shared:edit"));
+    assert!(!is_granted(&BTreeSet::new(), "tasks:shared:view"));
+}
+
+#[test]
+fn roles_inherit_their_parents_permissions() {
+    let policy = RbacPolicy::default();
+    let agent = policy.effective_permissions(["agent"], &[]);
+    let team_lead = policy.effective_permissions(["팀장"], &[]);
+    assert!(agent.is_subset(&team_lead));
+    assert!(is_granted(&team_lead, "statistics:view"));
+    assert!(!is_granted(&agent, "statistics:view"));
+
+    // 상속 경로가 두 번 겹쳐도 한 번만 펼친다
+    let expanded = policy.expand_roles(["admin", "supervisor"]);
+    assert_eq!(
+        expanded.into_iter().collect::<Vec<_>>(),
+        vec!["admin", "agent", "supervisor", "team_lead"]
+    );
+
+    let supervisor =
+        policy.effective_permissions(["ROLE_MANAGER"], &["reports:export".to_string()]);
+    assert!(is_granted(&supervisor, "queue:agents:assign"));
+    assert!(is_granted(&supervisor, "reports:export"));
+    assert!(!is_granted(&supervisor, "security:mfa:manage"));
+    assert!(policy.requires_second_factor(["admin"]));
+    assert!(!policy.requires_second_factor(["team_lead"]));
+
+    // 모르는 역할은 권한이 없다
+    assert!(policy.effective_permissions(["guest"], &[]).is_empty());
+}
+
+#[test]
+fn offline_sessions_keep_only_allowed_permissions() {
+    let policy = RbacPolicy::default();
+
+    let agent = policy.restrict_to_offline(&policy.effective_permissions(["agent"], &[]));
+    assert_eq!(agent, set(&["agents:self:status", "tasks:shared:view"]));
+
+    // 와일드카드 권한은 허용 목록의 구체 권한으로 좁혀진다
+    let admin = policy.restrict_to_offline(&set(&["*"]));
+    assert_eq!(admin, set(&["agents:self:status", "tasks:shared:view"]));
+    assert!(!is_granted(&admin, "queue:view"));
+
+    // 허용 목록에 있어도 원래 없던 권한은 생기지 않는다
+    let limited = policy.restrict_to_offline(&set(&["tasks:shared:view", "queue:view"]));
+    assert_eq!(limited, set(&["tasks:shared:view"]));
+}
+
+#[test]
+fn policy_with_an_inheritance_cycle_is_rejected() {
+    let json = serde_json::json!({
+        "roles": {
+            "a": { "display_name": "A", "inherits": ["b"] },
+            "b": { "display_name": "B", "inherits": ["c"] },
+            "c": { "display_name": "C", "inherits": ["a"] }
+        }
+    });
+    let policy: RbacPolicy = serde_json::from_value(json.clone()).unwrap();
+    let error = policy.validate().unwrap_err();
+    assert!(error.contains("순환 상속"), "{}", error);
+
+    // 파일에 있으면 기본 정책으로 대체
+    assert_eq!(
+        RbacPolicy::load(policy_path("cycle", json)),
+        RbacPolicy::default()
+    );
+}
+
+#[test]
+fn policy_with_an_unknown_parent_is_rejected() {
+    let json = serde_json::json!({
+        "roles": {
+            "agent": { "display_name": "상담원", "inherits": ["ghost"] }
+        }
+    });
+    let policy: RbacPolicy = serde_json::from_value(json.clone()).unwrap();
+    assert!(policy.validate().unwrap_err().contains("ghost"));
+    assert_eq!(
+        RbacPolicy::load(policy_path("unknown", json)),
+        RbacPolicy::default()
+    );
+}
+
+#[test]
+fn malformed_permissions_are_rejected() {
+    for permission in ["queue::view", "queue:vi*", ""] {
+        let json = serde_json::json!({
+            "roles": { "agent": { "display_name": "상담원", "permissions": [permission] } }
+        });
+        let policy: RbacPolicy = serde_json::from_value(json).unwrap();
+        assert!(policy.validate().is_err(), "{:?}", permission);
+    }
+}
+
+#[test]
+fn valid_policy_file_is_loaded() {
+    let json = serde_json::json!({
+        "roles": {
+            "agent": { "display_name": "상담원", "permissions": ["queue:view"] },
+            "lead": { "display_name": "팀장", "inherits": ["agent"], "permissions": ["agents:*"] }
+        },
+        "offline_permissions": ["queue:view"]
+    });
+    let policy = RbacPolicy::load(policy_path("valid", json));
+    assert_eq!(policy.roles.len(), 2);
+    let lead = policy.effective_permissions(["lead"], &[]);
+    assert_eq!(policy.restrict_to_offline(&lead), set(&["queue:view"]));
+}
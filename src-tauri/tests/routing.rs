@@ -0,0 +1,380 @@
+// ACD 라우팅 엔진을 시뮬레이션 시계로 결정적으로 검증
+use chrono::{TimeZone, Utc};
+
+use nexus_call_hub_lib::state::agent_state::{AgentState, AgentStateMachine};
+use nexus_call_hub_lib::state::clock::SimulatedClock;
+use nexus_call_hub_lib::state::queue_registry::{AgentSkill, QueueDefinition, QueueRegistry};
+use nexus_call_hub_lib::state::queue_state::AgentStatus;
+use nexus_call_hub_lib::state::routing::{
+    Interaction, RoutingConfig, RoutingDecision, RoutingEngine, RoutingStrategy,
+};
+
+fn clock() -> SimulatedClock {
+    SimulatedClock::new(Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap())
+}
+
+/// `idle_secs` 초 전부터 대기 중인 상담원
+fn available_agent(clock: &SimulatedClock, id: &str, idle_secs: i64) -> AgentStatus {
+    let since = clock.now() - chrono::Duration::seconds(idle_secs);
+    let mut state = AgentStateMachine::new(since);
+    state
+        .transition(id, AgentState::Available, None, since)
+        .unwrap();
+    AgentStatus {
+        id: id.to_string(),
+        name: id.to_string(),
+        state,
+        current_call: None,
+        call_duration: None,
+        simulated: false,
+    }
+}
+
+fn engine(strategy: RoutingStrategy) -> RoutingEngine {
+    RoutingEngine::new(RoutingConfig {
+        strategy,
+        ring_timeout_secs: 15,
+    })
+}
+
+fn call(id: &str) -> Interaction {
+    Interaction {
+        id: id.to_string(),
+        queue_id: None,
+        customer_id: None,
+        enqueued_at: None,
+    }
+}
+
+fn billing_registry(skills: &[(&str, u8)]) -> QueueRegistry {
+    let mut registry = QueueRegistry::default();
+    registry
+        .upsert_queue(QueueDefinition {
+            id: "billing".to_string(),
+            name: "요금 문의".to_string(),
+            skill: None,
+        })
+        .unwrap();
+    for (agent_id, proficiency) in skills {
+        registry
+            .set_agent_skills(
+                agent_id,
+                vec![AgentSkill {
+                    skill: "billing".to_string(),
+                    proficiency: *proficiency,
+                }],
+                None,
+                Utc::now(),
+            )
+            .unwrap();
+    }
+    registry
+}
+
+fn offered_to(
+    engine: &mut RoutingEngine,
+    clock: &SimulatedClock,
+    agents: &[AgentStatus],
+) -> Vec<String> {
+    engine
+        .tick(clock.now(), agents, &QueueRegistry::default())
+        .offered
+        .into_iter()
+        .map(|o| o.agent_id)
+        .collect()
+}
+
+#[test]
+fn longest_idle_agent_gets_the_call() {
+    let clock = clock();
+    let agents = vec![
+        available_agent(&clock, "a", 30),
+        available_agent(&clock, "b", 120),
+        available_agent(&clock, "c", 60),
+    ];
+    let mut engine = engine(RoutingStrategy::LongestIdle);
+    engine.enqueue(call("call-1"), clock.now()).unwrap();
+
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["b"]);
+
+    let entry = engine.log(Some(1)).pop().unwrap();
+    assert_eq!(entry.decision, RoutingDecision::Offered);
+    assert_eq!(entry.agent_id.as_deref(), Some("b"));
+    let ranking: Vec<&str> = entry
+        .candidates
+        .iter()
+        .map(|c| c.agent_id.as_str())
+        .collect();
+    assert_eq!(ranking, vec!["b", "c", "a"]);
+    assert_eq!(entry.candidates[0].idle_secs, 120);
+    assert!(entry.reason.contains("120"), "reason: {}", entry.reason);
+}
+
+#[test]
+fn round_robin_rotates_through_agents() {
+    let mut clock = clock();
+    let agents = vec![
+        available_agent(&clock, "a", 10),
+        available_agent(&clock, "b", 300),
+        available_agent(&clock, "c", 60),
+    ];
+    let mut engine = engine(RoutingStrategy::RoundRobin);
+
+    let mut assigned = Vec::new();
+    for n in 0..4 {
+        let id = format!("call-{}", n);
+        engine.enqueue(call(&id), clock.now()).unwrap();
+        let agent = offered_to(&mut engine, &clock, &agents).remove(0);
+        engine.accept(&id, &agent, clock.now()).unwrap();
+        assigned.push(agent);
+        clock.advance_secs(1);
+    }
+    assert_eq!(assigned, vec!["a", "b", "c", "a"]);
+}
+
+#[test]
+fn skill_based_prefers_highest_proficiency() {
+    let clock = clock();
+    let agents = vec![
+        available_agent(&clock, "junior", 600),
+        available_agent(&clock, "expert", 5),
+        available_agent(&clock, "unskilled", 900),
+    ];
+    let registry = billing_registry(&[("junior", 2), ("expert", 5)]);
+    let mut engine = engine(RoutingStrategy::SkillBased);
+    let mut interaction = call("call-1");
+    interaction.queue_id = Some("billing".to_string());
+    engine.enqueue(interaction, clock.now()).unwrap();
+
+    let outcome = engine.tick(clock.now(), &agents, &registry);
+    assert_eq!(outcome.offered[0].agent_id, "expert");
+
+    // 스킬 없는 상담원은 후보에서 제외
+    let entry = engine.log(Some(1)).pop().unwrap();
+    let ranking: Vec<(&str, Option<u8>)> = entry
+        .candidates
+        .iter()
+        .map(|c| (c.agent_id.as_str(), c.proficiency))
+        .collect();
+    assert_eq!(ranking, vec![("expert", Some(5)), ("junior", Some(2))]);
+}
+
+#[test]
+fn sticky_agent_returns_customer_to_previous_agent() {
+    let mut clock = clock();
+    let mut engine = engine(RoutingStrategy::StickyAgent);
+    let mut first = call("call-1");
+    first.customer_id = Some("customer-42".to_string());
+    engine.enqueue(first, clock.now()).unwrap();
+
+    let agents = vec![
+        available_agent(&clock, "a", 200),
+        available_agent(&clock, "b", 100),
+    ];
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["a"]);
+    engine.accept("call-1", "a", clock.now()).unwrap();
+
+    // 재문의 시점에는 b 가 더 오래 대기 중이지만 이전 상담원 a 에게 간다
+    clock.advance_secs(600);
+    let agents = vec![
+        available_agent(&clock, "a", 10),
+        available_agent(&clock, "b", 700),
+    ];
+    let mut again = call("call-2");
+    again.customer_id = Some("customer-42".to_string());
+    engine.enqueue(again, clock.now()).unwrap();
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["a"]);
+    assert_eq!(
+        engine.log(Some(1))[0].candidates[0].reason,
+        "이전 응대 상담원"
+    );
+
+    // 다른 고객은 평소처럼 오래 대기한 상담원에게
+    engine.enqueue(call("call-3"), clock.now()).unwrap();
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["b"]);
+}
+
+#[test]
+fn ring_timeout_falls_back_to_next_candidate() {
+    let mut clock = clock();
+    let agents = vec![
+        available_agent(&clock, "a", 300),
+        available_agent(&clock, "b", 100),
+    ];
+    let mut engine = engine(RoutingStrategy::LongestIdle);
+    engine.enqueue(call("call-1"), clock.now()).unwrap();
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["a"]);
+
+    // 타임아웃 전에는 변화 없음
+    clock.advance_secs(14);
+    assert_eq!(
+        engine.tick(clock.now(), &agents, &QueueRegistry::default()),
+        Default::default()
+    );
+
+    clock.advance_secs(1);
+    let outcome = engine.tick(clock.now(), &agents, &QueueRegistry::default());
+    assert_eq!(outcome.expired.len(), 1);
+    assert_eq!(outcome.expired[0].agent_id, "a");
+    assert_eq!(outcome.offered.len(), 1);
+    assert_eq!(outcome.offered[0].agent_id, "b");
+    assert_eq!(outcome.offered[0].attempt, 2);
+    assert_eq!(
+        outcome.offered[0].expires_at,
+        clock.now() + chrono::Duration::seconds(15)
+    );
+
+    let decisions: Vec<RoutingDecision> = engine.log(None).iter().map(|e| e.decision).collect();
+    assert_eq!(
+        decisions,
+        vec![
+            RoutingDecision::Enqueued,
+            RoutingDecision::Offered,
+            RoutingDecision::TimedOut,
+            RoutingDecision::Offered,
+        ]
+    );
+}
+
+#[test]
+fn rejected_by_every_candidate_starts_over() {
+    let clock = clock();
+    let agents = vec![
+        available_agent(&clock, "a", 300),
+        available_agent(&clock, "b", 100),
+    ];
+    let mut engine = engine(RoutingStrategy::LongestIdle);
+    engine.enqueue(call("call-1"), clock.now()).unwrap();
+
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["a"]);
+    engine.reject("call-1", "a", clock.now()).unwrap();
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["b"]);
+    engine.reject("call-1", "b", clock.now()).unwrap();
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["a"]);
+
+    assert!(engine
+        .log(None)
+        .iter()
+        .any(|e| e.decision == RoutingDecision::CandidatesExhausted));
+}
+
+#[test]
+fn waiting_without_agents_is_logged_once() {
+    let mut clock = clock();
+    let mut engine = engine(RoutingStrategy::LongestIdle);
+    engine.enqueue(call("call-1"), clock.now()).unwrap();
+
+    for _ in 0..3 {
+        assert!(offered_to(&mut engine, &clock, &[]).is_empty());
+        clock.advance_secs(1);
+    }
+    let waiting = engine
+        .log(None)
+        .iter()
+        .filter(|e| e.decision == RoutingDecision::NoCandidate)
+        .count();
+    assert_eq!(waiting, 1);
+    assert_eq!(engine.snapshot().waiting.len(), 1);
+
+    let agents = vec![available_agent(&clock, "a", 0)];
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["a"]);
+    assert!(engine.snapshot().waiting.is_empty());
+}
+
+#[test]
+fn calls_are_offered_in_arrival_order_to_distinct_agents() {
+    let mut clock = clock();
+    let mut engine = engine(RoutingStrategy::LongestIdle);
+    engine.enqueue(call("first"), clock.now()).unwrap();
+    clock.advance_secs(5);
+    engine.enqueue(call("second"), clock.now()).unwrap();
+
+    let agents = vec![
+        available_agent(&clock, "a", 300),
+        available_agent(&clock, "b", 100),
+    ];
+    let outcome = engine.tick(clock.now(), &agents, &QueueRegistry::default());
+    let pairs: Vec<(&str, &str)> = outcome
+        .offered
+        .iter()
+        .map(|o| (o.interaction.id.as_str(), o.agent_id.as_str()))
+        .collect();
+    assert_eq!(pairs, vec![("first", "a"), ("second", "b")]);
+
+    // 제안 중인 콜이 끊기면 제안도 철회
+    let (interaction, offer) = engine.abandon("second", clock.now()).unwrap();
+    assert_eq!(interaction.id, "second");
+    assert_eq!(offer.unwrap().agent_id, "b");
+    assert_eq!(engine.snapshot().offers.len(), 1);
+}
+
+#[test]
+fn simulated_agents_never_get_real_calls() {
+    let clock = clock();
+    let mut simulated = available_agent(&clock, "sim-1", 600);
+    simulated.simulated = true;
+    let agents = vec![simulated, available_agent(&clock, "a", 10)];
+    let mut engine = engine(RoutingStrategy::LongestIdle);
+    engine.enqueue(call("call-1"), clock.now()).unwrap();
+
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["a"]);
+}
+
+#[test]
+fn cancelled_offer_goes_back_ahead_of_later_calls() {
+    let mut clock = clock();
+    let mut engine = engine(RoutingStrategy::LongestIdle);
+    engine.enqueue(call("first"), clock.now()).unwrap();
+    let agents = vec![
+        available_agent(&clock, "a", 300),
+        available_agent(&clock, "b", 100),
+    ];
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["a"]);
+
+    // 호출 실패로 제안 철회 → 그 사이 들어온 콜보다 앞에, a 는 후보에서 제외
+    clock.advance_secs(1);
+    engine.enqueue(call("second"), clock.now()).unwrap();
+    engine
+        .cancel_offer("first", "a", clock.now(), "상담원 호출 실패".to_string())
+        .unwrap();
+    let waiting: Vec<String> = engine
+        .snapshot()
+        .waiting
+        .into_iter()
+        .map(|i| i.id)
+        .collect();
+    assert_eq!(waiting, vec!["first", "second"]);
+    assert!(engine.snapshot().offers.is_empty());
+
+    let outcome = engine.tick(clock.now(), &agents, &QueueRegistry::default());
+    let pairs: Vec<(&str, &str)> = outcome
+        .offered
+        .iter()
+        .map(|o| (o.interaction.id.as_str(), o.agent_id.as_str()))
+        .collect();
+    assert_eq!(pairs, vec![("first", "b"), ("second", "a")]);
+    assert_eq!(outcome.offered[0].attempt, 2);
+}
+
+#[test]
+fn accepted_call_that_fails_to_connect_is_requeued_not_lost() {
+    let mut clock = clock();
+    let mut engine = engine(RoutingStrategy::LongestIdle);
+    engine.enqueue(call("call-1"), clock.now()).unwrap();
+    let agents = vec![
+        available_agent(&clock, "a", 300),
+        available_agent(&clock, "b", 100),
+    ];
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["a"]);
+
+    clock.advance_secs(2);
+    let offer = engine.accept("call-1", "a", clock.now()).unwrap();
+    engine.requeue_accepted(&offer, clock.now(), "통화 연결 실패".to_string());
+    assert_eq!(engine.snapshot().waiting.len(), 1);
+    assert_eq!(engine.log(Some(1))[0].decision, RoutingDecision::Requeued);
+
+    assert_eq!(offered_to(&mut engine, &clock, &agents), vec!["b"]);
+    assert!(engine
+        .cancel_offer("call-1", "a", clock.now(), String::new())
+        .is_err());
+}
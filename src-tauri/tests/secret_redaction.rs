@@ -0,0 +1,219 @@
+// 토큰이 직렬화 / Debug 출력 / 로그인·갱신 이벤트(Tauri mock runtime)로 새지 않는지 검증
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::Utc;
+use common::MockServer;
+use nexus_call_hub_lib::state::audit_log::{AuditEvent, AuditLog};
+use nexus_call_hub_lib::state::auth_state::{
+    self, AuthState, User, TOKEN_REFRESHED_EVENT, USER_LOGGED_IN_EVENT,
+};
+use nexus_call_hub_lib::state::backend::BackendConfig;
+use nexus_call_hub_lib::state::secret::{Secret, REDACTED};
+use nexus_call_hub_lib::state::session_store::{PersistedSession, SessionStore};
+use nexus_call_hub_lib::state::token_refresh::RefreshedTokens;
+use std::path::PathBuf;
+use tauri::test::{mock_builder, mock_context, noop_assets, MockRuntime};
+use tauri::{App, Listener, Manager, WebviewUrl, WebviewWindowBuilder};
+
+const ACCESS: &str = "access-token-9f8e7d";
+const REFRESH: &str = "refresh-token-1a2b3c";
+
+fn user() -> User {
+    User {
+        id: "agent1".to_string(),
+        name: "상담원1".to_string(),
+        email: None,
+        department: "상담1팀".to_string(),
+        role: "agent".to_string(),
+        token: Secret::new(ACCESS.to_string()),
+        permissions: None,
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("nexus-secret-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+#[test]
+fn user_event_payload_masks_token() {
+    let json = serde_json::to_string(&user()).unwrap();
+    assert!(!json.contains(ACCESS));
+    assert!(json.contains(&format!(r#""token":"{}""#, REDACTED)));
+
+    let debug = format!("{:?}", user());
+    assert!(!debug.contains(ACCESS));
+    assert!(debug.contains(REDACTED));
+}
+
+#[test]
+fn frontend_login_payload_still_deserializes_token() {
+    let json = format!(
+        r#"{{"id":"agent1","name":"상담원1","email":null,"department":"상담1팀","role":"agent","token":"{}","permissions":null}}"#,
+        ACCESS
+    );
+    let user: User = serde_json::from_str(&json).unwrap();
+    assert_eq!(user.token.expose(), ACCESS);
+}
+
+#[test]
+fn refreshed_tokens_debug_is_masked() {
+    let tokens = RefreshedTokens {
+        access_token: ACCESS.to_string(),
+        refresh_token: Some(REFRESH.to_string()),
+    };
+    let debug = format!("{:?}", tokens);
+    assert!(!debug.contains(ACCESS));
+    assert!(!debug.contains(REFRESH));
+}
+
+#[test]
+fn audit_records_never_contain_token() {
+    let path = temp_path("audit_log.jsonl");
+    let _ = std::fs::remove_file(&path);
+    let mut log = AuditLog::open(path.clone(), &[7u8; 32]);
+    let record = log
+        .append(
+            AuditEvent::Login,
+            Some(user().id),
+            None,
+            Some("login".to_string()),
+            Utc::now(),
+        )
+        .unwrap();
+
+    assert!(!format!("{:?}", record).contains(ACCESS));
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains(ACCESS));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn persisted_session_round_trips_real_tokens() {
+    let path = temp_path("session.bin");
+    let store = SessionStore::new(path.clone(), b"test-machine");
+    store
+        .save(&PersistedSession::new(
+            user(),
+            Some(REFRESH.to_string()),
+            false,
+        ))
+        .unwrap();
+
+    let loaded = store.load().unwrap().unwrap();
+    assert_eq!(loaded.user.token.expose(), ACCESS);
+    assert_eq!(loaded.refresh_token.as_deref(), Some(REFRESH));
+    store.wipe().unwrap();
+}
+
+type Received = Arc<Mutex<Vec<(String, String)>>>;
+
+/// 서명 부분은 검사하지 않으므로 아무 값이나 붙인다
+fn jwt(sub: &str, expires_in_secs: i64) -> String {
+    let payload = serde_json::json!({
+        "sub": sub,
+        "exp": Utc::now().timestamp() + expires_in_secs,
+        "iat": Utc::now().timestamp(),
+    });
+    format!(
+        "{}.{}.signature",
+        URL_SAFE_NO_PAD.encode(br#"{"alg":"HS256","typ":"JWT"}"#),
+        URL_SAFE_NO_PAD.encode(payload.to_string())
+    )
+}
+
+fn create_app(server: &MockServer) -> App<MockRuntime> {
+    let auth = AuthState::default();
+    *auth.backend.lock().unwrap() = BackendConfig {
+        base_url: server.base_url.clone(),
+        // 로그인 직후 바로 갱신하도록 만료보다 길게 잡는다
+        refresh_ahead_secs: 3600,
+        ..BackendConfig::default()
+    };
+    mock_builder()
+        .manage(auth)
+        .build(mock_context(noop_assets()))
+        .expect("failed to build mock app")
+}
+
+/// 모든 윈도우에 오는 로그인 / 갱신 이벤트를 (이벤트 이름, payload) 로 수집
+fn listen(app: &App<MockRuntime>) -> Received {
+    let received: Received = Arc::new(Mutex::new(Vec::new()));
+    for event in [USER_LOGGED_IN_EVENT, TOKEN_REFRESHED_EVENT] {
+        let received = received.clone();
+        app.listen_any(event, move |e| {
+            received
+                .lock()
+                .unwrap()
+                .push((event.to_string(), e.payload().to_string()));
+        });
+    }
+    received
+}
+
+#[test]
+fn login_and_refresh_events_never_contain_tokens() {
+    let access = jwt("agent1", 600);
+    // 갱신된 토큰은 만료가 멀어서 다시 갱신되지 않는다
+    let rotated_access = jwt("agent1", 7200);
+    let login_body = format!(
+        r#"{{"success":true,"message":"ok","data":{{"token":"{}","refreshToken":"{}"}}}}"#,
+        access, REFRESH
+    );
+    let refresh_body = format!(
+        r#"{{"success":true,"message":"ok","data":{{"token":"{}","refreshToken":"refresh-rotated-4d5e"}}}}"#,
+        rotated_access
+    );
+    let server = MockServer::start(vec![(200, &login_body), (200, &refresh_body)]);
+    let app = create_app(&server);
+    let window = WebviewWindowBuilder::new(&app, "login", WebviewUrl::default())
+        .build()
+        .expect("failed to build mock window");
+    let received = listen(&app);
+
+    let session_user = tauri::async_runtime::block_on(auth_state::login(
+        app.handle().clone(),
+        window.as_ref().window(),
+        "agent1".to_string(),
+        "correct horse".to_string(),
+    ))
+    .unwrap();
+    assert!(!serde_json::to_string(&session_user)
+        .unwrap()
+        .contains(&access));
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !received
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(event, _)| event == TOKEN_REFRESHED_EVENT)
+    {
+        assert!(
+            Instant::now() < deadline,
+            "token-refreshed 이벤트가 오지 않음"
+        );
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    assert_eq!(
+        app.state::<AuthState>().bearer_token(Utc::now()).as_deref(),
+        Some(rotated_access.as_str())
+    );
+
+    let received = received.lock().unwrap();
+    assert!(received
+        .iter()
+        .any(|(event, _)| event == USER_LOGGED_IN_EVENT));
+    for (event, payload) in received.iter() {
+        for secret in [&access, &rotated_access, REFRESH, "refresh-rotated-4d5e"] {
+            assert!(!payload.contains(secret), "{} payload: {}", event, payload);
+        }
+        assert!(payload.contains(REDACTED), "{} payload: {}", event, payload);
+    }
+}
@@ -0,0 +1,105 @@
+// 세션 파일 암호화: 저장 / 복원 왕복, 변조 거부, 다른 키로 복호화 거부, 용도별 키 분리
+use std::path::PathBuf;
+
+use nexus_call_hub_lib::state::auth_state::User;
+use nexus_call_hub_lib::state::keystore::derive_key;
+use nexus_call_hub_lib::state::secret::Secret;
+use nexus_call_hub_lib::state::session_store::{PersistedSession, SessionStore};
+
+const MASTER_KEY: [u8; 32] = [7u8; 32];
+
+fn user() -> User {
+    User {
+        id: "kim".to_string(),
+        name: "김상담".to_string(),
+        email: None,
+        department: "고객상담팀".to_string(),
+        role: "상담원".to_string(),
+        token: Secret::new("header.payload.signature".to_string()),
+        permissions: None,
+    }
+}
+
+fn session_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "session-store-test-{}-{}",
+        name,
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join("session.bin")
+}
+
+fn saved(name: &str) -> SessionStore {
+    let store = SessionStore::new(session_path(name), &MASTER_KEY);
+    store
+        .save(&PersistedSession::new(
+            user(),
+            Some("refresh-token".to_string()),
+            true,
+        ))
+        .unwrap();
+    store
+}
+
+#[test]
+fn saved_session_loads_back_with_the_real_tokens() {
+    let store = saved("round-trip");
+    let bytes = std::fs::read(session_path("round-trip")).unwrap();
+    let plain = String::from_utf8_lossy(&bytes);
+    assert!(!plain.contains("header.payload.signature"));
+    assert!(!plain.contains("refresh-token"));
+
+    let session = store.load().unwrap().unwrap();
+    assert_eq!(session.user.id, "kim");
+    assert_eq!(session.user.token.expose(), "header.payload.signature");
+    assert_eq!(session.refresh_token.as_deref(), Some("refresh-token"));
+    assert!(session.locked);
+
+    store.wipe().unwrap();
+    assert!(store.load().unwrap().is_none());
+}
+
+#[test]
+fn tampered_file_is_rejected() {
+    let store = saved("tamper");
+    let path = session_path("tamper");
+    let original = std::fs::read(&path).unwrap();
+
+    // 암호문 / nonce / 헤더 어느 바이트를 바꿔도 복호화되지 않는다
+    for index in [original.len() - 1, 20, 4, 0] {
+        let mut bytes = original.clone();
+        bytes[index] ^= 0x01;
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(store.load().is_err(), "byte {}", index);
+    }
+
+    std::fs::write(&path, &original[..10]).unwrap();
+    assert!(store.load().is_err());
+    store.wipe().unwrap();
+}
+
+#[test]
+fn a_different_install_key_cannot_decrypt() {
+    let store = saved("wrong-key");
+    let other = SessionStore::new(session_path("wrong-key"), &[8u8; 32]);
+    assert!(other.load().is_err());
+    store.wipe().unwrap();
+}
+
+#[test]
+fn derived_keys_depend_on_master_key_and_context() {
+    let session = derive_key(&MASTER_KEY, b"nexus-call-hub/session/v2");
+    assert_eq!(
+        *session,
+        *derive_key(&MASTER_KEY, b"nexus-call-hub/session/v2")
+    );
+    assert_ne!(
+        *session,
+        *derive_key(&MASTER_KEY, b"nexus-call-hub/other/v1")
+    );
+    assert_ne!(
+        *session,
+        *derive_key(&[8u8; 32], b"nexus-call-hub/session/v2")
+    );
+}
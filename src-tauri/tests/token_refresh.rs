@@ -0,0 +1,110 @@
+// 토큰 갱신 클라이언트를 로컬 mock 서버로 검증
+mod common;
+
+use common::MockServer;
+use nexus_call_hub_lib::state::backend::{BackendConfig, RetryPolicy};
+use nexus_call_hub_lib::state::token_refresh::{
+    refresh_with_backoff, request_refresh, RefreshError, RefreshedTokens,
+};
+
+const REFRESHED: &str =
+    r#"{"success":true,"message":"ok","data":{"token":"access-2","refreshToken":"refresh-2"}}"#;
+
+fn config(server: &MockServer, max_attempts: u32) -> BackendConfig {
+    BackendConfig {
+        base_url: server.base_url.clone(),
+        retry: RetryPolicy {
+            max_attempts,
+            initial_backoff_millis: 10,
+            max_backoff_millis: 40,
+        },
+        ..BackendConfig::default()
+    }
+}
+
+fn refresh(config: &BackendConfig) -> Result<RefreshedTokens, RefreshError> {
+    let client = reqwest::Client::new();
+    tauri::async_runtime::block_on(refresh_with_backoff(&client, config, "refresh-1"))
+}
+
+#[test]
+fn refresh_posts_token_and_returns_rotated_pair() {
+    let server = MockServer::start(vec![(200, REFRESHED)]);
+    let client = reqwest::Client::new();
+    let tokens =
+        tauri::async_runtime::block_on(request_refresh(&client, &config(&server, 1), "refresh-1"))
+            .unwrap();
+
+    assert_eq!(
+        tokens,
+        RefreshedTokens {
+            access_token: "access-2".to_string(),
+            refresh_token: Some("refresh-2".to_string()),
+        }
+    );
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/auth/refresh");
+    assert_eq!(requests[0].body, r#"{"refreshToken":"refresh-1"}"#);
+}
+
+#[test]
+fn refresh_without_rotation_keeps_no_new_refresh_token() {
+    let server = MockServer::start(vec![(
+        200,
+        r#"{"success":true,"message":"ok","data":{"token":"access-2"}}"#,
+    )]);
+    let tokens = refresh(&config(&server, 1)).unwrap();
+    assert_eq!(tokens.access_token, "access-2");
+    assert_eq!(tokens.refresh_token, None);
+}
+
+#[test]
+fn server_errors_are_retried_until_success() {
+    let server = MockServer::start(vec![(503, "{}"), (500, "{}"), (200, REFRESHED)]);
+    let tokens = refresh(&config(&server, 5)).unwrap();
+    assert_eq!(tokens.access_token, "access-2");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn gives_up_after_max_attempts() {
+    let server = MockServer::start(vec![(502, "{}")]);
+    let result = refresh(&config(&server, 3));
+    assert!(matches!(result, Err(RefreshError::Transient(_))), "{:?}", result);
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn rejected_refresh_is_not_retried() {
+    let server = MockServer::start(vec![(401, r#"{"success":false,"message":"expired"}"#)]);
+    let result = refresh(&config(&server, 5));
+    assert!(matches!(result, Err(RefreshError::Rejected(_))), "{:?}", result);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn unsuccessful_api_response_is_rejected() {
+    let server = MockServer::start(vec![(
+        200,
+        r#"{"success":false,"message":"refresh token revoked"}"#,
+    )]);
+    let result = refresh(&config(&server, 5));
+    assert_eq!(
+        result,
+        Err(RefreshError::Rejected("refresh token revoked".to_string()))
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn backoff_doubles_and_is_capped() {
+    let policy = RetryPolicy {
+        max_attempts: 6,
+        initial_backoff_millis: 100,
+        max_backoff_millis: 500,
+    };
+    let waits: Vec<u128> = (1..=5).map(|n| policy.backoff(n).as_millis()).collect();
+    assert_eq!(waits, vec![100, 200, 400, 500, 500]);
+}
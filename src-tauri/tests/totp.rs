@@ -0,0 +1,257 @@
+// 2단계 인증: RFC 6238 테스트 벡터 / 시계 오차 허용 / 코드 재사용 거부 / 복구 코드 1회용 / 사용자별 실패 횟수 / 등록 파일 암호화와 평문 파일 이전
+mod common;
+
+use std::path::PathBuf;
+
+use chrono::Duration;
+use data_encoding::BASE32_NOPAD;
+
+use common::now;
+use nexus_call_hub_lib::state::totp::{
+    hotp, match_step, time_step, totp_at, SecondFactorOutcome, TotpStore, LEGACY_TOTP_FILE_NAME,
+    MAX_SECOND_FACTOR_ATTEMPTS, TOTP_FILE_NAME, TOTP_PERIOD_SECS,
+};
+
+/// RFC 6238 Appendix B 의 SHA-1 키 (ASCII "12345678901234567890")
+const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+const MASTER_KEY: &[u8] = &[7u8; 32];
+
+fn totp_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("totp-test-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join(TOTP_FILE_NAME)
+}
+
+/// 등록까지 마친 저장소와 비밀 키
+fn enrolled(user_id: &str) -> (TotpStore, Vec<u8>, Vec<String>) {
+    let mut store = TotpStore::default();
+    let enrollment = store.begin_enrollment(user_id, user_id, now()).unwrap();
+    let secret = BASE32_NOPAD.decode(enrollment.secret.as_bytes()).unwrap();
+    // 등록 확인은 이전 구간 코드로 해서 `now()` 구간 코드는 아직 안 쓴 상태로 둔다
+    let previous = now() - Duration::seconds(TOTP_PERIOD_SECS);
+    let code = totp_at(&secret, previous.timestamp());
+    assert!(store.confirm_enrollment(user_id, &code, now()).unwrap());
+    assert!(store.is_enrolled(user_id));
+    (store, secret, enrollment.recovery_codes)
+}
+
+#[test]
+fn rfc6238_appendix_b_sha1_vectors() {
+    let vectors = [
+        (59, "94287082"),
+        (1111111109, "07081804"),
+        (1111111111, "14050471"),
+        (1234567890, "89005924"),
+        (2000000000, "69279037"),
+        (20000000000, "65353130"),
+    ];
+    for (unix_secs, expected) in vectors {
+        assert_eq!(
+            hotp(RFC_SECRET, time_step(unix_secs), 8),
+            expected,
+            "T = {}",
+            unix_secs
+        );
+    }
+}
+
+#[test]
+fn six_digit_code_is_the_low_digits_of_the_rfc_value() {
+    assert_eq!(totp_at(RFC_SECRET, 59), "287082");
+    assert_eq!(totp_at(RFC_SECRET, 1111111109), "081804");
+}
+
+#[test]
+fn codes_one_step_off_are_accepted_and_two_steps_off_are_not() {
+    let at = now().timestamp();
+    let current = time_step(at);
+    for offset in [-1i64, 0, 1] {
+        let code = totp_at(RFC_SECRET, at + offset * TOTP_PERIOD_SECS);
+        assert_eq!(
+            match_step(RFC_SECRET, &code, at),
+            Some((current as i64 + offset) as u64),
+            "offset {}",
+            offset
+        );
+    }
+    for offset in [-2i64, 2] {
+        let code = totp_at(RFC_SECRET, at + offset * TOTP_PERIOD_SECS);
+        assert_eq!(match_step(RFC_SECRET, &code, at), None, "offset {}", offset);
+    }
+}
+
+#[test]
+fn malformed_codes_never_match() {
+    let at = now().timestamp();
+    let code = totp_at(RFC_SECRET, at);
+    assert_eq!(
+        match_step(RFC_SECRET, &format!(" {} ", code), at),
+        Some(time_step(at))
+    );
+    assert_eq!(match_step(RFC_SECRET, &code[..5], at), None);
+    assert_eq!(match_step(RFC_SECRET, "abcdef", at), None);
+}
+
+#[test]
+fn a_code_from_an_already_used_step_is_rejected() {
+    let (mut store, secret, _) = enrolled("lee");
+    let code = totp_at(&secret, now().timestamp());
+    assert_eq!(
+        store.verify_code("lee", &code, now()).unwrap(),
+        SecondFactorOutcome::Verified
+    );
+    // 같은 코드를 다시 쓰는 것도, 이미 지난 구간의 코드도 거부
+    assert_eq!(
+        store.verify_code("lee", &code, now()).unwrap(),
+        SecondFactorOutcome::Rejected {
+            remaining: MAX_SECOND_FACTOR_ATTEMPTS - 1
+        }
+    );
+    let earlier = totp_at(
+        &secret,
+        (now() - Duration::seconds(TOTP_PERIOD_SECS)).timestamp(),
+    );
+    assert!(matches!(
+        store.verify_code("lee", &earlier, now()).unwrap(),
+        SecondFactorOutcome::Rejected { .. }
+    ));
+    // 다음 구간 코드는 통과
+    let later = now() + Duration::seconds(TOTP_PERIOD_SECS);
+    let next = totp_at(&secret, later.timestamp());
+    assert_eq!(
+        store.verify_code("lee", &next, later).unwrap(),
+        SecondFactorOutcome::Verified
+    );
+}
+
+#[test]
+fn recovery_codes_work_once_each() {
+    let (mut store, _, recovery_codes) = enrolled("lee");
+    let total = recovery_codes.len();
+    let code = &recovery_codes[0];
+
+    assert_eq!(
+        store
+            .verify_recovery_code("lee", &code.to_uppercase())
+            .unwrap(),
+        SecondFactorOutcome::Verified
+    );
+    assert_eq!(store.remaining_recovery_codes("lee"), total - 1);
+    assert!(matches!(
+        store.verify_recovery_code("lee", code).unwrap(),
+        SecondFactorOutcome::Rejected { .. }
+    ));
+    assert_eq!(
+        store
+            .verify_recovery_code("lee", &recovery_codes[1])
+            .unwrap(),
+        SecondFactorOutcome::Verified
+    );
+    assert_eq!(store.remaining_recovery_codes("lee"), total - 2);
+}
+
+#[test]
+fn failures_are_counted_per_user() {
+    let (mut store, secret, _) = enrolled("lee");
+    let enrollment = store.begin_enrollment("park", "park", now()).unwrap();
+    let park_secret = BASE32_NOPAD.decode(enrollment.secret.as_bytes()).unwrap();
+    let code = totp_at(&park_secret, now().timestamp());
+    assert!(store.confirm_enrollment("park", &code, now()).unwrap());
+
+    for attempt in 1..MAX_SECOND_FACTOR_ATTEMPTS {
+        assert_eq!(
+            store.verify_recovery_code("lee", "wrong-code").unwrap(),
+            SecondFactorOutcome::Rejected {
+                remaining: MAX_SECOND_FACTOR_ATTEMPTS - attempt
+            }
+        );
+    }
+    assert_eq!(store.failed_attempts("lee"), MAX_SECOND_FACTOR_ATTEMPTS - 1);
+    assert_eq!(store.failed_attempts("park"), 0);
+
+    // 다른 사용자의 실패가 내 남은 시도 횟수를 깎지 않는다
+    assert_eq!(
+        store.verify_recovery_code("park", "wrong-code").unwrap(),
+        SecondFactorOutcome::Rejected {
+            remaining: MAX_SECOND_FACTOR_ATTEMPTS - 1
+        }
+    );
+    assert_eq!(
+        store.verify_recovery_code("lee", "wrong-code").unwrap(),
+        SecondFactorOutcome::Exhausted
+    );
+
+    // 성공하면 그 사용자만 초기화
+    let code = totp_at(&secret, now().timestamp());
+    assert_eq!(
+        store.verify_code("lee", &code, now()).unwrap(),
+        SecondFactorOutcome::Verified
+    );
+    assert_eq!(store.failed_attempts("lee"), 0);
+    assert_eq!(store.failed_attempts("park"), 1);
+}
+
+#[test]
+fn a_wrong_enrollment_code_leaves_the_user_unenrolled() {
+    let mut store = TotpStore::default();
+    let enrollment = store.begin_enrollment("lee", "lee", now()).unwrap();
+    let secret = BASE32_NOPAD.decode(enrollment.secret.as_bytes()).unwrap();
+    let wrong = (0..)
+        .map(|n| format!("{:06}", n))
+        .find(|code| match_step(&secret, code, now().timestamp()).is_none())
+        .unwrap();
+    assert!(!store.confirm_enrollment("lee", &wrong, now()).unwrap());
+    assert!(!store.is_enrolled("lee"));
+    assert_eq!(store.failed_attempts("lee"), 0);
+}
+
+#[test]
+fn enrollment_file_is_encrypted_and_reloads_only_with_the_same_key() {
+    let path = totp_path("encrypted");
+    let mut store = TotpStore::load(path.clone(), MASTER_KEY);
+    let enrollment = store.begin_enrollment("kim", "kim", now()).unwrap();
+    let secret = BASE32_NOPAD.decode(enrollment.secret.as_bytes()).unwrap();
+    let code = totp_at(&secret, now().timestamp());
+    assert!(store.confirm_enrollment("kim", &code, now()).unwrap());
+
+    let bytes = std::fs::read(&path).unwrap();
+    let contents = String::from_utf8_lossy(&bytes);
+    assert!(!contents.contains(&enrollment.secret));
+    assert!(!contents.contains("kim"));
+
+    let reloaded = TotpStore::load(path.clone(), MASTER_KEY);
+    assert!(reloaded.is_enrolled("kim"));
+    assert_eq!(
+        reloaded.remaining_recovery_codes("kim"),
+        enrollment.recovery_codes.len()
+    );
+
+    // 다른 설치의 키로는 읽을 수 없다
+    assert!(!TotpStore::load(path, &[9u8; 32]).is_enrolled("kim"));
+}
+
+#[test]
+fn plaintext_enrollment_file_is_migrated_and_removed() {
+    let path = totp_path("legacy");
+    let legacy = path.with_file_name(LEGACY_TOTP_FILE_NAME);
+    let secret = BASE32_NOPAD.encode(RFC_SECRET);
+    let settings = serde_json::json!({
+        "users": {
+            "kim": {
+                "secret": secret,
+                "confirmed": true,
+                "created_at": now(),
+                "recovery_codes": [],
+            }
+        }
+    });
+    std::fs::write(&legacy, serde_json::to_vec(&settings).unwrap()).unwrap();
+
+    let store = TotpStore::load(path.clone(), MASTER_KEY);
+    assert!(store.is_enrolled("kim"));
+    assert!(!legacy.exists());
+    assert!(!String::from_utf8_lossy(&std::fs::read(&path).unwrap()).contains(&secret));
+    assert!(TotpStore::load(path, MASTER_KEY).is_enrolled("kim"));
+}
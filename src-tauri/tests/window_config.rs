@@ -0,0 +1,134 @@
+// 윈도우 정의 레지스트리: 포함된 windows.json 파싱, label 중복 / 잘못된 id / 잘못된 권한 거부, 사용자 설정 적용
+use std::collections::BTreeMap;
+
+use nexus_call_hub_lib::window::config::{WindowOverride, WindowRegistry};
+use nexus_call_hub_lib::window::types::WindowType;
+
+fn window(label: &str, access: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "label": label,
+        "title": label,
+        "url": format!("{}.html", label),
+        "width": 800,
+        "height": 600,
+        "min_width": 400,
+        "min_height": 300,
+        "access": access
+    })
+}
+
+fn parse(windows: serde_json::Value) -> Result<WindowRegistry, String> {
+    WindowRegistry::parse(&serde_json::json!({ "windows": windows }).to_string())
+}
+
+fn window_type(id: &str) -> WindowType {
+    serde_json::from_value(serde_json::json!(id)).unwrap()
+}
+
+fn registry() -> WindowRegistry {
+    parse(serde_json::json!({
+        "Login": window("login", serde_json::json!({ "type": "public" })),
+        "QueueMonitor": window("queue_monitor", serde_json::json!({ "type": "authenticated" }))
+    }))
+    .unwrap()
+}
+
+#[test]
+fn bundled_windows_json_is_valid() {
+    let registry = WindowRegistry::bundled().unwrap();
+    for id in ["Launcher", "Login", "QueueMonitor", "Statistics"] {
+        assert!(registry.get(&window_type(id)).is_some(), "{}", id);
+    }
+    let (id, _) = registry.find_by_label("login").unwrap();
+    assert_eq!(id.as_str(), "Login");
+    assert!(registry.layouts().count() > 0);
+}
+
+#[test]
+fn duplicate_labels_are_rejected() {
+    let error = parse(serde_json::json!({
+        "Login": window("main", serde_json::json!({ "type": "public" })),
+        "Launcher": window("main", serde_json::json!({ "type": "authenticated" }))
+    }))
+    .unwrap_err();
+    assert!(error.contains("label 'main'"), "{}", error);
+}
+
+#[test]
+fn ids_and_labels_must_be_plain_identifiers() {
+    let error = parse(serde_json::json!({
+        "Queue-Monitor": window("queue_monitor", serde_json::json!({ "type": "public" }))
+    }))
+    .unwrap_err();
+    assert!(error.contains("'Queue-Monitor': id"), "{}", error);
+
+    let error = parse(serde_json::json!({
+        "QueueMonitor": window("queue monitor", serde_json::json!({ "type": "public" }))
+    }))
+    .unwrap_err();
+    assert!(error.contains("label 'queue monitor'"), "{}", error);
+
+    assert!(parse(serde_json::json!({})).is_err());
+}
+
+#[test]
+fn invalid_permission_strings_are_rejected() {
+    for permission in ["calls::dial", "calls:dia*", ""] {
+        let result = parse(serde_json::json!({
+            "CallOutbound": window(
+                "call_outbound",
+                serde_json::json!({ "type": "permission", "permission": permission })
+            )
+        }));
+        assert!(result.is_err(), "{:?}", permission);
+    }
+}
+
+#[test]
+fn every_problem_is_reported_at_once() {
+    let mut broken = window("bad label", serde_json::json!({ "type": "public" }));
+    broken["url"] = serde_json::json!("https://example.com/page.html");
+    broken["min_width"] = serde_json::json!(2000);
+    let error = parse(serde_json::json!({ "Broken": broken })).unwrap_err();
+    assert_eq!(error.split("; ").count(), 3, "{}", error);
+}
+
+#[test]
+fn overrides_change_display_settings_only_for_known_windows() {
+    let registry = registry();
+    let overrides = BTreeMap::from([(
+        window_type("QueueMonitor"),
+        WindowOverride {
+            title: Some("대기열 현황".to_string()),
+            width: Some(1200.0),
+            ..WindowOverride::default()
+        },
+    )]);
+    let merged = registry.with_overrides(&overrides).unwrap();
+    let definition = merged.get(&window_type("QueueMonitor")).unwrap();
+    assert_eq!(definition.title, "대기열 현황");
+    assert_eq!(definition.width, 1200.0);
+    assert_eq!(definition.height, 600.0);
+    // 원본은 그대로
+    assert_eq!(
+        registry.get(&window_type("QueueMonitor")).unwrap().width,
+        800.0
+    );
+
+    let unknown = BTreeMap::from([(window_type("Statistics"), WindowOverride::default())]);
+    let error = registry.with_overrides(&unknown).unwrap_err();
+    assert!(error.contains("Statistics"), "{}", error);
+}
+
+#[test]
+fn override_that_breaks_a_definition_is_rejected() {
+    // 최소 크기 400x300 보다 작게 줄이는 설정
+    let overrides = BTreeMap::from([(
+        window_type("Login"),
+        WindowOverride {
+            width: Some(200.0),
+            ..WindowOverride::default()
+        },
+    )]);
+    assert!(registry().with_overrides(&overrides).is_err());
+}
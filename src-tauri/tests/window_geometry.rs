@@ -0,0 +1,95 @@
+// 윈도우 배치 보정: 저장된 모니터 / 겹치는 모니터 / 주 모니터 순서, 큰 윈도우 축소, 왼쪽 보조 모니터의 음수 좌표
+use nexus_call_hub_lib::window::geometry::{clamp_to_monitors, MonitorArea, Rect, WindowGeometry};
+
+fn rect(x: i32, y: i32, width: u32, height: u32) -> Rect {
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// 주 모니터 1920x1040 (작업 표시줄 제외) + 왼쪽 보조 모니터 1280x984
+fn monitors() -> Vec<MonitorArea> {
+    vec![
+        MonitorArea {
+            name: Some("DISPLAY1".to_string()),
+            work_area: rect(0, 0, 1920, 1040),
+        },
+        MonitorArea {
+            name: Some("DISPLAY2".to_string()),
+            work_area: rect(-1280, 0, 1280, 984),
+        },
+    ]
+}
+
+fn geometry(bounds: Rect, monitor: Option<&str>) -> WindowGeometry {
+    WindowGeometry {
+        bounds,
+        maximized: false,
+        monitor: monitor.map(str::to_string),
+    }
+}
+
+#[test]
+fn window_inside_its_remembered_monitor_is_unchanged() {
+    let saved = geometry(rect(-1200, 100, 800, 600), Some("DISPLAY2"));
+    assert_eq!(clamp_to_monitors(&saved, &monitors()), saved);
+}
+
+#[test]
+fn negative_origin_on_a_left_monitor_is_kept_inside_it() {
+    // 왼쪽 모니터 밖으로 걸친 윈도우는 그 모니터 왼쪽 / 위쪽 경계로 붙인다
+    let saved = geometry(rect(-1400, -50, 800, 600), Some("DISPLAY2"));
+    let clamped = clamp_to_monitors(&saved, &monitors());
+    assert_eq!(clamped.bounds, rect(-1280, 0, 800, 600));
+    assert_eq!(clamped.monitor.as_deref(), Some("DISPLAY2"));
+
+    // 오른쪽 끝에 걸치면 주 모니터로 넘어가지 않고 보조 모니터 오른쪽 경계까지만
+    let saved = geometry(rect(-300, 200, 800, 600), Some("DISPLAY2"));
+    assert_eq!(
+        clamp_to_monitors(&saved, &monitors()).bounds,
+        rect(-800, 200, 800, 600)
+    );
+}
+
+#[test]
+fn missing_monitor_falls_back_to_the_most_overlapping_one() {
+    // 저장된 모니터가 사라졌지만 윈도우 대부분이 왼쪽 모니터에 걸쳐 있다
+    let saved = geometry(rect(-700, 900, 800, 300), Some("DISPLAY3"));
+    let clamped = clamp_to_monitors(&saved, &monitors());
+    assert_eq!(clamped.monitor.as_deref(), Some("DISPLAY2"));
+    assert_eq!(clamped.bounds, rect(-800, 684, 800, 300));
+}
+
+#[test]
+fn window_on_no_monitor_moves_to_the_primary() {
+    let saved = geometry(rect(5000, 3000, 800, 600), Some("DISPLAY3"));
+    let clamped = clamp_to_monitors(&saved, &monitors());
+    assert_eq!(clamped.monitor.as_deref(), Some("DISPLAY1"));
+    assert_eq!(clamped.bounds, rect(1120, 440, 800, 600));
+}
+
+#[test]
+fn oversized_window_is_shrunk_to_the_work_area() {
+    let saved = WindowGeometry {
+        maximized: true,
+        ..geometry(rect(-100, -100, 2560, 1440), Some("DISPLAY1"))
+    };
+    let clamped = clamp_to_monitors(&saved, &monitors());
+    assert_eq!(clamped.bounds, rect(0, 0, 1920, 1040));
+    assert!(clamped.maximized);
+
+    let on_left = geometry(rect(-1280, 0, 1920, 1080), Some("DISPLAY2"));
+    assert_eq!(
+        clamp_to_monitors(&on_left, &monitors()).bounds,
+        rect(-1280, 0, 1280, 984)
+    );
+}
+
+#[test]
+fn without_monitor_information_nothing_changes() {
+    let saved = geometry(rect(5000, 3000, 800, 600), Some("DISPLAY3"));
+    assert_eq!(clamp_to_monitors(&saved, &[]), saved);
+}
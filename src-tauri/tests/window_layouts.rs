@@ -0,0 +1,165 @@
+// 작업 레이아웃: 이름 검사 / 기본 레이아웃 이름 거부 / 중복 윈도우 거부 / 사용자별 개수 제한 / 덮어쓰기 / 로드 시 재검사
+use std::path::PathBuf;
+
+use nexus_call_hub_lib::window::config::WindowRegistry;
+use nexus_call_hub_lib::window::layouts::{
+    normalize_layout_name, validate_saved_layout_name, LayoutStore, LayoutWindow, WorkspaceLayout,
+    MAX_LAYOUTS_PER_USER, MAX_LAYOUT_NAME_CHARS,
+};
+use nexus_call_hub_lib::window::types::WindowType;
+
+fn registry() -> WindowRegistry {
+    WindowRegistry::bundled().unwrap()
+}
+
+fn window_type(id: &str) -> WindowType {
+    serde_json::from_value(serde_json::json!(id)).unwrap()
+}
+
+fn layout(windows: &[&str]) -> WorkspaceLayout {
+    WorkspaceLayout {
+        windows: windows
+            .iter()
+            .map(|id| LayoutWindow {
+                window: window_type(id),
+                geometry: None,
+                fullscreen: false,
+            })
+            .collect(),
+        saved_at: None,
+    }
+}
+
+fn layouts_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "window-layouts-test-{}-{}",
+        name,
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join("window_layouts.json")
+}
+
+#[test]
+fn layout_names_are_trimmed_and_length_checked() {
+    assert_eq!(normalize_layout_name("  오전 근무 ").unwrap(), "오전 근무");
+    assert!(normalize_layout_name("   ").is_err());
+
+    let longest = "가".repeat(MAX_LAYOUT_NAME_CHARS);
+    assert_eq!(normalize_layout_name(&longest).unwrap(), longest);
+    assert!(normalize_layout_name(&format!("{}가", longest)).is_err());
+}
+
+#[test]
+fn builtin_layout_names_cannot_be_reused() {
+    let registry = registry();
+    assert!(validate_saved_layout_name("Inbound desk", &registry).is_err());
+    assert!(validate_saved_layout_name(" Supervisor wall ", &registry).is_err());
+    assert_eq!(
+        validate_saved_layout_name("My desk", &registry).unwrap(),
+        "My desk"
+    );
+}
+
+#[test]
+fn layouts_need_distinct_known_windows() {
+    let registry = registry();
+    assert!(layout(&["CallInbound", "QueueMonitor"])
+        .validate(&registry)
+        .is_ok());
+    assert!(layout(&[]).validate(&registry).is_err());
+    assert!(layout(&["QueueMonitor", "CallInbound", "QueueMonitor"])
+        .validate(&registry)
+        .is_err());
+}
+
+#[test]
+fn each_user_can_save_a_limited_number_of_layouts() {
+    let mut store = LayoutStore::default();
+    for index in 0..MAX_LAYOUTS_PER_USER {
+        store
+            .save(
+                "kim",
+                &format!("layout {}", index),
+                layout(&["QueueMonitor"]),
+            )
+            .unwrap();
+    }
+    assert!(store
+        .save("kim", "one too many", layout(&["QueueMonitor"]))
+        .is_err());
+    assert!(store.get("kim", "one too many").is_none());
+    assert_eq!(store.user_layouts("kim").count(), MAX_LAYOUTS_PER_USER);
+
+    // 가득 차도 같은 이름 덮어쓰기와 다른 사용자 저장은 된다
+    store
+        .save("kim", "layout 0", layout(&["Statistics"]))
+        .unwrap();
+    store
+        .save("lee", "layout 0", layout(&["QueueMonitor"]))
+        .unwrap();
+}
+
+#[test]
+fn saving_under_an_existing_name_overwrites_it() {
+    let mut store = LayoutStore::default();
+    store
+        .save("kim", "desk", layout(&["QueueMonitor"]))
+        .unwrap();
+    store
+        .save("kim", "desk", layout(&["CallInbound", "Statistics"]))
+        .unwrap();
+
+    assert_eq!(store.user_layouts("kim").count(), 1);
+    assert_eq!(
+        store.get("kim", "desk"),
+        Some(&layout(&["CallInbound", "Statistics"]))
+    );
+    assert!(store.get("lee", "desk").is_none());
+}
+
+#[test]
+fn saved_layouts_round_trip_through_the_file() {
+    let registry = registry();
+    let path = layouts_path("round-trip");
+    let mut store = LayoutStore::load(path.clone(), &registry);
+    store
+        .save("kim", "desk", layout(&["CallInbound", "QueueMonitor"]))
+        .unwrap();
+
+    let loaded = LayoutStore::load(path.clone(), &registry);
+    assert_eq!(
+        loaded.get("kim", "desk"),
+        Some(&layout(&["CallInbound", "QueueMonitor"]))
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn invalid_layouts_are_dropped_on_load() {
+    let registry = registry();
+    let path = layouts_path("revalidate");
+    let desk = serde_json::json!({ "windows": [{ "window": "QueueMonitor" }] });
+    std::fs::write(
+        &path,
+        serde_json::json!({
+            "users": {
+                "kim": {
+                    "desk": desk,
+                    "Inbound desk": desk,
+                    "empty": { "windows": [] },
+                    "twice": { "windows": [{ "window": "Statistics" }, { "window": "Statistics" }] }
+                },
+                "lee": { "empty": { "windows": [] } }
+            }
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    let store = LayoutStore::load(path.clone(), &registry);
+    let names: Vec<&String> = store.user_layouts("kim").map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["desk"]);
+    assert_eq!(store.user_layouts("lee").count(), 0);
+    std::fs::remove_file(path).unwrap();
+}
@@ -3,6 +3,7 @@ import { useState, useEffect } from "react"
 import { invoke } from "@tauri-apps/api/core"
 import { listen } from "@tauri-apps/api/event"
 import { Button } from "@/shared/ui/button"
+import LockScreen from "@/widgets/LockScreen"
 
 interface User {
     id: string
@@ -297,6 +298,7 @@ function CallOutboundApp() {
 
     return (
         <div className="min-h-screen bg-gray-50 flex flex-col">
+            <LockScreen />
             {/* Header */}
             <header className="bg-white border-b border-gray-200 px-6 py-4">
                 <div className="flex items-center justify-between">
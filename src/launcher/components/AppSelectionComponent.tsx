@@ -2,6 +2,7 @@
 import { invoke } from "@tauri-apps/api/core"
 import { Button } from "@/shared/ui/button"
 import { useUser } from "@/shared/hooks/useUser"  // 🔐 새로 추가
+import { useWorkspaceLayouts } from "@/shared/hooks/useWorkspaceLayouts"
 import CommonHeader from "@/widgets/CommonHeader"
 
 // 상담사용 앱들: 요청에 따라 "챗봇 테스트", "공유 업무 정보"만 노출
@@ -111,8 +112,16 @@ function AppCard({ app }: AppCardProps) {
                 fromLabel: 'launcher',
                 toWindowType: app.windowType
             })
-        } catch (error) {
+        } catch (error: any) {
+            // window/access.rs 의 WindowError: { kind: 'unknown' | 'unauthenticated' | 'locked' | 'forbidden' | 'failed', ... }
             console.error(`${app.label} 윈도우 전환 실패:`, error)
+            if (error?.kind === 'forbidden') {
+                alert(`${app.label}: 접근 권한이 없습니다 (${error.permission})`)
+            } else if (error?.kind === 'unauthenticated') {
+                alert('로그인이 필요합니다. 다시 로그인해주세요.')
+            } else if (error?.kind === 'locked') {
+                alert('세션이 잠겨 있습니다. 잠금을 해제해주세요.')
+            }
         }
     }
 
@@ -145,6 +154,64 @@ function AppCard({ app }: AppCardProps) {
     )
 }
 
+// 🧩 작업 레이아웃 (기본 + 내가 저장한 배치)
+function WorkspaceLayoutsSection() {
+    const { layouts, applyLayout, saveLayout } = useWorkspaceLayouts()
+
+    const handleApply = async (name: string) => {
+        try {
+            await applyLayout(name)
+        } catch (error: any) {
+            console.error(`레이아웃 '${name}' 적용 실패:`, error)
+            if (error?.kind === 'forbidden') {
+                alert(`${name}: 접근 권한이 없습니다 (${error.permission})`)
+            } else {
+                alert(error?.message ?? `${error}`)
+            }
+        }
+    }
+
+    const handleSave = async () => {
+        const name = prompt('지금 열려 있는 윈도우 배치를 저장할 이름')
+        if (!name) return
+        try {
+            await saveLayout(name)
+        } catch (error) {
+            alert(`레이아웃 저장 실패: ${error}`)
+        }
+    }
+
+    return (
+        <section className="mb-8">
+            <h3 className="text-sm font-medium text-gray-700 mb-4 flex items-center gap-2">
+                <span className="w-2 h-2 bg-green-500 rounded-full"></span>
+                작업 레이아웃
+                <Button className="ml-auto h-7 text-xs" variant="outline" onClick={handleSave}>
+                    현재 배치 저장
+                </Button>
+            </h3>
+            <div className="grid grid-cols-2 gap-4">
+                {layouts.map(layout => (
+                    <Button
+                        key={`${layout.builtin}-${layout.name}`}
+                        className="h-auto py-3 flex flex-col items-start gap-1 bg-white border border-gray-200 rounded-xl"
+                        variant="outline"
+                        disabled={layout.denied.length > 0}
+                        title={layout.denied.length > 0 ? `권한 없음: ${layout.denied.join(', ')}` : undefined}
+                        onClick={() => handleApply(layout.name)}
+                    >
+                        <div className="text-sm font-medium text-gray-900">
+                            {layout.name}
+                            {layout.builtin && <span className="ml-2 text-xs text-gray-400">기본</span>}
+                        </div>
+                        <div className="text-xs text-gray-500">{layout.windows.join(' · ')}</div>
+                    </Button>
+                ))}
+            </div>
+        </section>
+    )
+}
+
 function AppSelectionComponent() {  // 🔧 props 제거
     const { user, isLoading } = useUser();  // 🔐 useUser 훅 사용
 
@@ -209,6 +276,8 @@ function AppSelectionComponent() {  // 🔧 props 제거
                         </p>
                     </div>
 
+                    <WorkspaceLayoutsSection />
+
                     {/* 상담 앱들 */}
                     <section className="mb-8">
                         <h3 className="text-sm font-medium text-gray-700 mb-4 flex items-center gap-2">
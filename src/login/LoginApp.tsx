@@ -4,22 +4,33 @@ import { User } from "@/shared/api/types"
 import { useState } from "react"
 import LoginComponent from "@/widgets/LoginForm/LoginComponent"
 import SignupComponent from "@/widgets/SignupForm/SignupComponent"
+import SecondFactorForm from "@/widgets/SecondFactorForm"
 
-type ViewMode = 'login' | 'signup'
+type ViewMode = 'login' | 'signup' | 'second_factor'
 
 function LoginApp() {
     const [viewMode, setViewMode] = useState<ViewMode>('login')
 
-    const handleLoginSuccess = async (user: User) => {
-        console.log('🎯 LoginApp: 로그인 성공 콜백 호출됨', user)
+    const handleLoginSuccess = async (session: User) => {
+        console.log('🎯 LoginApp: 로그인 성공 콜백 호출됨', session)
 
         try {
-            // 🎯 auth_state.rs의 set_user_state 활용
-            console.log('📝 사용자 정보를 Tauri AuthState에 저장 중...')
-            await invoke('set_user_state', { user })
-            console.log('✅ Tauri AuthState 저장 완료')
+            // 세션은 Rust 의 login / offline_login 명령이 이미 열었다
 
-            // 🚀 로그인 성공 후 런처로 이동
+            // 🔐 슈퍼바이저 / 관리자는 2단계 인증을 통과해야 런처를 열 수 있다
+            if (session.second_factor_pending) {
+                setViewMode('second_factor')
+                return
+            }
+            await openLauncher()
+        } catch (error) {
+            console.error('❌ 로그인 후 처리 실패:', error)
+        }
+    }
+
+    // 🚀 로그인 성공 후 런처로 이동
+    const openLauncher = async () => {
+        try {
             console.log('🔄 런처 윈도우로 전환 시작...')
             try {
                 console.log('🔄 switch_window(Login → Launcher) 시도')
@@ -57,10 +68,16 @@ function LoginApp() {
                 alert('윈도우 전환에 실패했습니다. 수동으로 런처를 열어주세요.')
             }
         } catch (error) {
-            console.error('❌ 로그인 후 처리 실패:', error)
+            console.error('❌ 런처 전환 실패:', error)
         }
     }
 
+    // 2단계 인증을 포기하면 대기 중인 세션을 끝내고 로그인 화면으로
+    const cancelSecondFactor = async () => {
+        await invoke('logout_user').catch(() => {})
+        setViewMode('login')
+    }
+
     // 회원가입 성공 후 로그인 페이지로 이동
     const handleSignupSuccess = () => {
         setViewMode('login')
@@ -75,6 +92,10 @@ function LoginApp() {
         setViewMode('login')
     }
 
+    if (viewMode === 'second_factor') {
+        return <SecondFactorForm onVerified={openLauncher} onCancel={cancelSecondFactor} />
+    }
+
     return (
         <>
             {viewMode === 'login' ? (
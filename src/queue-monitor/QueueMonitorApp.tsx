@@ -1,8 +1,9 @@
 // C:\pilot-tauri\nexus-call-hub\src\queue-monitor\QueueMonitorApp.tsx
-import { useState, useEffect } from "react"
+import { useState, useEffect, useRef } from "react"
 import { invoke } from "@tauri-apps/api/core"
 import { listen } from "@tauri-apps/api/event"
 import { Button } from "@/shared/ui/button"
+import LockScreen from "@/widgets/LockScreen"
 
 interface User {
     id: string
@@ -23,11 +24,35 @@ interface QueueStatus {
 interface AgentInfo {
     id: string
     name: string
-    status: 'available' | 'busy' | 'break' | 'offline'
+    status: 'available' | 'ringing' | 'busy' | 'wrap_up' | 'break' | 'offline'
+    breakReason?: 'rest' | 'lunch' | 'meeting' | 'training' | 'coaching' | 'personal' | null
+    statusSince?: string
     currentCall?: string
     callDuration?: number
+    simulated?: boolean
 }
 
+interface QueueSummary {
+    queue: { id: string, name: string, skill?: string | null }
+    status: QueueStatus
+    agents: { available: number, ringing: number, busy: number, wrap_up: number, break: number, offline: number }
+    kpis: { calls_waiting: number, fifteen_minutes: { service_level: number | null } }
+}
+
+// 순번이 붙은 변경분 (Rust `QueueDelta`)
+type QueueDelta = { seq: number, at: string } & (
+    | { type: 'queue_status', queue_id: string | null, patch: Partial<QueueStatus> }
+    | { type: 'queue_removed', queue_id: string }
+    | { type: 'agent', agent: AgentInfo }
+    | { type: 'agent_removed', agent_id: string }
+    // 대기열 정의는 값 없이 알리기만 한다 (조회는 명령으로)
+    | { type: 'queue_definitions' }
+)
+
+type QueueResync =
+    | { type: 'deltas', seq: number, deltas: QueueDelta[] }
+    | { type: 'snapshot', seq: number, status: QueueStatus, agents: AgentInfo[] }
+
 function QueueMonitorApp() {
     const [user, setUser] = useState<User | null>(null)
     const [queueStatus, setQueueStatus] = useState<QueueStatus>({
@@ -39,7 +64,11 @@ function QueueMonitorApp() {
         outbound_calls_today: 0
     })
     const [agents, setAgents] = useState<AgentInfo[]>([])
+    const [queues, setQueues] = useState<QueueSummary[]>([])
     const [lastUpdate, setLastUpdate] = useState(new Date())
+    // 마지막으로 반영한 delta seq (null = 아직 동기화 전)
+    const seqRef = useRef<number | null>(null)
+    const resyncingRef = useRef(false)
 
     useEffect(() => {
         // 사용자 정보 가져오기
@@ -47,7 +76,6 @@ function QueueMonitorApp() {
             try {
                 const userData = await invoke('get_user')
                 setUser(userData as User)
-                loadSampleData()
             } catch (error) {
                 console.error("사용자 정보 가져오기 실패:", error)
             }
@@ -55,72 +83,98 @@ function QueueMonitorApp() {
 
         fetchUser()
 
+        // 대기열(스킬 그룹)별 현황. delta 가 올 때마다 다시 조회 (조회 중에 온 delta 는 한 번으로 합친다)
+        let queuesLoading = false
+        let queuesStale = false
+        const refreshQueues = async () => {
+            if (queuesLoading) {
+                queuesStale = true
+                return
+            }
+            queuesLoading = true
+            try {
+                do {
+                    queuesStale = false
+                    setQueues(await invoke<QueueSummary[]>('list_queues'))
+                } while (queuesStale)
+            } catch (error) {
+                console.error("대기열 목록 가져오기 실패:", error)
+            } finally {
+                queuesLoading = false
+            }
+        }
+        refreshQueues()
+
         // 이벤트 리스너 등록
         const unlistenUser = listen('user-logged-out', () => {
             setUser(null)
         })
 
-        const unlistenQueue = listen('queue-status-updated', (event) => {
-            setQueueStatus(event.payload as QueueStatus)
+        const applyDelta = (delta: QueueDelta) => {
+            if (delta.type === 'queue_status' && delta.queue_id === null) {
+                setQueueStatus(prev => ({ ...prev, ...delta.patch }))
+            } else if (delta.type === 'agent') {
+                const updated = delta.agent
+                setAgents(prev => prev.some(a => a.id === updated.id)
+                    ? prev.map(a => a.id === updated.id ? updated : a)
+                    : [...prev, updated])
+            } else if (delta.type === 'agent_removed') {
+                setAgents(prev => prev.filter(a => a.id !== delta.agent_id))
+            }
+            seqRef.current = delta.seq
+        }
+
+        // 빠진 seq 가 있으면 그 이후 변경분(또는 전체 스냅샷)으로 따라잡기
+        const resync = async () => {
+            if (resyncingRef.current) return
+            resyncingRef.current = true
+            try {
+                const result = await invoke<QueueResync>('get_queue_snapshot_since', { seq: seqRef.current })
+                if (result.type === 'snapshot') {
+                    setQueueStatus(result.status)
+                    setAgents(result.agents)
+                    seqRef.current = result.seq
+                } else {
+                    result.deltas.forEach(applyDelta)
+                    seqRef.current = result.seq
+                }
+                refreshQueues()
+                setLastUpdate(new Date())
+            } catch (error) {
+                console.error("대기열 재동기화 실패:", error)
+            } finally {
+                resyncingRef.current = false
+            }
+        }
+
+        resync()
+
+        const unlistenDelta = listen('queue-delta', (event) => {
+            const delta = event.payload as QueueDelta
+            if (resyncingRef.current || seqRef.current === null) return
+            if (delta.seq <= seqRef.current) return
+            if (delta.seq !== seqRef.current + 1) {
+                resync()
+                return
+            }
+            applyDelta(delta)
+            refreshQueues()
             setLastUpdate(new Date())
         })
 
-        // 실시간 업데이트 시뮬레이션
-        const interval = setInterval(() => {
-            updateSampleData()
-        }, 5000)
+        // KPI 는 `queue-kpis-updated` 로 온다. 대기열별 KPI 도 바뀌었을 수 있으므로 다시 조회
+        const unlistenKpis = listen('queue-kpis-updated', () => {
+            refreshQueues()
+            setLastUpdate(new Date())
+        })
 
         return () => {
             unlistenUser.then(f => f())
-            unlistenQueue.then(f => f())
-            clearInterval(interval)
+            unlistenDelta.then(f => f())
+            unlistenKpis.then(f => f())
         }
     }, [])
 
-    const loadSampleData = () => {
-        // 샘플 상담원 데이터
-        const sampleAgents: AgentInfo[] = [
-            { id: '1', name: '김상담', status: 'busy', currentCall: '010-1234-5678', callDuration: 245 },
-            { id: '2', name: '이상담', status: 'available' },
-            { id: '3', name: '박상담', status: 'busy', currentCall: '010-9876-5432', callDuration: 89 },
-            { id: '4', name: '최상담', status: 'break' },
-            { id: '5', name: '정상담', status: 'available' },
-            { id: '6', name: '한상담', status: 'offline' }
-        ]
-
-        setAgents(sampleAgents)
-
-        // 초기 대기열 상태
-        setQueueStatus({
-            inbound_waiting: 8,
-            inbound_agents_available: 2,
-            inbound_agents_total: 6,
-            outbound_active_campaigns: 3,
-            outbound_calls_in_progress: 12,
-            outbound_calls_today: 247
-        })
-    }
-
-    const updateSampleData = () => {
-        // 실시간 데이터 업데이트 시뮬레이션
-        setQueueStatus(prev => ({
-            ...prev,
-            inbound_waiting: Math.max(0, prev.inbound_waiting + Math.floor(Math.random() * 3) - 1),
-            outbound_calls_in_progress: Math.max(0, prev.outbound_calls_in_progress + Math.floor(Math.random() * 3) - 1),
-            outbound_calls_today: prev.outbound_calls_today + Math.floor(Math.random() * 2)
-        }))
-
-        // 상담원 상태 업데이트
-        setAgents(prev => prev.map(agent => {
-            if (agent.status === 'busy' && agent.callDuration) {
-                return { ...agent, callDuration: agent.callDuration + 5 }
-            }
-            return agent
-        }))
-
-        setLastUpdate(new Date())
-    }
-
     const formatTime = (seconds: number) => {
         const mins = Math.floor(seconds / 60)
         const secs = seconds % 60
@@ -149,7 +203,9 @@ function QueueMonitorApp() {
     const getStatusColor = (status: string) => {
         switch (status) {
             case 'available': return 'text-green-600 bg-green-100'
+            case 'ringing': return 'text-blue-600 bg-blue-100'
             case 'busy': return 'text-red-600 bg-red-100'
+            case 'wrap_up': return 'text-purple-600 bg-purple-100'
             case 'break': return 'text-yellow-600 bg-yellow-100'
             case 'offline': return 'text-gray-600 bg-gray-100'
             default: return 'text-gray-600 bg-gray-100'
@@ -159,7 +215,9 @@ function QueueMonitorApp() {
     const getStatusText = (status: string) => {
         switch (status) {
             case 'available': return '대기중'
+            case 'ringing': return '호출중'
             case 'busy': return '통화중'
+            case 'wrap_up': return '후처리'
             case 'break': return '휴식중'
             case 'offline': return '오프라인'
             default: return '알수없음'
@@ -172,6 +230,7 @@ function QueueMonitorApp() {
 
     return (
         <div className="min-h-screen bg-gray-50 flex flex-col">
+            <LockScreen />
             {/* Header */}
             <header className="bg-white border-b border-gray-200 px-6 py-4">
                 <div className="flex items-center justify-between">
@@ -282,10 +341,44 @@ function QueueMonitorApp() {
                     </div>
                 </div>
 
+                {/* 대기열별 현황 */}
+                {queues.length > 0 && (
+                    <div className="mt-6 bg-white rounded-lg border border-gray-200 p-6">
+                        <h2 className="text-lg font-semibold text-gray-900 mb-4">대기열별 현황</h2>
+
+                        <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4">
+                            {queues.map(({ queue, status, agents: counts, kpis }) => (
+                                <div key={queue.id} className="border border-gray-200 rounded-lg p-4">
+                                    <div className="flex items-center justify-between mb-3">
+                                        <span className="font-medium">{queue.name}</span>
+                                        <span className="text-xs text-gray-500">{queue.skill ?? queue.id}</span>
+                                    </div>
+                                    <div className="grid grid-cols-2 gap-2 text-sm">
+                                        <div className="text-gray-600">대기 고객</div>
+                                        <div className="text-right font-medium text-red-600">{status.inbound_waiting}</div>
+                                        <div className="text-gray-600">대기 / 통화 상담원</div>
+                                        <div className="text-right font-medium">{counts.available} / {counts.busy}</div>
+                                        <div className="text-gray-600">서비스 레벨 (15분)</div>
+                                        <div className="text-right font-medium">
+                                            {kpis.fifteen_minutes.service_level === null
+                                                ? '-'
+                                                : `${(kpis.fifteen_minutes.service_level * 100).toFixed(1)}%`}
+                                        </div>
+                                    </div>
+                                </div>
+                            ))}
+                        </div>
+                    </div>
+                )}
+
                 {/* 상담원 상태 목록 */}
                 <div className="mt-6 bg-white rounded-lg border border-gray-200 p-6">
                     <h2 className="text-lg font-semibold text-gray-900 mb-4">상담원 현황</h2>
 
+                    {agents.length === 0 && (
+                        <div className="text-sm text-gray-500">아직 보고된 상담원 상태가 없습니다</div>
+                    )}
+
                     <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4">
                         {agents.map((agent) => (
                             <div key={agent.id} className="border border-gray-200 rounded-lg p-4">
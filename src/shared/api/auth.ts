@@ -1,13 +1,9 @@
 // C:\pilot-tauri\nexus-call-hub\src\launcher\api\auth.ts
 
 import { apiClient } from "./client"
-import { ApiResponse, LoginRequest, LoginResponseData, UserInfo, SignupRequest, SignupResponseData } from "./types"
+import { ApiResponse, UserInfo, SignupRequest, SignupResponseData } from "./types"
 
-// 로그인 API
-export const loginApi = async (credentials: LoginRequest): Promise<ApiResponse<LoginResponseData>> => {
-    const response = await apiClient.post<ApiResponse<LoginResponseData>>('/auth/login', credentials)
-    return response.data
-}
+// 로그인은 토큰이 웹뷰로 오지 않도록 Rust 의 `login` 명령으로 한다 (shared/hooks/useAuth.ts)
 
 // 회원가입 API
 export const signupApi = async (userData: SignupRequest): Promise<ApiResponse<SignupResponseData>> => {
@@ -1,7 +1,56 @@
 // C:\nexus-call-hub\src\shared\api\client.ts
-import axios from 'axios'
+import axios, { AxiosError, AxiosHeaders, AxiosResponse, InternalAxiosRequestConfig } from 'axios'
+import { invoke } from '@tauri-apps/api/core'
 import { getAuthToken } from '../auth/token'
 
+const isTauri = () => typeof window !== 'undefined' && (window as any).__TAURI_INTERNALS__ !== undefined
+
+// Rust api_proxy.rs 의 ApiResponse
+interface ProxyResponse {
+    status: number
+    headers: Record<string, string>
+    body: string
+}
+
+// 🔐 Tauri 에서는 Rust 의 api_request 로 요청 (토큰은 Rust 가 붙이고 웹뷰로 나오지 않음)
+const tauriAdapter = async (config: InternalAxiosRequestConfig): Promise<AxiosResponse> => {
+    const headers: Record<string, string> = {}
+    Object.entries(AxiosHeaders.from(config.headers).toJSON()).forEach(([key, value]) => {
+        if (value !== undefined && value !== null && key.toLowerCase() !== 'authorization') {
+            headers[key] = String(value)
+        }
+    })
+
+    const proxied = await invoke<ProxyResponse>('api_request', {
+        request: {
+            method: config.method?.toUpperCase(),
+            url: apiClient.getUri(config),
+            headers,
+            body: config.data === undefined || config.data === null ? null : String(config.data),
+        }
+    })
+
+    const response: AxiosResponse = {
+        data: proxied.body,
+        status: proxied.status,
+        statusText: String(proxied.status),
+        headers: proxied.headers,
+        config,
+        request: null,
+    }
+    const validateStatus = config.validateStatus ?? ((status: number) => status >= 200 && status < 300)
+    if (!validateStatus(proxied.status)) {
+        throw new AxiosError(
+            `Request failed with status code ${proxied.status}`,
+            proxied.status >= 500 ? AxiosError.ERR_BAD_RESPONSE : AxiosError.ERR_BAD_REQUEST,
+            config,
+            null,
+            response
+        )
+    }
+    return response
+}
+
 // 환경별 API 서버 설정
 const getApiBaseUrl = () => {
     // 개발 환경 체크 (Vite 개발 서버)
@@ -13,12 +62,12 @@ const getApiBaseUrl = () => {
     // Tauri 앱 환경 체크
     if (typeof window !== 'undefined' && (window as any).__TAURI__) {
         console.log('📱 Tauri 앱 환경 - EC2 서버 사용')
-        return 'http://43.200.234.52:8080'
+        return 'https://43.200.234.52'
     }
 
     // 기본값 (브라우저에서 빌드된 것)
     console.log('🌐 브라우저 환경 - EC2 서버 사용')
-    return 'http://43.200.234.52:8080'
+    return 'https://43.200.234.52'
 };
 
 // API 베이스 설정
@@ -29,12 +78,13 @@ export const apiClient = axios.create({
         'Accept': 'application/json; charset=utf-8',
     },
     timeout: 10000, // 10초 타임아웃
+    ...(isTauri() ? { adapter: tauriAdapter } : {}),
 })
 
-// 요청 인터셉터 (토큰 자동 추가)
+// 요청 인터셉터 (브라우저 개발 환경에서만 토큰 직접 추가. Tauri 는 Rust 가 처리)
 apiClient.interceptors.request.use(
     (config) => {
-        const token = getAuthToken()
+        const token = isTauri() ? null : getAuthToken()
         if (token) {
             config.headers = config.headers ?? {}
             ;(config.headers as any).Authorization = `Bearer ${token}`
@@ -19,6 +19,7 @@ export interface LoginResponseData {
     message: string
     token: string
     tokenType: string
+    refreshToken?: string
 }
 
 // 회원가입 요청 타입 (백엔드 SignupRequest에 맞춤)
@@ -50,6 +51,17 @@ export interface User {
     role: string;
     token: string;
     permissions?: string[];   // 선택적
+    impersonated_by?: Impersonator | null;  // 대리 세션이면 원래 사용자 (Rust 가 채움)
+    offline?: boolean;        // 오프라인 로그인 세션 (Rust 가 채움)
+    second_factor_pending?: boolean;  // 2단계 인증 대기 중 (Rust 가 채움)
+}
+
+// Rust auth_state.rs 의 Impersonator
+export interface Impersonator {
+    id: string;
+    name: string;
+    role: string;
+    started_at: string;
 }
 
 // Company Chat API 타입들
@@ -1,20 +1,21 @@
-const KEY = 'auth_token'
+// 토큰은 Rust AuthState 가 암호화된 세션 파일로 보관한다.
+// 프론트엔드는 메모리에만 들고 있고 localStorage 에는 남기지 않는다.
+let currentToken: string | null = null
+
+// 이전 버전이 localStorage 에 남긴 값 정리
+try {
+  localStorage.removeItem('auth_token')
+  localStorage.removeItem('auth_user')
+} catch {
+  // ignore storage errors
+}
 
 export function getAuthToken(): string | null {
-  try {
-    return localStorage.getItem(KEY)
-  } catch {
-    return null
-  }
+  return currentToken
 }
 
 export function setAuthToken(token: string | null) {
-  try {
-    if (token) localStorage.setItem(KEY, token)
-    else localStorage.removeItem(KEY)
-  } catch {
-    // ignore storage errors
-  }
+  currentToken = token
 }
 
 export function clearAuthToken() {
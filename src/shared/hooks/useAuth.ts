@@ -1,29 +1,22 @@
 // C:\pilot-tauri\nexus-call-hub\src\launcher\hooks\useAuth.ts
 import { useMutation, useQuery } from '@tanstack/react-query'
-import { loginApi, getCurrentUserApi, validateTokenApi } from '../api/auth'
-import { User } from '../api/types'
+import { getCurrentUserApi, validateTokenApi } from '../api/auth'
+import { LoginRequest, User } from '../api/types'
 import { invoke } from '@tauri-apps/api/core'
 
-// 로그인 뮤테이션
-export const useLogin = () => {
-    return useMutation({
-        mutationFn: loginApi,
-        onSuccess: async (data) => {
-            if (data.success && data.data) {
-                // 백엔드 응답을 Tauri State 형식으로 변환
-                const user: User = {
-                    id: data.data.username, // 임시로 username을 id로 사용
-                    name: data.data.username,
-                    department: '고객상담팀', // 추후 백엔드에서 받아올 예정
-                    role: '상담원', // 추후 백엔드에서 받아올 예정
-                    token: data.data.token
-                }
-
-                // Tauri State에 사용자 정보 저장
-                await invoke('login_user', { user })
+// Rust `LoginError` (unreachable: 서버에 닿지 않음 / rejected: 자격 증명 거부)
+export interface LoginError {
+    kind: 'unreachable' | 'rejected'
+    message: string
+}
 
-                console.log('로그인 성공 및 Tauri State 저장 완료')
-            }
+// 로그인 뮤테이션. Rust 가 서버에 직접 로그인하고 토큰은 Rust 에만 남는다
+// (오프라인 로그인용 자격 증명도 Rust 가 해시로 저장)
+export const useLogin = () => {
+    return useMutation<User, LoginError, LoginRequest>({
+        mutationFn: (credentials) => invoke<User>('login', { ...credentials }),
+        onSuccess: (user) => {
+            console.log('로그인 성공 및 Tauri State 저장 완료:', user.name)
         },
         onError: (error) => {
             console.error('로그인 실패:', error)
@@ -0,0 +1,67 @@
+// C:\pilot-tauri\nexus-call-hub\src\shared\hooks\useSecondFactor.ts
+import { useCallback, useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+
+// Rust totp.rs 의 SecondFactorStatus
+export interface SecondFactorStatus {
+    required: boolean;
+    enrolled: boolean;
+    pending: boolean;
+    remaining_recovery_codes: number;
+    failed_attempts: number;
+}
+
+// Rust totp.rs 의 TotpEnrollment (비밀 키 / 복구 코드는 이때 한 번만 내려온다)
+export interface TotpEnrollment {
+    secret: string;
+    otpauth_uri: string;
+    qr_svg: string;
+    recovery_codes: string[];
+}
+
+export type SecondFactorCredential =
+    | { type: 'totp'; code: string }
+    | { type: 'recovery_code'; code: string };
+
+// 🔐 2단계 인증 (인증 앱 등록 / 코드 확인)
+export const useSecondFactor = () => {
+    const [status, setStatus] = useState<SecondFactorStatus | null>(null);
+
+    const refresh = useCallback(async () => {
+        try {
+            setStatus(await invoke<SecondFactorStatus>('get_second_factor_status'));
+        } catch (error) {
+            console.error('2단계 인증 상태 조회 실패:', error);
+        }
+    }, []);
+
+    useEffect(() => {
+        refresh();
+    }, [refresh]);
+
+    // 등록은 2단계 인증까지 마친 세션에서만 가능. userId 를 주면 관리자가 대신 등록 (security:mfa:manage)
+    const beginEnrollment = (userId?: string) =>
+        invoke<TotpEnrollment>('begin_totp_enrollment', { userId });
+
+    // 등록 확인. 틀리면 false
+    const confirmEnrollment = async (code: string, userId?: string): Promise<boolean> => {
+        const confirmed = await invoke<boolean>('confirm_totp_enrollment', { code, userId });
+        await refresh();
+        return confirmed;
+    };
+
+    // 로그인 후 확인. 틀리면 false, 시도 횟수 초과 시 예외 (로그아웃됨)
+    const verify = async (credential: SecondFactorCredential): Promise<boolean> => {
+        const verified = await invoke<boolean>('verify_second_factor', { credential });
+        await refresh();
+        return verified;
+    };
+
+    return {
+        status,
+        beginEnrollment,
+        confirmEnrollment,
+        verify,
+        refresh,
+    };
+};
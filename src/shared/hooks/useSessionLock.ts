@@ -0,0 +1,86 @@
+// C:\pilot-tauri\nexus-call-hub\src\shared\hooks\useSessionLock.ts
+import { useCallback, useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+
+// Rust idle_lock.rs 의 SessionLocked
+export interface SessionLocked {
+    locked_at: string;
+    reason: 'idle' | 'manual' | 'restored';
+}
+
+// Rust idle_lock.rs 의 LockStatus
+export interface LockStatus {
+    locked: SessionLocked | null;
+    enabled: boolean;
+    idle_timeout_secs: number;
+    idle_secs: number;
+    has_pin: boolean;
+    failed_attempts: number;
+}
+
+export type UnlockCredential =
+    | { type: 'pin'; pin: string }
+    | { type: 'password'; password: string };
+
+// 활동 보고 간격 (너무 자주 invoke 하지 않도록)
+const ACTIVITY_REPORT_INTERVAL_MS = 10_000;
+const ACTIVITY_EVENTS = ['mousemove', 'mousedown', 'keydown', 'wheel', 'touchstart'] as const;
+
+// 🔒 자리 비움 잠금 상태 + 사용자 활동 보고
+export const useSessionLock = () => {
+    const [status, setStatus] = useState<LockStatus | null>(null);
+
+    const refresh = useCallback(async () => {
+        try {
+            setStatus(await invoke<LockStatus>('get_lock_status'));
+        } catch (error) {
+            console.error('잠금 상태 조회 실패:', error);
+        }
+    }, []);
+
+    useEffect(() => {
+        refresh();
+
+        const unlistenLocked = listen<SessionLocked>('session-locked', (event) => {
+            console.log('🔒 세션 잠금:', event.payload.reason);
+            setStatus(prev => prev ? { ...prev, locked: event.payload } : prev);
+            refresh();
+        });
+        const unlistenUnlocked = listen('session-unlocked', () => refresh());
+        const unlistenLogout = listen('user-logged-out', () => refresh());
+
+        let lastReported = 0;
+        const onActivity = () => {
+            const now = Date.now();
+            if (now - lastReported < ACTIVITY_REPORT_INTERVAL_MS) return;
+            lastReported = now;
+            invoke('report_activity').catch(() => {});
+        };
+        ACTIVITY_EVENTS.forEach(name => window.addEventListener(name, onActivity, { passive: true }));
+
+        return () => {
+            unlistenLocked.then(f => f());
+            unlistenUnlocked.then(f => f());
+            unlistenLogout.then(f => f());
+            ACTIVITY_EVENTS.forEach(name => window.removeEventListener(name, onActivity));
+        };
+    }, [refresh]);
+
+    // 잠금 해제. 틀리면 false, 시도 횟수 초과 시 예외 (로그아웃됨)
+    const unlock = async (credential: UnlockCredential): Promise<boolean> => {
+        const unlocked = await invoke<boolean>('unlock_session', { credential });
+        await refresh();
+        return unlocked;
+    };
+
+    const lock = () => invoke('lock_session');
+
+    return {
+        status,
+        isLocked: !!status?.locked,
+        unlock,
+        lock,
+        refresh,
+    };
+};
@@ -1,66 +1,28 @@
 // C:\nexus-call-hub\src\shared\hooks\useUser.ts
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
-import { useEffect } from 'react';
+import { useEffect, useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 import { User } from '../api/types';
-import { setAuthToken, clearAuthToken } from '../auth/token';
-
-const USER_STORAGE_KEY = 'auth_user';
-
-function getStoredUser(): User | null {
-    try {
-        const raw = localStorage.getItem(USER_STORAGE_KEY);
-        return raw ? (JSON.parse(raw) as User) : null;
-    } catch {
-        return null;
-    }
-}
-
-function setStoredUser(user: User | null) {
-    try {
-        if (user) localStorage.setItem(USER_STORAGE_KEY, JSON.stringify(user));
-        else localStorage.removeItem(USER_STORAGE_KEY);
-    } catch {
-        // ignore storage errors
-    }
-}
+import { clearAuthToken } from '../auth/token';
 
 export function useUser() {
     const queryClient = useQueryClient();
+    // 토큰 만료 임박 시 남은 초 (session-expiring 이벤트)
+    const [sessionExpiresIn, setSessionExpiresIn] = useState<number | null>(null);
 
     // 🔐 사용자 정보 조회 (auth_state.rs 시스템 활용)
     const userQuery = useQuery({
         queryKey: ['user'],
         queryFn: async (): Promise<User | null> => {
-            try {
-                // auth_state.rs의 get_user_state 활용
-                const tauriUser = await invoke('get_user_state');
-                if (tauriUser) {
-                    console.log('✅ Tauri AuthState에서 사용자 정보 조회:', tauriUser);
-                    return tauriUser as User;
-                }
-            } catch (error) {
-                console.warn('⚠️ Tauri AuthState 조회 실패, 로컬 스토리지 시도:', error);
+            // 세션은 Rust AuthState 가 암호화 파일에서 복원한다 (localStorage 사용 안 함)
+            const tauriUser = await invoke('get_user_state');
+            if (tauriUser) {
+                console.log('✅ Tauri AuthState에서 사용자 정보 조회:', tauriUser);
+                return tauriUser as User;
             }
-
-            // Tauri AuthState에 없으면 로컬 스토리지에서 시도
-            const localUser = getStoredUser();
-            if (localUser) {
-                console.log('✅ 로컬 스토리지에서 사용자 정보 조회:', localUser);
-                // 로컬 스토리지에 있으면 Tauri AuthState에도 저장
-                try {
-                    await invoke('set_user_state', { user: localUser });
-                    console.log('✅ 로컬 스토리지 → Tauri AuthState 동기화 완료');
-                } catch (syncError) {
-                    console.warn('⚠️ Tauri AuthState 동기화 실패:', syncError);
-                }
-                return localUser;
-            }
-
             return null;
         },
-        initialData: getStoredUser(),
         staleTime: 1000, // 1초로 줄여서 더 자주 확인
         gcTime: 10 * 60 * 1000,   // 10분
         retry: 1, // 재시도 횟수 제한
@@ -75,22 +37,38 @@ export function useUser() {
                 // 로그인 이벤트 리스너
                 const unlistenLogin = await listen('user-logged-in', (event) => {
                     console.log('🔔 사용자 로그인 이벤트 수신:', event.payload);
+                    // token 필드는 "[REDACTED]" 로 오므로 쓰지 않는다 (API 호출은 api_request 로 Rust 가 처리)
+                    queryClient.setQueryData(['user'], event.payload as User);
+                    setSessionExpiresIn(null);
+                });
+
+                // 토큰 자동 갱신 이벤트 리스너 (Rust 에서 refresh 완료)
+                const unlistenRefreshed = await listen('token-refreshed', (event) => {
                     const user = event.payload as User;
                     queryClient.setQueryData(['user'], user);
-                    setStoredUser(user);
-                    if (user.token) setAuthToken(user.token);
+                    setSessionExpiresIn(null);
+                });
+
+                // 토큰 만료 임박 이벤트 리스너
+                const unlistenExpiring = await listen('session-expiring', (event) => {
+                    const { seconds_remaining } = event.payload as { expires_at: string, seconds_remaining: number };
+                    console.warn(`⏳ 세션 만료 ${seconds_remaining}초 전`);
+                    setSessionExpiresIn(seconds_remaining);
                 });
 
                 // 로그아웃 이벤트 리스너
-                const unlistenLogout = await listen('user-logged-out', () => {
-                    console.log('🔔 사용자 로그아웃 이벤트 수신');
+                const unlistenLogout = await listen('user-logged-out', (event) => {
+                    // reason: 'user_requested' | 'token_expired' | 'refresh_failed' | 'unlock_failed' | 'offline_rejected'
+                    console.log('🔔 사용자 로그아웃 이벤트 수신:', event.payload);
+                    setSessionExpiresIn(null);
                     queryClient.setQueryData(['user'], null);
-                    setStoredUser(null);
                     clearAuthToken();
                 });
 
                 return () => {
                     unlistenLogin();
+                    unlistenRefreshed();
+                    unlistenExpiring();
                     unlistenLogout();
                 };
             } catch (error) {
@@ -104,14 +82,6 @@ export function useUser() {
         };
     }, [queryClient]);
 
-    // 데이터 변화에 따라 로컬 스토리지/토큰 동기화
-    useEffect(() => {
-        const user = userQuery.data as User | null | undefined;
-        setStoredUser(user ?? null);
-        const token = (user as any)?.token as string | undefined;
-        if (token) setAuthToken(token); else clearAuthToken();
-    }, [userQuery.data]);
-
     // 🔐 로그아웃 (auth_state.rs 시스템 활용)
     const logoutMutation = useMutation({
         mutationFn: async (): Promise<void> => {
@@ -123,7 +93,7 @@ export function useUser() {
         },
     });
 
-    // 🔐 권한 확인 (auth_state.rs 시스템 활용)
+    // 🔐 권한 확인 (rbac.rs: 역할 상속 + `queue:*` 같은 와일드카드 적용)
     const checkPermission = async (permission: string): Promise<boolean> => {
         try {
             return await invoke('check_permission', { permission });
@@ -133,6 +103,29 @@ export function useUser() {
         }
     };
 
+    // 🔐 현재 사용자의 유효 권한 목록
+    const getEffectivePermissions = async (): Promise<string[]> => {
+        try {
+            return await invoke('get_effective_permissions');
+        } catch (error) {
+            console.error('권한 목록 조회 실패:', error);
+            return [];
+        }
+    };
+
+    // 🎭 대리 세션 시작 / 종료 (impersonation.rs). 결과는 user-logged-in 이벤트로도 전달됨
+    const startImpersonation = async (userId: string, reason?: string): Promise<User> => {
+        const user = await invoke<User>('start_impersonation', { userId, reason: reason ?? null });
+        queryClient.setQueryData(['user'], user);
+        return user;
+    };
+
+    const endImpersonation = async (): Promise<User> => {
+        const user = await invoke<User>('end_impersonation');
+        queryClient.setQueryData(['user'], user);
+        return user;
+    };
+
     // 🔐 인증 상태 확인 (auth_state.rs 시스템 활용)
     const checkAuthStatus = async (): Promise<boolean> => {
         try {
@@ -158,11 +151,17 @@ export function useUser() {
         // 액션
         logout: logoutMutation.mutateAsync,
         checkPermission,
+        getEffectivePermissions,
         checkAuthStatus,
         refreshUser,
+        startImpersonation,
+        endImpersonation,
 
         // 상태
         isLoggedIn: !!userQuery.data,
+        impersonatedBy: userQuery.data?.impersonated_by ?? null,
+        isOffline: !!userQuery.data?.offline,
         isLoggingOut: logoutMutation.isPending,
+        sessionExpiresIn,
     };
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+// C:\pilot-tauri\nexus-call-hub\src\shared\hooks\useWorkspaceLayouts.ts
+import { useCallback, useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+
+// Rust window/layouts.rs 의 LayoutSummary
+export interface LayoutSummary {
+    name: string;
+    builtin: boolean;
+    windows: string[];
+    // 현재 사용자가 열 수 없는 윈도우 (하나라도 있으면 적용 불가)
+    denied: string[];
+    saved_at?: string | null;
+}
+
+// 🧩 작업 레이아웃 (여러 윈도우를 저장된 배치로 한 번에 열기)
+export const useWorkspaceLayouts = () => {
+    const [layouts, setLayouts] = useState<LayoutSummary[]>([]);
+
+    const refresh = useCallback(async () => {
+        try {
+            setLayouts(await invoke<LayoutSummary[]>('list_layouts'));
+        } catch (error) {
+            console.error('레이아웃 목록 조회 실패:', error);
+        }
+    }, []);
+
+    useEffect(() => {
+        refresh();
+    }, [refresh]);
+
+    // 적용. 권한 없는 윈도우가 있으면 WindowError 예외 (아무것도 열리지 않음)
+    const applyLayout = (name: string) => invoke<string[]>('apply_layout', { name });
+
+    // 지금 열려 있는 윈도우 배치를 저장 (같은 이름이면 덮어씀)
+    const saveLayout = async (name: string) => {
+        const saved = await invoke<LayoutSummary>('save_layout', { name });
+        await refresh();
+        return saved;
+    };
+
+    return {
+        layouts,
+        applyLayout,
+        saveLayout,
+        refresh,
+    };
+};
@@ -1,8 +1,45 @@
 // C:\pilot-tauri\nexus-call-hub\src\statistics\StatisticsApp.tsx
+import { useState, useEffect } from "react"
 import { invoke } from "@tauri-apps/api/core"
+import { listen } from "@tauri-apps/api/event"
 import { Button } from "@/shared/ui/button"
 
+interface StaffingResult {
+    required_agents: number
+    current_agents: number
+    shortfall: number
+    traffic_erlangs: number
+    target_service_level: number
+    target_answer_secs: number
+    current: {
+        service_level: number
+        probability_of_waiting: number
+        average_speed_of_answer_secs: number | null
+    }
+}
+
 function StatisticsApp() {
+    const [staffing, setStaffing] = useState<StaffingResult | null>(null)
+
+    useEffect(() => {
+        const loadStaffing = async () => {
+            try {
+                setStaffing(await invoke<StaffingResult>('calculate_staffing'))
+            } catch (error) {
+                console.error("인력 산정 실패:", error)
+            }
+        }
+        loadStaffing()
+
+        // 대기열 / 상담원 변경분(delta) 또는 KPI 갱신이 올 때마다 재계산
+        const unlisteners = ['queue-delta', 'queue-kpis-updated']
+            .map(name => listen(name, loadStaffing))
+
+        return () => {
+            unlisteners.forEach(u => u.then(f => f()))
+        }
+    }, [])
+
     const handleClose = async () => {
         try {
             await invoke('close_window', { label: 'statistics' })
@@ -103,6 +140,33 @@ function StatisticsApp() {
                     </div>
                 </div>
 
+                {/* 인력 산정 (Erlang C) */}
+                {staffing && (
+                    <div className={`rounded-xl p-6 shadow-sm mb-8 ${staffing.shortfall > 0 ? 'bg-red-50 border border-red-200' : 'bg-white'}`}>
+                        <div className="flex items-center justify-between">
+                            <div>
+                                <p className="text-sm text-gray-600">
+                                    인력 산정 (목표 {Math.round(staffing.target_service_level * 100)}/{staffing.target_answer_secs}초, {staffing.traffic_erlangs.toFixed(1)} Erlang)
+                                </p>
+                                <p className={`text-2xl font-bold ${staffing.shortfall > 0 ? 'text-red-600' : 'text-gray-900'}`}>
+                                    {staffing.shortfall > 0
+                                        ? `상담원 ${staffing.shortfall}명 부족`
+                                        : '현재 인원으로 목표 달성 가능'}
+                                </p>
+                            </div>
+                            <div className="text-right text-sm text-gray-600">
+                                <p>필요 {staffing.required_agents}명 / 현재 {staffing.current_agents}명</p>
+                                <p>예상 서비스 레벨 {(staffing.current.service_level * 100).toFixed(1)}%</p>
+                                <p>
+                                    대기 확률 {(staffing.current.probability_of_waiting * 100).toFixed(1)}%
+                                    {staffing.current.average_speed_of_answer_secs !== null &&
+                                        ` · ASA ${staffing.current.average_speed_of_answer_secs.toFixed(0)}초`}
+                                </p>
+                            </div>
+                        </div>
+                    </div>
+                )}
+
                 {/* 최근 통화 목록 */}
                 <div className="bg-white rounded-xl shadow-sm overflow-hidden">
                     <div className="px-6 py-4 border-b border-gray-200">
@@ -2,6 +2,7 @@
 import { useUser } from "@/shared/hooks/useUser"
 import { Button } from "@/shared/ui/button"
 import { invoke } from "@tauri-apps/api/core"
+import LockScreen from "@/widgets/LockScreen"
 
 interface CommonHeaderProps {
     title: string
@@ -22,7 +23,15 @@ function CommonHeader({
     showLogout = true,
     customActions
 }: Omit<CommonHeaderProps, 'user'>) {  // user prop 제거
-    const { user, logout, isLoggingOut } = useUser(); // 🔐 새로운 useUser 훅 사용
+    const { user, logout, isLoggingOut, impersonatedBy, endImpersonation, isOffline } = useUser(); // 🔐 새로운 useUser 훅 사용
+
+    const handleEndImpersonation = async () => {
+        try {
+            await endImpersonation()
+        } catch (error) {
+            console.error('대리 세션 종료 실패:', error)
+        }
+    }
 
     const handleBack = async () => {
         if (onBack) {
@@ -68,6 +77,30 @@ function CommonHeader({
     }
 
     return (
+        <>
+        {/* 🔒 자리 비움 잠금 시 화면 전체를 가림 */}
+        <LockScreen />
+        {/* 📴 오프라인 세션 표시 */}
+        {isOffline && user && (
+            <div className="bg-gray-700 text-white text-xs px-4 py-1">
+                📴 서버에 연결되지 않아 오프라인 모드로 로그인했습니다. 일부 기능이 제한되며, 연결되면 자동으로 전환됩니다.
+            </div>
+        )}
+        {/* 🎭 대리 세션 표시 */}
+        {impersonatedBy && user && (
+            <div className="bg-amber-400 text-amber-950 text-xs px-4 py-1 flex items-center justify-between">
+                <span>
+                    🎭 {impersonatedBy.name} 님이 {user.name} 님으로 대리 작업 중입니다
+                </span>
+                <button
+                    type="button"
+                    onClick={handleEndImpersonation}
+                    className="underline font-medium hover:text-amber-800"
+                >
+                    대리 세션 종료
+                </button>
+            </div>
+        )}
         <header className="bg-[#55BEC8] border-b border-gray-200 px-4 py-2">
             <div className="flex items-center justify-between">
                 {/* 왼쪽 영역 - 타이틀 */}
@@ -142,6 +175,7 @@ function CommonHeader({
                 </div>
             </div>
         </header>
+        </>
     )
 }
 
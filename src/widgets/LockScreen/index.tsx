@@ -0,0 +1,82 @@
+// C:\pilot-tauri\nexus-call-hub\src\widgets\LockScreen\index.tsx
+import { useState } from "react"
+import { useSessionLock } from "@/shared/hooks/useSessionLock"
+
+// 🔒 세션이 잠기면 화면 전체를 가리는 잠금 화면 (아래 화면 상태는 그대로 유지됨)
+function LockScreen() {
+    const { status, isLocked, unlock } = useSessionLock()
+    const [mode, setMode] = useState<'pin' | 'password'>('pin')
+    const [secret, setSecret] = useState("")
+    const [error, setError] = useState("")
+    const [isUnlocking, setIsUnlocking] = useState(false)
+
+    if (!isLocked || !status) {
+        return null
+    }
+
+    const effectiveMode = status.has_pin ? mode : 'password'
+
+    const handleUnlock = async (e: React.FormEvent) => {
+        e.preventDefault()
+        setError("")
+        setIsUnlocking(true)
+        try {
+            const unlocked = await unlock(
+                effectiveMode === 'pin'
+                    ? { type: 'pin', pin: secret }
+                    : { type: 'password', password: secret }
+            )
+            if (!unlocked) {
+                setError('일치하지 않습니다. 다시 입력해주세요.')
+            }
+        } catch (err: any) {
+            setError(String(err))
+        } finally {
+            setSecret("")
+            setIsUnlocking(false)
+        }
+    }
+
+    return (
+        <div className="fixed inset-0 z-[9999] bg-gray-900 flex items-center justify-center">
+            <form onSubmit={handleUnlock} className="bg-white rounded-lg shadow-xl p-8 w-80 text-center">
+                <div className="text-4xl mb-3">🔒</div>
+                <h2 className="text-lg font-semibold text-gray-900 mb-1">세션이 잠겼습니다</h2>
+                <p className="text-sm text-gray-500 mb-6">
+                    {effectiveMode === 'pin' ? 'PIN 을 입력해 잠금을 해제하세요' : '비밀번호를 입력해 잠금을 해제하세요'}
+                </p>
+
+                <input
+                    type="password"
+                    inputMode={effectiveMode === 'pin' ? 'numeric' : undefined}
+                    autoFocus
+                    value={secret}
+                    onChange={(e) => setSecret(e.target.value)}
+                    className="w-full border border-gray-300 rounded px-3 py-2 mb-3 text-center"
+                    placeholder={effectiveMode === 'pin' ? 'PIN' : '비밀번호'}
+                />
+                {error && <p className="text-sm text-red-600 mb-3">{error}</p>}
+
+                <button
+                    type="submit"
+                    disabled={isUnlocking || !secret}
+                    className="w-full bg-blue-600 text-white rounded py-2 disabled:opacity-50"
+                >
+                    {isUnlocking ? '확인 중...' : '잠금 해제'}
+                </button>
+
+                {status.has_pin && (
+                    <button
+                        type="button"
+                        onClick={() => { setMode(effectiveMode === 'pin' ? 'password' : 'pin'); setError("") }}
+                        className="mt-3 text-xs text-gray-500 hover:text-gray-700"
+                    >
+                        {effectiveMode === 'pin' ? '비밀번호로 해제' : 'PIN 으로 해제'}
+                    </button>
+                )}
+            </form>
+        </div>
+    )
+}
+
+export default LockScreen
@@ -1,6 +1,6 @@
 // C:\pilot-tauri\nexus-call-hub\src\launcher\components\LoginComponent.tsx
 import { useState } from "react"
-import { useLogin } from "../../shared/hooks/useAuth"
+import { LoginError, useLogin } from "../../shared/hooks/useAuth"
 import { User } from "../../shared/api/types"
 // import { User } from "@/shared/api/types"
 
@@ -24,52 +24,38 @@ function LoginComponent({ onLoginSuccess, onSwitchToSignup }: LoginComponentProp
         setError("")
 
         try {
-            const result = await loginMutation.mutateAsync(credentials)
+            // 🔐 Rust 가 로그인하고 토큰은 Tauri State 에만 저장한다 (웹뷰로 오지 않음)
+            const user = await loginMutation.mutateAsync(credentials)
 
-            if (result.success && result.data) {
-                // 백엔드 응답을 Tauri State 형식으로 변환
-                const user: User = {
-                    id: result.data.username,
-                    name: result.data.username,
-                    department: '고객상담팀', // 추후 백엔드 /auth/me API에서 받아올 예정
-                    role: '상담원', // 추후 백엔드 /auth/me API에서 받아올 예정
-                    token: result.data.token
-                }
-
-                // 🔧 Tauri State에 사용자 정보 저장 (이미 구현된 auth_state.rs 활용)
-                try {
-                    await import("@tauri-apps/api/core").then(({ invoke }) => 
-                        invoke('set_user_state', { user })
-                    );
-                    console.log('✅ Tauri State 저장 완료');
-                } catch (tauriError) {
-                    console.error('❌ Tauri State 저장 실패:', tauriError);
-                }
+            // 부모 컴포넌트에 로그인 성공 알림
+            onLoginSuccess(user)
+        } catch (error: any) {
+            console.error('로그인 에러:', error)
+            const loginError = error as LoginError
 
-                // 🔧 로컬 스토리지에도 저장
+            // 📴 서버에 닿지 않으면 이 PC 에 저장된 자격 증명으로 오프라인 로그인 (권한 제한)
+            if (loginError?.kind === 'unreachable') {
                 try {
-                    localStorage.setItem('auth_user', JSON.stringify(user));
-                    localStorage.setItem('auth_token', user.token);
-                    console.log('✅ 로컬 스토리지 저장 완료');
-                } catch (storageError) {
-                    console.error('❌ 로컬 스토리지 저장 실패:', storageError);
+                    const { invoke } = await import("@tauri-apps/api/core")
+                    const user = await invoke<User>('offline_login', {
+                        username: credentials.username,
+                        password: credentials.password
+                    })
+                    console.log('📴 오프라인 로그인 성공:', user.name)
+                    onLoginSuccess(user)
+                    return
+                } catch (offlineError) {
+                    console.warn('오프라인 로그인 실패:', offlineError)
+                    setError(`서버에 연결할 수 없습니다. ${offlineError}`)
+                    return
                 }
-
-                // 부모 컴포넌트에 로그인 성공 알림
-                onLoginSuccess(user)
-            } else {
-                setError(result.message || '로그인에 실패했습니다.')
             }
-        } catch (error: any) {
-            console.error('로그인 에러:', error)
 
             // 에러 메시지 처리
-            if (error.response?.data?.message) {
-                setError(error.response.data.message)
-            } else if (error.message) {
-                setError(error.message)
+            if (loginError?.message) {
+                setError(loginError.message)
             } else {
-                setError('네트워크 오류가 발생했습니다. 서버가 실행 중인지 확인해주세요.')
+                setError('로그인에 실패했습니다.')
             }
         }
     }
@@ -0,0 +1,104 @@
+// C:\pilot-tauri\nexus-call-hub\src\widgets\SecondFactorForm\index.tsx
+import { useState } from "react"
+import { useSecondFactor } from "@/shared/hooks/useSecondFactor"
+
+interface SecondFactorFormProps {
+    onVerified: () => void
+    onCancel: () => void
+}
+
+// 🔐 로그인 후 2단계 인증. 등록 전이면 관리자에게 등록을 요청하도록 안내 (대기 세션에서는 등록 불가)
+function SecondFactorForm({ onVerified, onCancel }: SecondFactorFormProps) {
+    const { status, verify } = useSecondFactor()
+    const [mode, setMode] = useState<'totp' | 'recovery_code'>('totp')
+    const [code, setCode] = useState("")
+    const [error, setError] = useState("")
+    const [isVerifying, setIsVerifying] = useState(false)
+
+    const needsEnrollment = !!status && !status.enrolled
+
+    if (!status) {
+        return null
+    }
+
+    const handleSubmit = async (e: React.FormEvent) => {
+        e.preventDefault()
+        setError("")
+        setIsVerifying(true)
+        try {
+            const verified = await verify({ type: mode, code })
+            if (verified) {
+                onVerified()
+            } else {
+                setError('코드가 일치하지 않습니다. 다시 입력해주세요.')
+            }
+        } catch (err: any) {
+            setError(String(err))
+        } finally {
+            setCode("")
+            setIsVerifying(false)
+        }
+    }
+
+    return (
+        <div className="min-h-screen bg-gradient-to-br from-blue-100 to-indigo-200 flex items-center justify-center p-5">
+            <form onSubmit={handleSubmit} className="bg-white rounded-lg shadow-xl p-8 w-96 text-center">
+                <div className="text-4xl mb-3">🔐</div>
+                <h2 className="text-lg font-semibold text-gray-900 mb-1">2단계 인증</h2>
+
+                {needsEnrollment ? (
+                    <p className="text-sm text-gray-500 mb-6">
+                        이 계정은 2단계 인증이 필요하지만 등록된 인증 앱이 없습니다.
+                        관리자에게 인증 앱 등록을 요청하세요
+                    </p>
+                ) : (
+                    <>
+                        <p className="text-sm text-gray-500 mb-6">
+                            {mode === 'totp'
+                                ? '인증 앱에 표시된 6자리 코드를 입력하세요'
+                                : `복구 코드를 입력하세요 (남은 코드 ${status.remaining_recovery_codes}개)`}
+                        </p>
+
+                        <input
+                            type="text"
+                            inputMode={mode === 'totp' ? 'numeric' : undefined}
+                            autoComplete="one-time-code"
+                            autoFocus
+                            value={code}
+                            onChange={(e) => setCode(e.target.value)}
+                            className="w-full border border-gray-300 rounded px-3 py-2 mb-3 text-center tracking-widest"
+                            placeholder={mode === 'totp' ? '000000' : 'xxxxx-xxxxx'}
+                        />
+                        {error && <p className="text-sm text-red-600 mb-3">{error}</p>}
+
+                        <button
+                            type="submit"
+                            disabled={isVerifying || !code}
+                            className="w-full bg-blue-600 text-white rounded py-2 disabled:opacity-50"
+                        >
+                            {isVerifying ? '확인 중...' : '확인'}
+                        </button>
+
+                        <button
+                            type="button"
+                            onClick={() => { setMode(mode === 'totp' ? 'recovery_code' : 'totp'); setError("") }}
+                            className="mt-3 text-xs text-gray-500 hover:text-gray-700"
+                        >
+                            {mode === 'totp' ? '복구 코드 사용' : '인증 앱 코드 사용'}
+                        </button>
+                    </>
+                )}
+
+                <button
+                    type="button"
+                    onClick={onCancel}
+                    className="mt-3 ml-4 text-xs text-gray-500 hover:text-gray-700"
+                >
+                    로그인 화면으로
+                </button>
+            </form>
+        </div>
+    )
+}
+
+export default SecondFactorForm